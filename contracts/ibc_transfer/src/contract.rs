@@ -1,10 +1,8 @@
 use cosmwasm_std::{
     coin, entry_point, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, Response,
-    StdError, StdResult, SubMsg,
+    StdError, StdResult, Storage, SubMsg,
 };
 use cw2::set_contract_version;
-use neutron_sdk::interchain_txs::helpers::decode_message_response;
-use neutron_sdk::proto_types::neutron::transfer::MsgTransferResponse;
 use neutron_sdk::{
     bindings::{
         msg::{IbcFee, NeutronMsg},
@@ -19,10 +17,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg},
-    state::{
-        read_reply_payload, read_sudo_payload, save_reply_payload, save_sudo_payload,
-        IBC_SUDO_ID_RANGE_END, IBC_SUDO_ID_RANGE_START,
-    },
+    state::{migrate_legacy_sudo_payloads, DEFAULT_MIGRATION_LIMIT, SUDO_PAYLOADS},
 };
 
 // Default timeout for IbcTransfer is 10000000 blocks
@@ -92,7 +87,7 @@ fn sudo_callback2(deps: Deps, payload: Type2) -> StdResult<Response> {
 }
 
 // Enum representing payload to process during handling acknowledgement messages in Sudo handler
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum SudoPayload {
     HandlerPayload1(Type1),
     HandlerPayload2(Type2),
@@ -103,30 +98,18 @@ fn msg_with_sudo_callback<C: Into<CosmosMsg<T>>, T>(
     deps: DepsMut<NeutronQuery>,
     msg: C,
     payload: SudoPayload,
-) -> StdResult<SubMsg<T>> {
-    let id = save_reply_payload(deps.storage, payload)?;
-    Ok(SubMsg::reply_on_success(msg, id))
+) -> NeutronResult<SubMsg<T>> {
+    SUDO_PAYLOADS.with_sudo_callback(deps.storage, msg, &payload)
 }
 
 // prepare_sudo_payload is called from reply handler
 // The method is used to extract sequence id and channel from SubmitTxResponse to process sudo payload defined in msg_with_sudo_callback later in Sudo handler.
 // Such flow msg_with_sudo_callback() -> reply() -> prepare_sudo_payload() -> sudo() allows you "attach" some payload to your Transfer message
 // and process this payload when an acknowledgement for the SubmitTx message is received in Sudo handler
-fn prepare_sudo_payload(mut deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
-    let payload = read_reply_payload(deps.storage, msg.id)?;
-    let resp: MsgTransferResponse = decode_message_response(
-        &msg.result
-            .into_result()
-            .map_err(StdError::generic_err)?
-            .msg_responses[0] // msg_responses must have exactly one Msg response: https://github.com/neutron-org/neutron/blob/28b1d2ce968aaf1866e92d5286487f079eba3370/wasmbinding/message_plugin.go#L307
-            .clone()
-            .value
-            .to_vec(),
-    )
-    .map_err(|e| StdError::generic_err(format!("failed to parse response: {:?}", e)))?;
-    let seq_id = resp.sequence_id;
-    let channel_id = resp.channel;
-    save_sudo_payload(deps.branch().storage, channel_id, seq_id, payload)?;
+fn prepare_sudo_payload(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+    SUDO_PAYLOADS
+        .handle_reply(deps.storage, msg)
+        .map_err(|e| StdError::generic_err(format!("failed to parse response: {:?}", e)))?;
     Ok(Response::new())
 }
 
@@ -134,7 +117,7 @@ fn prepare_sudo_payload(mut deps: DepsMut, _env: Env, msg: Reply) -> StdResult<R
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
     match msg.id {
         // It's convenient to use range of ID's to handle multiple reply messages
-        IBC_SUDO_ID_RANGE_START..=IBC_SUDO_ID_RANGE_END => prepare_sudo_payload(deps, env, msg),
+        id if SUDO_PAYLOADS.is_reply(id) => prepare_sudo_payload(deps, env, msg),
         _ => Err(StdError::generic_err(format!(
             "unsupported reply message id {}",
             msg.id
@@ -232,6 +215,10 @@ fn sudo_error(deps: DepsMut, req: RequestPacket, data: String) -> StdResult<Resp
         )
         .as_str(),
     );
+    // the transfer has failed, so its payload won't be needed anymore
+    let payload = take_sudo_payload(deps.storage, req)?;
+    deps.api
+        .debug(format!("WASMDEBUG: sudo_error: sudo payload: {:?}", payload).as_str());
     Ok(Response::new())
 }
 
@@ -243,6 +230,10 @@ fn sudo_timeout(deps: DepsMut, req: RequestPacket) -> StdResult<Response> {
         )
         .as_str(),
     );
+    // the transfer has timed out, so its payload won't be needed anymore
+    let payload = take_sudo_payload(deps.storage, req)?;
+    deps.api
+        .debug(format!("WASMDEBUG: sudo_timeout: sudo payload: {:?}", payload).as_str());
     Ok(Response::new())
 }
 
@@ -254,14 +245,9 @@ fn sudo_response(deps: DepsMut, req: RequestPacket, data: Binary) -> StdResult<R
         )
         .as_str(),
     );
-    let seq_id = req
-        .sequence
-        .ok_or_else(|| StdError::generic_err("sequence not found"))?;
-    let channel_id = req
-        .source_channel
-        .ok_or_else(|| StdError::generic_err("channel_id not found"))?;
-
-    match read_sudo_payload(deps.storage, channel_id, seq_id)? {
+    let payload =
+        take_sudo_payload(deps.storage, req)?.ok_or_else(|| StdError::not_found("SudoPayload"))?;
+    match payload {
         // here we can do different logic depending on the type of the payload we saved in msg_with_sudo_callback() call
         // This allows us to distinguish different transfer message from each other.
         // For example some protocols can send one transfer to refund user for some action and another transfer to top up some balance.
@@ -269,14 +255,29 @@ fn sudo_response(deps: DepsMut, req: RequestPacket, data: Binary) -> StdResult<R
         SudoPayload::HandlerPayload1(t1) => sudo_callback1(deps.as_ref(), t1),
         SudoPayload::HandlerPayload2(t2) => sudo_callback2(deps.as_ref(), t2),
     }
-    // at this place we can safely remove the data under (channel_id, seq_id) key
-    // but it costs an extra gas, so its on you how to use the storage
+}
+
+/// Returns and removes the sudo payload saved for the **req** packet in msg_with_sudo_callback()
+fn take_sudo_payload(
+    store: &mut dyn Storage,
+    req: RequestPacket,
+) -> StdResult<Option<SudoPayload>> {
+    let seq_id = req
+        .sequence
+        .ok_or_else(|| StdError::generic_err("sequence not found"))?;
+    let channel_id = req
+        .source_channel
+        .ok_or_else(|| StdError::generic_err("channel_id not found"))?;
+
+    SUDO_PAYLOADS.take(store, channel_id, seq_id)
 }
 
 #[entry_point]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
     deps.api.debug("WASMDEBUG: migrate");
-    Ok(Response::default())
+    let done =
+        migrate_legacy_sudo_payloads(deps.storage, msg.limit.unwrap_or(DEFAULT_MIGRATION_LIMIT))?;
+    Ok(Response::default().add_attribute("legacy_sudo_payloads_migrated", done.to_string()))
 }
 
 fn min_ntrn_ibc_fee(fee: IbcFee) -> IbcFee {
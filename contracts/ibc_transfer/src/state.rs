@@ -1,63 +1,51 @@
-use cosmwasm_std::{from_json, to_json_vec, Binary, StdResult, Storage};
+use cosmwasm_std::{from_json, Binary, Order, StdResult, Storage};
 use cw_storage_plus::{Item, Map};
+use neutron_sdk::sudo::payload_store::SudoPayloadStore;
 
 use crate::contract::SudoPayload;
 
 pub const IBC_SUDO_ID_RANGE_START: u64 = 1_000_000_000;
-pub const IBC_SUDO_ID_RANGE_SIZE: u64 = 1_000;
-pub const IBC_SUDO_ID_RANGE_END: u64 = IBC_SUDO_ID_RANGE_START + IBC_SUDO_ID_RANGE_SIZE;
 
-pub const REPLY_QUEUE_ID: Map<u64, Vec<u8>> = Map::new("reply_queue_id");
-
-const REPLY_ID: Item<u64> = Item::new("reply_id");
-
-/// get_next_id gives us an id for a reply msg
-/// dynamic reply id helps us to pass sudo payload to sudo handler via reply handler
-/// by setting unique(in transaction lifetime) id to the reply and mapping our payload to the id
+/// SUDO_PAYLOADS - storage for sudo handler payloads
+/// every outgoing transfer gets its own reply id from the range started at IBC_SUDO_ID_RANGE_START,
+/// so we can map our payload to the id and then, in the reply handler, to the (channel_id, seq_id) key
+/// of the packet, since every ibc channel has its own sequence counter (autoincrement)
 /// execute ->(unique reply.id) reply (channel_id,seq_id)-> sudo handler
-/// Since id uniqueless id only matters inside a transaction,
-/// we can safely reuse the same id set in every new transaction
-pub fn get_next_id(store: &mut dyn Storage) -> StdResult<u64> {
-    let mut id = REPLY_ID.may_load(store)?.unwrap_or(IBC_SUDO_ID_RANGE_START);
-    if id > IBC_SUDO_ID_RANGE_END {
-        id = IBC_SUDO_ID_RANGE_START
+pub const SUDO_PAYLOADS: SudoPayloadStore<SudoPayload> =
+    SudoPayloadStore::new("reply_payloads", "sudo_payloads", IBC_SUDO_ID_RANGE_START);
+
+/// Storage of the previous contract version, see [migrate_legacy_sudo_payloads]
+const LEGACY_REPLY_QUEUE_ID: Map<u64, Vec<u8>> = Map::new("reply_queue_id");
+const LEGACY_REPLY_ID: Item<u64> = Item::new("reply_id");
+const LEGACY_SUDO_PAYLOAD: Map<(String, u64), Vec<u8>> = Map::new("sudo_payload");
+
+/// Default number of legacy sudo payloads moved by a single `migrate` call
+pub const DEFAULT_MIGRATION_LIMIT: u32 = 100;
+
+/// Moves up to **limit** sudo payloads saved as JSON encoded bytes by the previous contract version
+/// to [SUDO_PAYLOADS] and removes the leftovers of the previous reply payload storage.
+/// Migrated payloads are removed from the legacy storage, so every call continues where the previous
+/// one stopped. Returns `true` if there are no legacy payloads left.
+pub fn migrate_legacy_sudo_payloads(store: &mut dyn Storage, limit: u32) -> StdResult<bool> {
+    let legacy: Vec<((String, u64), Vec<u8>)> = LEGACY_SUDO_PAYLOAD
+        .range(store, None, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<_>>()?;
+    for ((channel_id, seq_id), data) in legacy {
+        let payload: SudoPayload = from_json(Binary::new(data))?;
+        SUDO_PAYLOADS.save(store, channel_id.clone(), seq_id, &payload)?;
+        LEGACY_SUDO_PAYLOAD.remove(store, (channel_id, seq_id));
     }
-    REPLY_ID.save(store, &(id + 1))?;
-    Ok(id)
-}
-
-pub fn save_reply_payload(store: &mut dyn Storage, payload: SudoPayload) -> StdResult<u64> {
-    let id = get_next_id(store)?;
-    REPLY_QUEUE_ID.save(store, id, &to_json_vec(&payload)?)?;
-    Ok(id)
-}
 
-pub fn read_reply_payload(store: &dyn Storage, id: u64) -> StdResult<SudoPayload> {
-    let data = REPLY_QUEUE_ID.load(store, id)?;
-    from_json(Binary::new(data))
-}
-
-/// SUDO_PAYLOAD - tmp storage for sudo handler payloads
-/// key (String, u64) - (channel_id, seq_id)
-/// every ibc chanel have its own sequence counter(autoincrement)
-/// we can catch the counter in the reply msg for outgoing sudo msg
-/// and save our payload for the msg
-pub const SUDO_PAYLOAD: Map<(String, u64), Vec<u8>> = Map::new("sudo_payload");
-
-pub fn save_sudo_payload(
-    store: &mut dyn Storage,
-    channel_id: String,
-    seq_id: u64,
-    payload: SudoPayload,
-) -> StdResult<()> {
-    SUDO_PAYLOAD.save(store, (channel_id, seq_id), &to_json_vec(&payload)?)
-}
+    // reply payloads live only within a transaction, so there is nothing to move.
+    // The previous version reused at most IBC_SUDO_ID_RANGE_SIZE reply ids, so the storage is bounded
+    let reply_ids: Vec<u64> = LEGACY_REPLY_QUEUE_ID
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for id in reply_ids {
+        LEGACY_REPLY_QUEUE_ID.remove(store, id);
+    }
+    LEGACY_REPLY_ID.remove(store);
 
-pub fn read_sudo_payload(
-    store: &dyn Storage,
-    channel_id: String,
-    seq_id: u64,
-) -> StdResult<SudoPayload> {
-    let data = SUDO_PAYLOAD.load(store, (channel_id, seq_id))?;
-    from_json(Binary::new(data))
+    Ok(LEGACY_SUDO_PAYLOAD.is_empty(store))
 }
@@ -1,6 +1,3 @@
-use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
-use cosmos_sdk_proto::cosmos::tx::v1beta1::{TxBody, TxRaw};
-use cosmos_sdk_proto::traits::Message;
 use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
     StdResult, Uint128,
@@ -22,6 +19,7 @@ use neutron_sdk::interchain_queries::v047::queries::{
 };
 use neutron_sdk::interchain_queries::{
     check_query_type, get_registered_query, query_kv_result,
+    tx::{decode_tx_query_result, DecodedTx, TxMsg},
     v047::{
         register_queries::{
             new_register_balances_query_msg, new_register_bank_total_supply_query_msg,
@@ -31,7 +29,7 @@ use neutron_sdk::interchain_queries::{
             new_register_staking_validators_query_msg, new_register_transfers_query_msg,
             new_register_wasm_contract_store_query_msg,
         },
-        types::{COSMOS_SDK_TRANSFER_MSG_URL, RECIPIENT_FIELD},
+        types::RECIPIENT_FIELD,
     },
 };
 use neutron_sdk::sudo::msg::SudoMsg;
@@ -378,12 +376,11 @@ pub fn sudo_tx_query_result(
     deps: DepsMut<NeutronQuery>,
     _env: Env,
     query_id: u64,
-    _height: Height,
+    height: Height,
     data: Binary,
 ) -> NeutronResult<Response> {
     // Decode the transaction data
-    let tx: TxRaw = TxRaw::decode(data.as_slice())?;
-    let body: TxBody = TxBody::decode(tx.body_bytes.as_slice())?;
+    let tx = decode_tx_query_result(height, &data)?;
 
     // Get the registered query by ID and retrieve the raw query string
     let registered_query: QueryRegisteredQueryResponse =
@@ -402,6 +399,14 @@ pub fn sudo_tx_query_result(
             let query_data: Vec<TransactionFilterItem> =
                 serde_json_wasm::from_str(transactions_filter.as_str())?;
 
+            // If the transaction doesn't satisfy the registered filters, return an error, and
+            // this query result will be rejected by Neutron: no data will be saved to state.
+            if !tx.matches_filters(&query_data, MAX_ALLOWED_MESSAGES)? {
+                return Err(NeutronError::Std(StdError::generic_err(
+                    "failed to find a matching transaction message",
+                )));
+            }
+
             let recipient = query_data
                 .iter()
                 .find(|x| x.field == RECIPIENT_FIELD && x.op == TransactionFilterOp::Eq)
@@ -411,7 +416,7 @@ pub fn sudo_tx_query_result(
                 })
                 .unwrap_or("");
 
-            let deposits = recipient_deposits_from_tx_body(body, recipient)?;
+            let deposits = recipient_deposits_from_tx(&tx, recipient)?;
             // If we didn't find a Send message with the correct recipient, return an error, and
             // this query result will be rejected by Neutron: no data will be saved to state.
             if deposits.is_empty() {
@@ -435,33 +440,37 @@ pub fn sudo_tx_query_result(
     }
 }
 
-/// parses tx body and retrieves transactions to the given recipient.
-fn recipient_deposits_from_tx_body(
-    tx_body: TxBody,
-    recipient: &str,
-) -> NeutronResult<Vec<Transfer>> {
+/// retrieves bank transfers to the given recipient from the decoded transaction.
+fn recipient_deposits_from_tx(tx: &DecodedTx, recipient: &str) -> NeutronResult<Vec<Transfer>> {
     let mut deposits: Vec<Transfer> = vec![];
-    // Only handle up to MAX_ALLOWED_MESSAGES messages, everything else
+    // Only decode up to MAX_ALLOWED_MESSAGES messages, everything else
     // will be ignored to prevent 'out of gas' conditions.
     // Note: in real contracts you will have to somehow save ignored
     // data in order to handle it later.
-    for msg in tx_body.messages.iter().take(MAX_ALLOWED_MESSAGES) {
-        // Skip all messages in this transaction that are not Send messages.
-        if msg.type_url != *COSMOS_SDK_TRANSFER_MSG_URL.to_string() {
-            continue;
-        }
-
-        // Parse a Send message and check that it has the required recipient.
-        let transfer_msg: MsgSend = MsgSend::decode(msg.value.as_slice())?;
-        if transfer_msg.to_address == recipient {
-            for coin in transfer_msg.amount {
-                deposits.push(Transfer {
-                    sender: transfer_msg.from_address.clone(),
-                    amount: coin.amount.clone(),
-                    denom: coin.denom,
-                    recipient: recipient.to_string(),
-                });
+    for msg in tx.decode_messages(MAX_ALLOWED_MESSAGES) {
+        // Skip all messages in this transaction that are not Send messages
+        // and check that a Send message has the required recipient.
+        match msg {
+            TxMsg::BankSend(transfer_msg) if transfer_msg.to_address == recipient => {
+                for coin in transfer_msg.amount {
+                    deposits.push(Transfer {
+                        sender: transfer_msg.from_address.clone(),
+                        amount: coin.amount,
+                        denom: coin.denom,
+                        recipient: recipient.to_string(),
+                    });
+                }
+            }
+            // A message of a known type which failed to decode may be a deposit as well,
+            // so return an error instead of silently skipping it, and this query result
+            // will be rejected by Neutron: no data will be saved to state.
+            TxMsg::Undecodable(msg) => {
+                return Err(NeutronError::Std(StdError::generic_err(format!(
+                    "failed to decode transaction message of type {}",
+                    msg.type_url
+                ))))
             }
+            _ => continue,
         }
     }
     Ok(deposits)
@@ -1,7 +1,5 @@
 use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
-use cosmos_sdk_proto::cosmos::staking::v1beta1::{
-    MsgDelegate, MsgDelegateResponse, MsgUndelegate, MsgUndelegateResponse,
-};
+use cosmos_sdk_proto::cosmos::staking::v1beta1::{MsgDelegate, MsgUndelegate};
 use cosmos_sdk_proto::traits::Message;
 use cosmwasm_std::{
     to_json_binary, Binary, Coin as CoinSDK, CosmosMsg, CustomQuery, Deps, DepsMut, Env,
@@ -13,24 +11,24 @@ use serde::{Deserialize, Serialize};
 
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use neutron_sdk::bindings::msg::IbcFee;
-use neutron_sdk::proto_types::neutron::interchaintxs::v1::MsgSubmitTxResponse;
 use neutron_sdk::{
     bindings::{
         msg::NeutronMsg,
         query::{NeutronQuery, QueryInterchainAccountAddressResponse},
         types::ProtobufAny,
     },
-    interchain_txs::helpers::{decode_message_response, get_port_id},
-    interchain_txs::v047::helpers::decode_acknowledgement_response,
+    interchain_txs::helpers::get_port_id,
+    interchain_txs::responses::{decode_typed_acknowledgement_response, TypedMsgResponse},
     query::min_ibc_fee::query_min_ibc_fee,
+    shim::Timestamp,
     sudo::msg::{RequestPacket, SudoMsg},
     NeutronError, NeutronResult,
 };
 
 use crate::storage::{
-    add_error_to_queue, read_errors_from_queue, read_reply_payload, read_sudo_payload,
-    save_reply_payload, save_sudo_payload, AcknowledgementResult, SudoPayload,
-    ACKNOWLEDGEMENT_RESULTS, INTERCHAIN_ACCOUNTS, SUDO_PAYLOAD_REPLY_ID,
+    add_error_to_queue, migrate_legacy_sudo_payloads, read_errors_from_queue,
+    AcknowledgementResult, SudoPayload, ACKNOWLEDGEMENT_RESULTS, DEFAULT_MIGRATION_LIMIT,
+    INTERCHAIN_ACCOUNTS, SUDO_PAYLOADS,
 };
 
 // Default timeout for SubmitTX is two weeks
@@ -187,9 +185,8 @@ fn msg_with_sudo_callback<C: Into<CosmosMsg<T>>, T>(
     deps: DepsMut<NeutronQuery>,
     msg: C,
     payload: SudoPayload,
-) -> StdResult<SubMsg<T>> {
-    save_reply_payload(deps.storage, payload)?;
-    Ok(SubMsg::reply_on_success(msg, SUDO_PAYLOAD_REPLY_ID))
+) -> NeutronResult<SubMsg<T>> {
+    SUDO_PAYLOADS.with_sudo_callback(deps.storage, msg, &payload)
 }
 
 fn execute_register_ica(
@@ -360,9 +357,11 @@ pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> StdResult<Response> {
 }
 
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
     deps.api.debug("WASMDEBUG: migrate");
-    Ok(Response::default())
+    let done =
+        migrate_legacy_sudo_payloads(deps.storage, msg.limit.unwrap_or(DEFAULT_MIGRATION_LIMIT))?;
+    Ok(Response::default().add_attribute("legacy_sudo_payloads_migrated", done.to_string()))
 }
 
 // handler
@@ -420,7 +419,10 @@ fn sudo_response(deps: DepsMut, request: RequestPacket, data: Binary) -> StdResu
     // interchain transaction. You can decide that this is not worth killing the channel,
     // write an error log and / or save the acknowledgement to an errors queue for later manual
     // processing. The decision is based purely on your application logic.
-    let payload = read_sudo_payload(deps.storage, channel_id, seq_id).ok();
+    let payload = SUDO_PAYLOADS
+        .take(deps.storage, channel_id, seq_id)
+        .ok()
+        .flatten();
     if payload.is_none() {
         let error_msg = "WASMDEBUG: Error: Unable to read sudo payload";
         deps.api.debug(error_msg);
@@ -434,18 +436,14 @@ fn sudo_response(deps: DepsMut, request: RequestPacket, data: Binary) -> StdResu
     // In this particular case, we return an error because not being able to parse this data
     // that a fatal error occurred on Neutron side, or that the remote chain sent us unexpected data.
     // Both cases require immediate attention.
-    let parsed_data = decode_acknowledgement_response(data)?;
+    let parsed_data = decode_typed_acknowledgement_response(data)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
 
     let mut item_types = vec![];
     for item in parsed_data {
-        let item_type = item.type_url.as_str();
-        item_types.push(item_type.to_string());
-        match item_type {
-            "/cosmos.staking.v1beta1.MsgUndelegate" => {
-                // In this particular case, a mismatch between the string message type and the
-                // serialised data layout looks like a fatal error that has to be investigated.
-                let out: MsgUndelegateResponse = decode_message_response(&item.value)?;
-
+        item_types.push(item.type_url());
+        match item {
+            TypedMsgResponse::CosmosStakingV1beta1Undelegate(out) => {
                 // In this particular case, we demonstrate that minor errors should not
                 // close the channel, and should be treated in a forgiving manner.
                 let completion_time = out.completion_time.or_else(|| {
@@ -453,16 +451,12 @@ fn sudo_response(deps: DepsMut, request: RequestPacket, data: Binary) -> StdResu
                     deps.api
                         .debug(error_msg);
                     add_error_to_queue(deps.storage, error_msg.to_string());
-                    Some(prost_types::Timestamp::default())
+                    Some(Timestamp::default())
                 });
                 deps.api
                     .debug(format!("Undelegation completion time: {:?}", completion_time).as_str());
             }
-            "/cosmos.staking.v1beta1.MsgDelegateResponse" => {
-                // In this particular case, a mismatch between the string message type and the
-                // serialised data layout looks like a fatal error that has to be investigated.
-                let _out: MsgDelegateResponse = decode_message_response(&item.value)?;
-            }
+            TypedMsgResponse::CosmosStakingV1beta1Delegate(_) => {}
             _ => {
                 deps.api.debug(
                     format!(
@@ -516,7 +510,10 @@ fn sudo_timeout(deps: DepsMut, _env: Env, request: RequestPacket) -> StdResult<R
     // processing. The decision is based purely on your application logic.
     // Please be careful because it may lead to an unexpected state changes because state might
     // has been changed before this call and will not be reverted because of supressed error.
-    let payload = read_sudo_payload(deps.storage, channel_id, seq_id).ok();
+    let payload = SUDO_PAYLOADS
+        .take(deps.storage, channel_id, seq_id)
+        .ok()
+        .flatten();
     if let Some(payload) = payload {
         // update but also check that we don't update same seq_id twice
         ACKNOWLEDGEMENT_RESULTS.update(
@@ -555,7 +552,10 @@ fn sudo_error(deps: DepsMut, request: RequestPacket, details: String) -> StdResu
     let channel_id = request
         .source_channel
         .ok_or_else(|| StdError::generic_err("channel_id not found"))?;
-    let payload = read_sudo_payload(deps.storage, channel_id, seq_id).ok();
+    let payload = SUDO_PAYLOADS
+        .take(deps.storage, channel_id, seq_id)
+        .ok()
+        .flatten();
 
     if let Some(payload) = payload {
         // update but also check that we don't update same seq_id twice
@@ -582,23 +582,17 @@ fn sudo_error(deps: DepsMut, request: RequestPacket, details: String) -> StdResu
 // The method is used to extract sequence id and channel from SubmitTxResponse to process sudo payload defined in msg_with_sudo_callback later in Sudo handler.
 // Such flow msg_with_sudo_callback() -> reply() -> prepare_sudo_payload() -> sudo() allows you "attach" some payload to your SubmitTx message
 // and process this payload when an acknowledgement for the SubmitTx message is received in Sudo handler
-fn prepare_sudo_payload(mut deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
-    let payload = read_reply_payload(deps.storage)?;
-    let resp: MsgSubmitTxResponse = decode_message_response(
-        &msg.result
-            .into_result()
-            .map_err(StdError::generic_err)?
-            .msg_responses[0] // msg_responses must have exactly one Msg response: https://github.com/neutron-org/neutron/blob/28b1d2ce968aaf1866e92d5286487f079eba3370/wasmbinding/message_plugin.go#L443
-            .clone()
-            .value
-            .to_vec(),
-    )
-    .map_err(|e| StdError::generic_err(format!("failed to parse response: {:?}", e)))?;
-    deps.api
-        .debug(format!("WASMDEBUG: reply msg: {:?}", resp).as_str());
-    let seq_id = resp.sequence_id;
-    let channel_id = resp.channel;
-    save_sudo_payload(deps.branch().storage, channel_id, seq_id, payload)?;
+fn prepare_sudo_payload(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+    let (channel_id, seq_id) = SUDO_PAYLOADS
+        .handle_reply(deps.storage, msg)
+        .map_err(|e| StdError::generic_err(format!("failed to parse response: {:?}", e)))?;
+    deps.api.debug(
+        format!(
+            "WASMDEBUG: reply msg: channel_id: {}, seq_id: {}",
+            channel_id, seq_id
+        )
+        .as_str(),
+    );
     Ok(Response::new())
 }
 
@@ -619,7 +613,7 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
     deps.api
         .debug(format!("WASMDEBUG: reply msg: {:?}", msg).as_str());
     match msg.id {
-        SUDO_PAYLOAD_REPLY_ID => prepare_sudo_payload(deps, env, msg),
+        id if SUDO_PAYLOADS.is_reply(id) => prepare_sudo_payload(deps, env, msg),
         _ => Err(StdError::generic_err(format!(
             "unsupported reply message id {}",
             msg.id
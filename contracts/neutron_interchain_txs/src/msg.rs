@@ -24,7 +24,11 @@ pub enum QueryMsg {
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// max number of legacy sudo payloads to move to the new storage, the migration
+    /// can be repeated until the `legacy_sudo_payloads_migrated` attribute is `true`
+    pub limit: Option<u32>,
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct InstantiateMsg {}
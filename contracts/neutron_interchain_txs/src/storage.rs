@@ -1,5 +1,6 @@
-use cosmwasm_std::{from_json, to_json_vec, Binary, Order, StdResult, Storage};
+use cosmwasm_std::{from_json, Binary, Order, StdResult, Storage};
 use cw_storage_plus::{Item, Map};
+use neutron_sdk::sudo::payload_store::SudoPayloadStore;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -12,10 +13,20 @@ pub struct SudoPayload {
     pub port_id: String,
 }
 
-pub const SUDO_PAYLOAD_REPLY_ID: u64 = 1;
+pub const SUDO_PAYLOAD_REPLY_ID_START: u64 = 1;
+
+/// SUDO_PAYLOADS - storage for sudo handler payloads, keyed by reply id until the reply
+/// and by (channel_id, seq_id) of the sent packet after it
+pub const SUDO_PAYLOADS: SudoPayloadStore<SudoPayload> = SudoPayloadStore::new(
+    "reply_payloads",
+    "sudo_payloads",
+    SUDO_PAYLOAD_REPLY_ID_START,
+);
+
+/// Storage of the previous contract version, see [migrate_legacy_sudo_payloads]
+const LEGACY_REPLY_ID_STORAGE: Item<Vec<u8>> = Item::new("reply_queue_id");
+const LEGACY_SUDO_PAYLOAD: Map<(String, u64), Vec<u8>> = Map::new("sudo_payload");
 
-pub const REPLY_ID_STORAGE: Item<Vec<u8>> = Item::new("reply_queue_id");
-pub const SUDO_PAYLOAD: Map<(String, u64), Vec<u8>> = Map::new("sudo_payload");
 pub const INTERCHAIN_ACCOUNTS: Map<String, Option<(String, String)>> =
     Map::new("interchain_accounts");
 
@@ -37,15 +48,6 @@ pub enum AcknowledgementResult {
     Timeout(String),
 }
 
-pub fn save_reply_payload(store: &mut dyn Storage, payload: SudoPayload) -> StdResult<()> {
-    REPLY_ID_STORAGE.save(store, &to_json_vec(&payload)?)
-}
-
-pub fn read_reply_payload(store: &dyn Storage) -> StdResult<SudoPayload> {
-    let data = REPLY_ID_STORAGE.load(store)?;
-    from_json(Binary::new(data))
-}
-
 pub fn add_error_to_queue(store: &mut dyn Storage, error_msg: String) -> Option<()> {
     let result = ERRORS_QUEUE
         .keys(store, None, None, Order::Descending)
@@ -63,20 +65,26 @@ pub fn read_errors_from_queue(store: &dyn Storage) -> StdResult<Vec<(Vec<u8>, St
         .collect()
 }
 
-pub fn read_sudo_payload(
-    store: &dyn Storage,
-    channel_id: String,
-    seq_id: u64,
-) -> StdResult<SudoPayload> {
-    let data = SUDO_PAYLOAD.load(store, (channel_id, seq_id))?;
-    from_json(Binary::new(data))
-}
+/// Default number of legacy sudo payloads moved by a single `migrate` call
+pub const DEFAULT_MIGRATION_LIMIT: u32 = 100;
+
+/// Moves up to **limit** sudo payloads saved as JSON encoded bytes by the previous contract version
+/// to [SUDO_PAYLOADS] and removes the leftovers of the previous reply payload storage.
+/// Migrated payloads are removed from the legacy storage, so every call continues where the previous
+/// one stopped. Returns `true` if there are no legacy payloads left.
+pub fn migrate_legacy_sudo_payloads(store: &mut dyn Storage, limit: u32) -> StdResult<bool> {
+    let legacy: Vec<((String, u64), Vec<u8>)> = LEGACY_SUDO_PAYLOAD
+        .range(store, None, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<_>>()?;
+    for ((channel_id, seq_id), data) in legacy {
+        let payload: SudoPayload = from_json(Binary::new(data))?;
+        SUDO_PAYLOADS.save(store, channel_id.clone(), seq_id, &payload)?;
+        LEGACY_SUDO_PAYLOAD.remove(store, (channel_id, seq_id));
+    }
+
+    // the reply payload lives only within a transaction, so there is nothing to move
+    LEGACY_REPLY_ID_STORAGE.remove(store);
 
-pub fn save_sudo_payload(
-    store: &mut dyn Storage,
-    channel_id: String,
-    seq_id: u64,
-    payload: SudoPayload,
-) -> StdResult<()> {
-    SUDO_PAYLOAD.save(store, (channel_id, seq_id), &to_json_vec(&payload)?)
+    Ok(LEGACY_SUDO_PAYLOAD.is_empty(store))
 }
@@ -2,14 +2,18 @@ use std::marker::PhantomData;
 
 use crate::{
     contract::query_errors_queue,
-    storage::{add_error_to_queue, read_errors_from_queue, ERRORS_QUEUE},
+    storage::{
+        add_error_to_queue, migrate_legacy_sudo_payloads, read_errors_from_queue, SudoPayload,
+        ERRORS_QUEUE, SUDO_PAYLOADS,
+    },
 };
 
 use cosmwasm_std::{
     from_json,
     testing::{MockApi, MockQuerier, MockStorage},
-    OwnedDeps,
+    to_json_vec, OwnedDeps,
 };
+use cw_storage_plus::Map;
 
 use neutron_sdk::bindings::query::NeutronQuery;
 
@@ -78,3 +82,47 @@ fn test_errors_queue() {
         ]
     );
 }
+
+#[test]
+fn test_migrate_legacy_sudo_payloads() {
+    let mut store = MockStorage::new();
+    let payload = SudoPayload {
+        message: "message".to_string(),
+        port_id: "port".to_string(),
+    };
+
+    // payloads of the previous contract version are JSON encoded bytes under the "sudo_payload" namespace
+    let legacy: Map<(String, u64), Vec<u8>> = Map::new("sudo_payload");
+    for seq_id in 1..=3 {
+        legacy
+            .save(
+                &mut store,
+                ("channel-0".to_string(), seq_id),
+                &to_json_vec(&payload).unwrap(),
+            )
+            .unwrap();
+    }
+
+    // payloads are moved in batches of at most limit entries
+    assert!(!migrate_legacy_sudo_payloads(&mut store, 2).unwrap());
+    assert!(!legacy.has(&store, ("channel-0".to_string(), 1)));
+    assert!(!legacy.has(&store, ("channel-0".to_string(), 2)));
+    assert!(legacy.has(&store, ("channel-0".to_string(), 3)));
+    assert!(migrate_legacy_sudo_payloads(&mut store, 2).unwrap());
+
+    assert!(legacy.is_empty(&store));
+    for seq_id in 1..=3 {
+        assert_eq!(
+            SUDO_PAYLOADS
+                .take(&mut store, "channel-0".to_string(), seq_id)
+                .unwrap(),
+            Some(payload.clone())
+        );
+    }
+    assert_eq!(
+        SUDO_PAYLOADS
+            .take(&mut store, "channel-0".to_string(), 1)
+            .unwrap(),
+        None
+    );
+}
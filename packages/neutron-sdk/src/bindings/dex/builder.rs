@@ -0,0 +1,527 @@
+use crate::{
+    bindings::dex::{
+        math::is_tick_out_of_range,
+        msg::DexMsg,
+        types::{DepositOption, LimitOrderType, MultiHopRoute, PairID, Params, PrecDec},
+    },
+    errors::error::{NeutronError, NeutronResult},
+};
+use cosmwasm_std::Uint128;
+
+/// Builds a [DexMsg::Deposit] from a list of deposits into pools of a single pair.
+///
+/// Tokens are ordered the same as in the **dex** module, i.e. as token0 and token1 of the [PairID],
+/// amounts and tick indexes of every deposit are adjusted to the order.
+///
+/// ```rust ignore
+/// let params: ParamsResponse = deps.querier.query(&DexQuery::Params {}.into())?;
+/// let msg = DepositBuilder::new(receiver, "uatom", "untrn")
+///     .add(Uint128::new(1000), Uint128::new(1000), 0, 1)
+///     .add(Uint128::new(500), Uint128::zero(), -10, 5)
+///     .build(&params.params)?;
+/// ```
+pub struct DepositBuilder {
+    receiver: String,
+    token_a: String,
+    token_b: String,
+    deposits: Vec<(Uint128, Uint128, i64, u64, DepositOption)>,
+}
+
+impl DepositBuilder {
+    pub fn new(
+        receiver: impl Into<String>,
+        token_a: impl Into<String>,
+        token_b: impl Into<String>,
+    ) -> Self {
+        DepositBuilder {
+            receiver: receiver.into(),
+            token_a: token_a.into(),
+            token_b: token_b.into(),
+            deposits: vec![],
+        }
+    }
+
+    /// Adds a deposit of **amount_a** and **amount_b** into the pool at **tick_index_a_to_b** with **fee**
+    pub fn add(
+        self,
+        amount_a: Uint128,
+        amount_b: Uint128,
+        tick_index_a_to_b: i64,
+        fee: u64,
+    ) -> Self {
+        self.add_with_options(
+            amount_a,
+            amount_b,
+            tick_index_a_to_b,
+            fee,
+            DepositOption {
+                disable_swap: false,
+            },
+        )
+    }
+
+    /// Adds a deposit the same as [DepositBuilder::add] with custom deposit **options**
+    pub fn add_with_options(
+        mut self,
+        amount_a: Uint128,
+        amount_b: Uint128,
+        tick_index_a_to_b: i64,
+        fee: u64,
+        options: DepositOption,
+    ) -> Self {
+        self.deposits
+            .push((amount_a, amount_b, tick_index_a_to_b, fee, options));
+        self
+    }
+
+    /// Validates the deposits against the dex **params** and creates the message
+    pub fn build(self, params: &Params) -> NeutronResult<DexMsg> {
+        check_not_paused(params)?;
+        check_pair(&self.token_a, &self.token_b)?;
+        if self.deposits.is_empty() {
+            return invalid("deposit must have at least one pool");
+        }
+
+        let pair = PairID::new(self.token_a.clone(), self.token_b.clone());
+        let reversed = pair.token0 != self.token_a;
+
+        let mut amounts_a = vec![];
+        let mut amounts_b = vec![];
+        let mut tick_indexes_a_to_b = vec![];
+        let mut fees = vec![];
+        let mut options = vec![];
+        for (amount_a, amount_b, tick_index, fee, option) in self.deposits {
+            if amount_a.is_zero() && amount_b.is_zero() {
+                return invalid("deposit amounts can't be both zero");
+            }
+            check_tick_index(tick_index)?;
+            check_fee(params, fee)?;
+
+            if reversed {
+                amounts_a.push(amount_b);
+                amounts_b.push(amount_a);
+                tick_indexes_a_to_b.push(-tick_index);
+            } else {
+                amounts_a.push(amount_a);
+                amounts_b.push(amount_b);
+                tick_indexes_a_to_b.push(tick_index);
+            }
+            fees.push(fee);
+            options.push(option);
+        }
+
+        Ok(DexMsg::Deposit {
+            receiver: self.receiver,
+            token_a: pair.token0,
+            token_b: pair.token1,
+            amounts_a,
+            amounts_b,
+            tick_indexes_a_to_b,
+            fees,
+            options,
+        })
+    }
+}
+
+/// Builds a [DexMsg::PlaceLimitOrder], an order is **GoodTilCancelled** by default.
+///
+/// ```rust ignore
+/// let msg = LimitOrderBuilder::new(receiver, "untrn", "uatom", Uint128::new(1000), tick_index)
+///     .order_type(LimitOrderType::GoodTilTime)
+///     .expiration_time(env.block.time.plus_hours(1).seconds())
+///     .build()?;
+/// ```
+pub struct LimitOrderBuilder {
+    receiver: String,
+    token_in: String,
+    token_out: String,
+    amount_in: Uint128,
+    tick_index_in_to_out: i64,
+    order_type: LimitOrderType,
+    expiration_time: Option<u64>,
+    max_amount_out: Option<Uint128>,
+    limit_sell_price: Option<PrecDec>,
+}
+
+impl LimitOrderBuilder {
+    pub fn new(
+        receiver: impl Into<String>,
+        token_in: impl Into<String>,
+        token_out: impl Into<String>,
+        amount_in: Uint128,
+        tick_index_in_to_out: i64,
+    ) -> Self {
+        LimitOrderBuilder {
+            receiver: receiver.into(),
+            token_in: token_in.into(),
+            token_out: token_out.into(),
+            amount_in,
+            tick_index_in_to_out,
+            order_type: LimitOrderType::GoodTilCancelled,
+            expiration_time: None,
+            max_amount_out: None,
+            limit_sell_price: None,
+        }
+    }
+
+    pub fn order_type(mut self, order_type: LimitOrderType) -> Self {
+        self.order_type = order_type;
+        self
+    }
+
+    /// Sets expiration time of the order in seconds, required for **GoodTilTime** orders only
+    pub fn expiration_time(mut self, expiration_time: u64) -> Self {
+        self.expiration_time = Some(expiration_time);
+        self
+    }
+
+    /// Sets maximum amount of the token out, allowed for **FillOrKill** and **ImmediateOrCancel** orders only
+    pub fn max_amount_out(mut self, max_amount_out: Uint128) -> Self {
+        self.max_amount_out = Some(max_amount_out);
+        self
+    }
+
+    /// Sets the limit sell price, which is used by the **dex** module instead of the tick index
+    pub fn limit_sell_price(mut self, limit_sell_price: PrecDec) -> Self {
+        self.limit_sell_price = Some(limit_sell_price);
+        self
+    }
+
+    /// Validates the order and creates the message
+    pub fn build(self) -> NeutronResult<DexMsg> {
+        check_pair(&self.token_in, &self.token_out)?;
+        if self.amount_in.is_zero() {
+            return invalid("limit order amount in must be positive");
+        }
+        check_tick_index(self.tick_index_in_to_out)?;
+
+        let good_til_time = self.order_type == LimitOrderType::GoodTilTime;
+        if good_til_time != self.expiration_time.is_some() {
+            return invalid("expiration time must be set for GOOD_TIL_TIME limit orders only");
+        }
+
+        let taker_only = matches!(
+            self.order_type,
+            LimitOrderType::FillOrKill | LimitOrderType::ImmediateOrCancel
+        );
+        if self.max_amount_out.is_some() && !taker_only {
+            return invalid(
+                "max amount out can be set for FILL_OR_KILL and IMMEDIATE_OR_CANCEL limit orders only",
+            );
+        }
+
+        if self
+            .limit_sell_price
+            .is_some_and(|price| price <= PrecDec::zero())
+        {
+            return invalid("limit sell price must be positive");
+        }
+
+        Ok(DexMsg::PlaceLimitOrder {
+            receiver: self.receiver,
+            token_in: self.token_in,
+            token_out: self.token_out,
+            tick_index_in_to_out: self.tick_index_in_to_out,
+            amount_in: self.amount_in,
+            order_type: self.order_type,
+            expiration_time: self.expiration_time,
+            max_amount_out: self.max_amount_out,
+            limit_sell_price: self
+                .limit_sell_price
+                .map(|price| price.to_string())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Builds a [DexMsg::MultiHopSwap] through one or more routes with the same token in and token out
+///
+/// ```rust ignore
+/// let msg = MultiHopSwapBuilder::new(receiver, Uint128::new(1000), exit_limit_price)
+///     .route(vec!["untrn", "uatom"])
+///     .route(vec!["untrn", "uosmo", "uatom"])
+///     .pick_best_route(true)
+///     .build()?;
+/// ```
+pub struct MultiHopSwapBuilder {
+    receiver: String,
+    amount_in: Uint128,
+    exit_limit_price: PrecDec,
+    routes: Vec<MultiHopRoute>,
+    pick_best_route: bool,
+}
+
+impl MultiHopSwapBuilder {
+    pub fn new(receiver: impl Into<String>, amount_in: Uint128, exit_limit_price: PrecDec) -> Self {
+        MultiHopSwapBuilder {
+            receiver: receiver.into(),
+            amount_in,
+            exit_limit_price,
+            routes: vec![],
+            pick_best_route: false,
+        }
+    }
+
+    /// Adds a route through the **hops** denoms, starting with token in and ending with token out
+    pub fn route<T: Into<String>>(mut self, hops: impl IntoIterator<Item = T>) -> Self {
+        self.routes.push(MultiHopRoute {
+            hops: hops.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// If set, all routes are run and the route with the best price is used,
+    /// otherwise the first route which satisfies the exit limit price is used
+    pub fn pick_best_route(mut self, pick_best_route: bool) -> Self {
+        self.pick_best_route = pick_best_route;
+        self
+    }
+
+    /// Validates the routes and creates the message
+    pub fn build(self) -> NeutronResult<DexMsg> {
+        if self.amount_in.is_zero() {
+            return invalid("multi hop swap amount in must be positive");
+        }
+        if self.exit_limit_price <= PrecDec::zero() {
+            return invalid("exit limit price must be positive");
+        }
+        let Some(first) = self.routes.first() else {
+            return invalid("multi hop swap must have at least one route");
+        };
+
+        for route in &self.routes {
+            if route.hops.len() < 2 {
+                return invalid("route must have at least two denoms");
+            }
+            if route.hops.first() != first.hops.first() || route.hops.last() != first.hops.last() {
+                return invalid("all routes must have the same token in and token out");
+            }
+            if route.hops.windows(2).any(|pair| pair[0] == pair[1]) {
+                return invalid("route can't swap a denom to itself");
+            }
+        }
+
+        Ok(DexMsg::MultiHopSwap {
+            receiver: self.receiver,
+            routes: self.routes,
+            amount_in: self.amount_in,
+            exit_limit_price: self.exit_limit_price,
+            pick_best_route: self.pick_best_route,
+        })
+    }
+}
+
+fn invalid<T>(reason: &str) -> NeutronResult<T> {
+    Err(NeutronError::InvalidDexMsg(reason.to_string()))
+}
+
+fn check_not_paused(params: &Params) -> NeutronResult<()> {
+    if params.paused {
+        return invalid("dex is paused");
+    }
+    Ok(())
+}
+
+fn check_pair(token_a: &str, token_b: &str) -> NeutronResult<()> {
+    if token_a.is_empty() || token_b.is_empty() {
+        return invalid("denoms can't be empty");
+    }
+    if token_a == token_b {
+        return invalid("denoms of a pair must be different");
+    }
+    Ok(())
+}
+
+fn check_tick_index(tick_index: i64) -> NeutronResult<()> {
+    if is_tick_out_of_range(tick_index) {
+        return Err(NeutronError::TickOutsideRange { tick_index });
+    }
+    Ok(())
+}
+
+fn check_fee(params: &Params, fee: u64) -> NeutronResult<()> {
+    if !params.fee_tiers.contains(&fee) {
+        return Err(NeutronError::InvalidDexMsg(format!(
+            "fee {} is not one of the allowed fee tiers {:?}",
+            fee, params.fee_tiers
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> Params {
+        Params {
+            fee_tiers: vec![0, 1, 2, 3, 4, 5, 10, 20, 50, 100, 150, 200],
+            paused: false,
+            max_jits_per_block: 25,
+            good_til_purge_allowance: 540_000,
+        }
+    }
+
+    #[test]
+    fn test_deposit() {
+        let msg = DepositBuilder::new("receiver", "untrn", "uatom")
+            .add(Uint128::new(100), Uint128::new(200), 10, 1)
+            .add_with_options(
+                Uint128::zero(),
+                Uint128::new(50),
+                -5,
+                5,
+                DepositOption { disable_swap: true },
+            )
+            .build(&params())
+            .unwrap();
+
+        // uatom < untrn, so the sides are swapped
+        assert_eq!(
+            msg,
+            DexMsg::Deposit {
+                receiver: "receiver".to_string(),
+                token_a: "uatom".to_string(),
+                token_b: "untrn".to_string(),
+                amounts_a: vec![Uint128::new(200), Uint128::new(50)],
+                amounts_b: vec![Uint128::new(100), Uint128::zero()],
+                tick_indexes_a_to_b: vec![-10, 5],
+                fees: vec![1, 5],
+                options: vec![
+                    DepositOption {
+                        disable_swap: false
+                    },
+                    DepositOption { disable_swap: true }
+                ],
+            }
+        );
+
+        assert_eq!(
+            DepositBuilder::new("receiver", "uatom", "untrn")
+                .add(Uint128::new(100), Uint128::new(200), 10, 7)
+                .build(&params())
+                .unwrap_err(),
+            NeutronError::InvalidDexMsg(
+                "fee 7 is not one of the allowed fee tiers [0, 1, 2, 3, 4, 5, 10, 20, 50, 100, 150, 200]"
+                    .to_string()
+            )
+        );
+        assert!(DepositBuilder::new("receiver", "uatom", "untrn")
+            .build(&params())
+            .is_err());
+        assert!(DepositBuilder::new("receiver", "uatom", "uatom")
+            .add(Uint128::new(100), Uint128::new(200), 10, 1)
+            .build(&params())
+            .is_err());
+
+        let mut paused = params();
+        paused.paused = true;
+        assert_eq!(
+            DepositBuilder::new("receiver", "uatom", "untrn")
+                .add(Uint128::new(100), Uint128::new(200), 10, 1)
+                .build(&paused)
+                .unwrap_err(),
+            NeutronError::InvalidDexMsg("dex is paused".to_string())
+        );
+    }
+
+    #[test]
+    fn test_limit_order() {
+        let msg = LimitOrderBuilder::new("receiver", "untrn", "uatom", Uint128::new(100), 10)
+            .order_type(LimitOrderType::GoodTilTime)
+            .expiration_time(1_700_000_000)
+            .build()
+            .unwrap();
+        assert_eq!(
+            msg,
+            DexMsg::PlaceLimitOrder {
+                receiver: "receiver".to_string(),
+                token_in: "untrn".to_string(),
+                token_out: "uatom".to_string(),
+                tick_index_in_to_out: 10,
+                amount_in: Uint128::new(100),
+                order_type: LimitOrderType::GoodTilTime,
+                expiration_time: Some(1_700_000_000),
+                max_amount_out: None,
+                limit_sell_price: "".to_string(),
+            }
+        );
+
+        // expiration time is required for good til time orders only
+        assert!(
+            LimitOrderBuilder::new("receiver", "untrn", "uatom", Uint128::new(100), 10)
+                .order_type(LimitOrderType::GoodTilTime)
+                .build()
+                .is_err()
+        );
+        assert!(
+            LimitOrderBuilder::new("receiver", "untrn", "uatom", Uint128::new(100), 10)
+                .expiration_time(1_700_000_000)
+                .build()
+                .is_err()
+        );
+
+        // max amount out is allowed for taker only orders
+        assert!(
+            LimitOrderBuilder::new("receiver", "untrn", "uatom", Uint128::new(100), 10)
+                .max_amount_out(Uint128::new(10))
+                .build()
+                .is_err()
+        );
+        assert!(
+            LimitOrderBuilder::new("receiver", "untrn", "uatom", Uint128::new(100), 10)
+                .order_type(LimitOrderType::FillOrKill)
+                .max_amount_out(Uint128::new(10))
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_multi_hop_swap() {
+        let exit_limit_price = PrecDec::one();
+        let msg = MultiHopSwapBuilder::new("receiver", Uint128::new(100), exit_limit_price)
+            .route(["untrn", "uatom"])
+            .route(["untrn", "uosmo", "uatom"])
+            .pick_best_route(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            msg,
+            DexMsg::MultiHopSwap {
+                receiver: "receiver".to_string(),
+                routes: vec![
+                    MultiHopRoute {
+                        hops: vec!["untrn".to_string(), "uatom".to_string()]
+                    },
+                    MultiHopRoute {
+                        hops: vec![
+                            "untrn".to_string(),
+                            "uosmo".to_string(),
+                            "uatom".to_string()
+                        ]
+                    },
+                ],
+                amount_in: Uint128::new(100),
+                exit_limit_price,
+                pick_best_route: true,
+            }
+        );
+
+        assert_eq!(
+            MultiHopSwapBuilder::new("receiver", Uint128::new(100), exit_limit_price)
+                .route(["untrn", "uatom"])
+                .route(["untrn", "uosmo"])
+                .build()
+                .unwrap_err(),
+            NeutronError::InvalidDexMsg(
+                "all routes must have the same token in and token out".to_string()
+            )
+        );
+        assert!(
+            MultiHopSwapBuilder::new("receiver", Uint128::new(100), exit_limit_price)
+                .build()
+                .is_err()
+        );
+    }
+}
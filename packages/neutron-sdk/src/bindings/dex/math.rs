@@ -0,0 +1,452 @@
+use crate::{
+    bindings::dex::types::{DepositRecord, PoolReserves, PrecDec},
+    errors::error::{NeutronError, NeutronResult},
+};
+use cosmwasm_std::{Int128, Int256, Int512, OverflowError, OverflowOperation, StdError};
+
+/// Maximum absolute value of a tick index supported by the **dex** module
+pub const MAX_TICK_INDEX: i64 = 559_680;
+
+/// Returns the base of the tick price formula, i.e. 1.0001
+pub fn base_price() -> PrecDec {
+    PrecDec::new(Int256::from(1_000_100_000_000_000_000_000_000_000i128))
+}
+
+/// Checks whether **tick_index** is outside of the range supported by the **dex** module
+pub fn is_tick_out_of_range(tick_index: i64) -> bool {
+    tick_index.unsigned_abs() > MAX_TICK_INDEX as u64
+}
+
+/// Calculates the taker to maker price at **tick_index**, i.e. 1.0001^-tick_index.
+///
+/// The calculation and rounding are the same as in the **dex** module:
+/// positive ticks are calculated as 1 / 1.0001^tick_index, every multiplication and division
+/// rounds half to even at the 27th decimal place.
+pub fn calc_price(tick_index: i64) -> NeutronResult<PrecDec> {
+    if is_tick_out_of_range(tick_index) {
+        return Err(NeutronError::TickOutsideRange { tick_index });
+    }
+
+    let power = pow_round(base_price(), tick_index.unsigned_abs())?;
+    if tick_index < 0 {
+        Ok(power)
+    } else {
+        quo_round(PrecDec::one(), power)
+    }
+}
+
+/// Returns the smallest price which can be represented by a tick, i.e. price at [MAX_TICK_INDEX]
+pub fn min_price() -> PrecDec {
+    // can't fail, the tick is in range
+    calc_price(MAX_TICK_INDEX).unwrap()
+}
+
+/// Returns the largest price which can be represented by a tick, i.e. price at -[MAX_TICK_INDEX]
+pub fn max_price() -> PrecDec {
+    // can't fail, the tick is in range
+    calc_price(-MAX_TICK_INDEX).unwrap()
+}
+
+/// Returns the tick index with a price nearest to the taker to maker **price**.
+/// If several ticks have the same price, the lowest of them is returned.
+/// The price decreases as the tick index grows, so prices above 1 have negative tick indexes.
+pub fn calc_tick_index_from_price(price: PrecDec) -> NeutronResult<i64> {
+    if price < min_price() || price > max_price() {
+        return Err(NeutronError::PriceOutsideRange {
+            price: price.to_string(),
+        });
+    }
+
+    // find the smallest tick with a price lower than or equal to the requested one
+    let (mut low, mut high) = (-MAX_TICK_INDEX, MAX_TICK_INDEX);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if calc_price(mid)? <= price {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    if low == -MAX_TICK_INDEX {
+        return Ok(low);
+    }
+
+    // the requested price is between the prices of the found tick and the previous one
+    let lower = calc_price(low)?;
+    let upper = calc_price(low - 1)?;
+    if upper - price < price - lower {
+        Ok(low - 1)
+    } else {
+        Ok(low)
+    }
+}
+
+/// Calculates value of **amount0** of token0 and **amount1** of token1 denominated in token0
+pub fn calc_amount_as_token0(
+    amount0: Int128,
+    amount1: Int128,
+    price1_to_0: PrecDec,
+) -> NeutronResult<PrecDec> {
+    Ok(PrecDec::from(amount0).checked_add(mul_int(price1_to_0, amount1)?)?)
+}
+
+/// Calculates amounts of token0 and token1 which are deposited into a pool with the reserves of
+/// **lower_tick0** and **upper_tick1** when up to **max_amount0** and **max_amount1** are provided.
+/// Deposits keep the ratio of the pool reserves, the same as the **dex** module does with autoswap disabled.
+pub fn calc_deposit_amounts(
+    lower_tick0: &PoolReserves,
+    upper_tick1: &PoolReserves,
+    max_amount0: Int128,
+    max_amount1: Int128,
+) -> NeutronResult<(Int128, Int128)> {
+    let reserves0 = PrecDec::from(lower_tick0.reserves_maker_denom);
+    let reserves1 = PrecDec::from(upper_tick1.reserves_maker_denom);
+    let amount0 = PrecDec::from(max_amount0);
+    let amount1 = PrecDec::from(max_amount1);
+
+    let deposit0 = if reserves1 > PrecDec::zero() {
+        amount0.min(quo_round(mul_round(amount1, reserves0)?, reserves1)?)
+    } else {
+        amount0
+    };
+    let deposit1 = if reserves0 > PrecDec::zero() {
+        amount1.min(quo_round(mul_round(amount0, reserves1)?, reserves0)?)
+    } else {
+        amount1
+    };
+
+    Ok((to_int128(deposit0)?, to_int128(deposit1)?))
+}
+
+/// Calculates shares minted for a deposit of **amount0** of token0 and **amount1** of token1 into a pool
+/// with the reserves of **lower_tick0** and **upper_tick1** and **existing_shares** total shares
+pub fn calc_shares_minted(
+    lower_tick0: &PoolReserves,
+    upper_tick1: &PoolReserves,
+    amount0: Int128,
+    amount1: Int128,
+    existing_shares: Int128,
+) -> NeutronResult<Int128> {
+    let fee = upper_tick1.key.fee.unwrap_or_default() as i64;
+    let center_tick_index_token1 = upper_tick1.key.tick_index_taker_to_maker - fee;
+    let price1_to_0_center = calc_price(-center_tick_index_token1)?;
+
+    let value_minted = calc_amount_as_token0(amount0, amount1, price1_to_0_center)?;
+    let value_existing = calc_amount_as_token0(
+        lower_tick0.reserves_maker_denom,
+        upper_tick1.reserves_maker_denom,
+        price1_to_0_center,
+    )?;
+
+    if value_existing > PrecDec::zero() {
+        to_int128(quo_round(
+            mul_int(value_minted, existing_shares)?,
+            value_existing,
+        )?)
+    } else {
+        to_int128(value_minted)
+    }
+}
+
+/// Calculates amounts of token0 and token1 withdrawn from a pool with the reserves of **lower_tick0**
+/// and **upper_tick1** when **shares** out of **total_shares** are removed
+pub fn calc_withdraw_amounts(
+    lower_tick0: &PoolReserves,
+    upper_tick1: &PoolReserves,
+    shares: Int128,
+    total_shares: Int128,
+) -> NeutronResult<(Int128, Int128)> {
+    let amount = |reserves: Int128| -> NeutronResult<Int128> {
+        reserves
+            .checked_multiply_ratio(shares, total_shares)
+            .map_err(|e| NeutronError::Std(StdError::generic_err(e.to_string())))
+    };
+
+    Ok((
+        amount(lower_tick0.reserves_maker_denom)?,
+        amount(upper_tick1.reserves_maker_denom)?,
+    ))
+}
+
+/// Calculates amounts of token0 and token1 currently owned by a deposit **record**.
+/// Returns **None** if the record is returned without its pool or total shares.
+pub fn calc_deposit_record_amounts(
+    record: &DepositRecord,
+) -> NeutronResult<Option<(Int128, Int128)>> {
+    let (Some(pool), Some(total_shares)) = (&record.pool, record.total_shares) else {
+        return Ok(None);
+    };
+    let (Some(lower_tick0), Some(upper_tick1)) = (&pool.lower_tick0, &pool.upper_tick1) else {
+        return Ok(None);
+    };
+
+    calc_withdraw_amounts(lower_tick0, upper_tick1, record.shares_owned, total_shares).map(Some)
+}
+
+fn mul_int(value: PrecDec, amount: Int128) -> Result<PrecDec, OverflowError> {
+    value.checked_mul(PrecDec::from(amount))
+}
+
+fn to_int128(value: PrecDec) -> NeutronResult<Int128> {
+    Int128::try_from(value).map_err(|e| NeutronError::Std(StdError::generic_err(e.to_string())))
+}
+
+/// Rounds **value** / **divisor** half to even, the same as **chopPrecisionAndRound** of the **dex** module
+fn round_half_even(value: Int512, divisor: Int512) -> Int512 {
+    let (quotient, remainder) = (value / divisor, value % divisor);
+    let doubled = remainder.abs() * Int512::from(2u8);
+    let odd = !(quotient % Int512::from(2u8)).is_zero();
+    if doubled > divisor.abs() || (doubled == divisor.abs() && odd) {
+        if value.is_negative() != divisor.is_negative() {
+            quotient - Int512::one()
+        } else {
+            quotient + Int512::one()
+        }
+    } else {
+        quotient
+    }
+}
+
+fn fractional() -> Int512 {
+    Int512::from(PrecDec::one().atomics())
+}
+
+fn from_int512(value: Int512, operation: OverflowOperation) -> Result<PrecDec, OverflowError> {
+    Int256::try_from(value)
+        .map(PrecDec::new)
+        .map_err(|_| OverflowError::new(operation))
+}
+
+fn mul_round(a: PrecDec, b: PrecDec) -> Result<PrecDec, OverflowError> {
+    from_int512(
+        round_half_even(a.atomics().full_mul(b.atomics()), fractional()),
+        OverflowOperation::Mul,
+    )
+}
+
+pub(crate) fn quo_round(a: PrecDec, b: PrecDec) -> NeutronResult<PrecDec> {
+    if b.is_zero() {
+        return Err(NeutronError::Std(StdError::generic_err("division by zero")));
+    }
+    // the same as the dex module, multiply by the precision twice, truncate and then round
+    let scaled = Int512::from(a.atomics())
+        .checked_mul(fractional() * fractional())
+        .map_err(|_| OverflowError::new(OverflowOperation::Mul))?;
+    let quotient = scaled / Int512::from(b.atomics());
+    Ok(from_int512(
+        round_half_even(quotient, fractional()),
+        OverflowOperation::Mul,
+    )?)
+}
+
+fn pow_round(base: PrecDec, exp: u64) -> Result<PrecDec, OverflowError> {
+    // the same exponentiation by squaring as in the dex module
+    if exp == 0 {
+        return Ok(PrecDec::one());
+    }
+
+    let mut base = base;
+    let mut acc = PrecDec::one();
+    let mut exp = exp;
+    while exp > 1 {
+        if exp % 2 != 0 {
+            acc = mul_round(acc, base)?;
+        }
+        exp /= 2;
+        base = mul_round(base, base)?;
+    }
+
+    mul_round(base, acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::dex::types::{PairID, Pool, PoolReservesKey, TradePairID};
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> PrecDec {
+        PrecDec::from_str(s).unwrap()
+    }
+
+    fn reserves(maker_denom: &str, tick_index: i64, fee: u64, amount: i128) -> PoolReserves {
+        PoolReserves {
+            key: PoolReservesKey {
+                trade_pair_id: TradePairID {
+                    maker_denom: maker_denom.to_string(),
+                    taker_denom: String::new(),
+                },
+                tick_index_taker_to_maker: tick_index,
+                fee: Some(fee),
+            },
+            reserves_maker_denom: Int128::new(amount),
+            price_taker_to_maker: calc_price(tick_index).unwrap(),
+            price_opposite_taker_to_maker: calc_price(-tick_index).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_calc_price() {
+        // 1.0001^k has 4k decimals, so up to 6 ticks away from zero the power is exact and
+        // the price is the exact value rounded half to even at the 27th decimal place
+        for (tick_index, price) in [
+            (-6, "1.000600150020001500060001"),
+            (-5, "1.00050010001000050001"),
+            (-4, "1.0004000600040001"),
+            (-3, "1.000300030001"),
+            (-2, "1.00020001"),
+            (-1, "1.0001"),
+            (0, "1"),
+            (1, "0.999900009999000099990001"),
+            (2, "0.999800029996000499940006999"),
+            (3, "0.999700059990001499790027996"),
+            (4, "0.999600099980003499440083988"),
+            (5, "0.999500149965006998740209967"),
+            (6, "0.999400209944012597480461921"),
+        ] {
+            assert_eq!(calc_price(tick_index).unwrap(), dec(price), "{tick_index}");
+        }
+
+        assert_eq!(
+            calc_price(MAX_TICK_INDEX + 1).unwrap_err(),
+            NeutronError::TickOutsideRange {
+                tick_index: MAX_TICK_INDEX + 1
+            }
+        );
+        assert_eq!(
+            calc_price(-MAX_TICK_INDEX - 1).unwrap_err(),
+            NeutronError::TickOutsideRange {
+                tick_index: -MAX_TICK_INDEX - 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_calc_price_error_bound() {
+        // exact values of 1.0001^-tick_index rounded half to even at the 27th decimal place,
+        // computed with 120 significant digits. Every multiplication of the dex algorithm rounds,
+        // so further from zero the price must stay within 1e-21 relative error or 1e-26 absolute
+        // error of them
+        for (tick_index, exact) in [
+            (10, "0.999000549780071479985003856"),
+            (100, "0.990050328741209481710348809"),
+            (-100, "1.010049662092876568855018863"),
+            (1000, "0.904841941932768878082864961"),
+            (-1000, "1.105165392603232697240184240"),
+            (10000, "0.367897834377123709894001772"),
+            (-10000, "2.718145926825224864037664675"),
+            (46054, "0.010000004406380063735001366"),
+            (-46054, "99.999955936218778826696912202"),
+            (100000, "0.0000454226338893289903418"),
+            (200000, "0.00000000206321566944401848"),
+            (-200000, "484680305.025733588332716006350490072"),
+            (300000, "0.000000000000093716689987882"),
+            (MAX_TICK_INDEX, "0.000000000000000000000000495"),
+            (
+                -MAX_TICK_INDEX,
+                "2020125331305056766452266.35838946378663066662499626",
+            ),
+        ] {
+            let exact = dec(exact);
+            let price = calc_price(tick_index).unwrap();
+            let error = if price > exact {
+                price - exact
+            } else {
+                exact - price
+            };
+            let bound = exact.checked_mul(dec("0.000000000000000000001")).unwrap()
+                + dec("0.00000000000000000000000001");
+            assert!(error <= bound, "{tick_index}: {price} vs {exact}");
+        }
+    }
+
+    #[test]
+    fn test_calc_tick_index_from_price() {
+        for tick_index in [0, 1, -1, 100, -100, 46054, -46054, -MAX_TICK_INDEX] {
+            assert_eq!(
+                calc_tick_index_from_price(calc_price(tick_index).unwrap()).unwrap(),
+                tick_index
+            );
+        }
+
+        // the highest ticks share the same price due to the precision limit
+        let tick_index = calc_tick_index_from_price(min_price()).unwrap();
+        assert!(tick_index < MAX_TICK_INDEX);
+        assert_eq!(calc_price(tick_index).unwrap(), min_price());
+
+        assert_eq!(calc_tick_index_from_price(dec("2")).unwrap(), -6932);
+        assert_eq!(calc_tick_index_from_price(dec("0.5")).unwrap(), 6932);
+        assert_eq!(calc_tick_index_from_price(dec("1.00004")).unwrap(), 0);
+        assert_eq!(calc_tick_index_from_price(dec("1.00006")).unwrap(), -1);
+
+        assert_eq!(
+            calc_tick_index_from_price(PrecDec::zero()).unwrap_err(),
+            NeutronError::PriceOutsideRange {
+                price: "0".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_deposit_and_withdraw() {
+        let lower_tick0 = reserves("untrn", 1, 1, 1_000);
+        let upper_tick1 = reserves("uatom", 1, 1, 2_000);
+
+        assert_eq!(
+            calc_deposit_amounts(
+                &lower_tick0,
+                &upper_tick1,
+                Int128::new(100),
+                Int128::new(100)
+            )
+            .unwrap(),
+            (Int128::new(50), Int128::new(100))
+        );
+
+        // the first deposit mints token0 value of the deposit: 100 + 100 * 1.0001^0
+        let empty0 = reserves("untrn", 1, 1, 0);
+        let empty1 = reserves("uatom", 1, 1, 0);
+        assert_eq!(
+            calc_shares_minted(
+                &empty0,
+                &empty1,
+                Int128::new(100),
+                Int128::new(100),
+                Int128::zero()
+            )
+            .unwrap(),
+            Int128::new(200)
+        );
+        assert_eq!(
+            calc_shares_minted(
+                &lower_tick0,
+                &upper_tick1,
+                Int128::new(50),
+                Int128::new(100),
+                Int128::new(3_000)
+            )
+            .unwrap(),
+            Int128::new(150)
+        );
+
+        let record = DepositRecord {
+            pair_id: PairID::default(),
+            shares_owned: Int128::new(1_000),
+            center_tick_index: 0,
+            lower_tick_index: -1,
+            upper_tick_index: 1,
+            fee: Some(1),
+            total_shares: Some(Int128::new(3_000)),
+            pool: Some(Pool {
+                id: 0,
+                lower_tick0: Some(lower_tick0),
+                upper_tick1: Some(upper_tick1),
+            }),
+        };
+        assert_eq!(
+            calc_deposit_record_amounts(&record).unwrap(),
+            Some((Int128::new(333), Int128::new(666)))
+        );
+    }
+}
@@ -1,3 +1,7 @@
+pub mod builder;
+pub mod math;
 pub mod msg;
+pub mod prec_dec;
 pub mod query;
+pub mod simulation;
 pub mod types;
@@ -0,0 +1,634 @@
+use cosmwasm_std::{
+    CheckedFromRatioError, CheckedMultiplyRatioError, ConversionOverflowError, Decimal256,
+    DivideByZeroError, Int128, Int256, Int512, OverflowError, OverflowOperation, StdError, Uint128,
+    Uint256,
+};
+use schemars::JsonSchema;
+use serde::{de, ser, Deserialize, Deserializer, Serialize};
+use std::cmp::Ordering;
+use std::fmt::{self, Write};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Signed fixed-point decimal with 27 fractional digits, the precision used by the **dex** module.
+///
+/// Serialized as a decimal string, e.g. `"1.0001"` or `"-0.000000000000000000000000001"`.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PrecDec(Int256);
+
+impl PrecDec {
+    /// Number of fractional digits
+    pub const DECIMAL_PLACES: u32 = 27;
+    /// 10^27
+    const DECIMAL_FRACTIONAL: Int256 = Int256::from_i128(1_000_000_000_000_000_000_000_000_000);
+
+    /// The largest value that can be represented
+    pub const MAX: Self = Self(Int256::MAX);
+    /// The smallest value that can be represented
+    pub const MIN: Self = Self(Int256::MIN);
+
+    /// Creates a PrecDec(value), i.e. **value** is the number of atomic units (10^-27)
+    pub const fn new(value: Int256) -> Self {
+        Self(value)
+    }
+
+    pub const fn zero() -> Self {
+        Self(Int256::zero())
+    }
+
+    pub const fn one() -> Self {
+        Self(Self::DECIMAL_FRACTIONAL)
+    }
+
+    /// Creates a decimal from **atomics** with the given number of **decimal_places**.
+    /// Digits beyond 27 decimal places are truncated.
+    pub fn from_atomics(
+        atomics: impl Into<Int256>,
+        decimal_places: u32,
+    ) -> Result<Self, OverflowError> {
+        let atomics = atomics.into();
+        let ten = Int256::from(10u8);
+
+        Ok(match decimal_places.cmp(&Self::DECIMAL_PLACES) {
+            Ordering::Less => {
+                // can't overflow, 10^26 fits into Int256
+                let factor = ten.pow(Self::DECIMAL_PLACES - decimal_places);
+                Self(atomics.checked_mul(factor)?)
+            }
+            Ordering::Equal => Self(atomics),
+            Ordering::Greater => match ten.checked_pow(decimal_places - Self::DECIMAL_PLACES) {
+                Ok(factor) => Self(atomics / factor),
+                // any Int256 divided by a factor out of Int256 range is zero
+                Err(_) => Self::zero(),
+            },
+        })
+    }
+
+    /// Returns the ratio **numerator** / **denominator** rounded towards zero
+    pub fn checked_from_ratio(
+        numerator: impl Into<Int256>,
+        denominator: impl Into<Int256>,
+    ) -> Result<Self, CheckedFromRatioError> {
+        numerator
+            .into()
+            .checked_multiply_ratio(Self::DECIMAL_FRACTIONAL, denominator.into())
+            .map(Self)
+            .map_err(|e| match e {
+                CheckedMultiplyRatioError::DivideByZero => CheckedFromRatioError::DivideByZero,
+                CheckedMultiplyRatioError::Overflow => CheckedFromRatioError::Overflow,
+            })
+    }
+
+    /// Returns the number of atomic units (10^-27)
+    pub const fn atomics(&self) -> Int256 {
+        self.0
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub const fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Returns the absolute value.
+    ///
+    /// # Panics
+    ///
+    /// Panics on [PrecDec::MIN], which absolute value can't be represented, see [PrecDec::checked_abs]
+    pub fn abs(&self) -> Self {
+        Self(self.0.abs())
+    }
+
+    pub fn checked_abs(self) -> Result<Self, OverflowError> {
+        if self.is_negative() {
+            self.checked_neg()
+        } else {
+            Ok(self)
+        }
+    }
+
+    pub fn checked_neg(self) -> Result<Self, OverflowError> {
+        Int256::zero().checked_sub(self.0).map(Self)
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// Computes `self * other` rounded towards zero
+    pub fn checked_mul(self, other: Self) -> Result<Self, OverflowError> {
+        let result = self.0.full_mul(other.0) / Int512::from(Self::DECIMAL_FRACTIONAL);
+        result
+            .try_into()
+            .map(Self)
+            .map_err(|_| OverflowError::new(OverflowOperation::Mul))
+    }
+
+    /// Computes `self / other` rounded towards zero
+    pub fn checked_div(self, other: Self) -> Result<Self, CheckedFromRatioError> {
+        Self::checked_from_ratio(self.0, other.0)
+    }
+
+    /// Computes `self * other` and rounds the result up (away from zero for positive results)
+    pub fn checked_mul_ceil(self, other: Self) -> Result<Self, OverflowError> {
+        let product = self.0.full_mul(other.0);
+        let fractional = Int512::from(Self::DECIMAL_FRACTIONAL);
+        let mut result = product / fractional;
+        if !(product % fractional).is_zero() && !product.is_negative() {
+            result += Int512::one();
+        }
+        result
+            .try_into()
+            .map(Self)
+            .map_err(|_| OverflowError::new(OverflowOperation::Mul))
+    }
+
+    pub fn checked_pow(self, exp: u32) -> Result<Self, OverflowError> {
+        // exponentiation by squaring
+        let mut result = Self::one();
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Self {
+        self.checked_mul(other).unwrap_or_else(|_| {
+            if self.is_negative() == other.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            }
+        })
+    }
+
+    pub fn saturating_div(self, other: Self) -> Self {
+        self.checked_div(other).unwrap_or_else(|_| {
+            if self.is_negative() == other.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            }
+        })
+    }
+
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        self.checked_pow(exp).unwrap_or_else(|_| {
+            if self.is_negative() && exp % 2 == 1 {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        })
+    }
+
+    /// Returns `1 / self`, or **DivideByZeroError** if the value is zero
+    pub fn checked_inv(self) -> Result<Self, DivideByZeroError> {
+        Self::one().checked_div(self).map_err(|_| DivideByZeroError)
+    }
+
+    /// Converts the value to an integer rounding towards zero
+    pub fn to_int_trunc(self) -> Int256 {
+        self.0 / Self::DECIMAL_FRACTIONAL
+    }
+
+    /// Converts the value to an integer rounding towards negative infinity
+    pub fn to_int_floor(self) -> Int256 {
+        let trunc = self.to_int_trunc();
+        if self.is_negative() && !(self.0 % Self::DECIMAL_FRACTIONAL).is_zero() {
+            trunc - Int256::one()
+        } else {
+            trunc
+        }
+    }
+
+    /// Converts the value to an integer rounding towards positive infinity
+    pub fn to_int_ceil(self) -> Int256 {
+        let trunc = self.to_int_trunc();
+        if !self.is_negative() && !(self.0 % Self::DECIMAL_FRACTIONAL).is_zero() {
+            trunc + Int256::one()
+        } else {
+            trunc
+        }
+    }
+
+    /// Multiplies an integer **amount** by the value, rounding towards zero
+    pub fn checked_mul_int(self, amount: impl Into<Int256>) -> Result<Int256, OverflowError> {
+        self.checked_mul(Self::from_int(amount.into())?)
+            .map(Self::to_int_trunc)
+    }
+
+    fn from_int(value: Int256) -> Result<Self, OverflowError> {
+        value.checked_mul(Self::DECIMAL_FRACTIONAL).map(Self)
+    }
+}
+
+impl FromStr for PrecDec {
+    type Err = StdError;
+
+    /// Parses a decimal string, e.g. "1.23", "-1", "0.000000000000000000000000001".
+    /// More than 27 fractional digits result in an error.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (is_neg, unsigned) = match input.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, input),
+        };
+        let mut parts = unsigned.split('.');
+
+        // split always returns at least one element
+        let whole = parts.next().unwrap();
+        if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(StdError::generic_err("Error parsing whole"));
+        }
+        let mut atomics = Uint256::from_str(whole)?
+            .checked_mul(Self::DECIMAL_FRACTIONAL.unsigned_abs())
+            .map_err(|_| StdError::generic_err("Value too big"))?;
+
+        if let Some(fractional) = parts.next() {
+            if fractional.is_empty() || !fractional.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(StdError::generic_err("Error parsing fractional"));
+            }
+            let exp = Self::DECIMAL_PLACES
+                .checked_sub(fractional.len() as u32)
+                .ok_or_else(|| {
+                    StdError::generic_err(format!(
+                        "Cannot parse more than {} fractional digits",
+                        Self::DECIMAL_PLACES
+                    ))
+                })?;
+            // can't overflow, the fractional part is less than 10^27
+            atomics = atomics
+                .checked_add(Uint256::from_str(fractional)? * Uint256::from(10u8).pow(exp))
+                .map_err(|_| StdError::generic_err("Value too big"))?;
+        }
+
+        if parts.next().is_some() {
+            return Err(StdError::generic_err("Unexpected number of dots"));
+        }
+
+        let atomics = if is_neg && atomics == Int256::MIN.unsigned_abs() {
+            // Int256::MIN has no positive counterpart
+            Int256::MIN
+        } else {
+            let atomics =
+                Int256::try_from(atomics).map_err(|_| StdError::generic_err("Value too big"))?;
+            if is_neg {
+                -atomics
+            } else {
+                atomics
+            }
+        };
+
+        Ok(PrecDec(atomics))
+    }
+}
+
+impl fmt::Display for PrecDec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unsigned = self.0.unsigned_abs();
+        let fractional_unit = Self::DECIMAL_FRACTIONAL.unsigned_abs();
+        let whole = unsigned / fractional_unit;
+        let fractional = unsigned % fractional_unit;
+
+        if self.is_negative() {
+            f.write_char('-')?;
+        }
+        if fractional.is_zero() {
+            write!(f, "{whole}")
+        } else {
+            let fractional = format!(
+                "{:0>padding$}",
+                fractional,
+                padding = Self::DECIMAL_PLACES as usize
+            );
+            write!(f, "{whole}.{}", fractional.trim_end_matches('0'))
+        }
+    }
+}
+
+impl fmt::Debug for PrecDec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PrecDec({self})")
+    }
+}
+
+impl Serialize for PrecDec {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PrecDec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        PrecDec::from_str(&s).map_err(|e| de::Error::custom(format!("invalid PrecDec: {e}")))
+    }
+}
+
+impl JsonSchema for PrecDec {
+    fn schema_name() -> String {
+        "PrecDec".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+impl From<PrecDec> for String {
+    fn from(value: PrecDec) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for PrecDec {
+    type Error = StdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        PrecDec::from_str(&value)
+    }
+}
+
+impl From<Uint128> for PrecDec {
+    fn from(value: Uint128) -> Self {
+        // can't overflow, Uint128::MAX * 10^27 fits into Int256
+        PrecDec(Int256::from(value.u128()) * Self::DECIMAL_FRACTIONAL)
+    }
+}
+
+impl From<Int128> for PrecDec {
+    fn from(value: Int128) -> Self {
+        // can't overflow, Int128::MIN * 10^27 fits into Int256
+        PrecDec(Int256::from(value.i128()) * Self::DECIMAL_FRACTIONAL)
+    }
+}
+
+impl TryFrom<Decimal256> for PrecDec {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: Decimal256) -> Result<Self, Self::Error> {
+        Int256::try_from(value.atomics())
+            .ok()
+            .and_then(|atomics| PrecDec::from_atomics(atomics, Decimal256::DECIMAL_PLACES).ok())
+            .ok_or_else(|| ConversionOverflowError::new("Decimal256", "PrecDec"))
+    }
+}
+
+impl TryFrom<PrecDec> for Decimal256 {
+    type Error = ConversionOverflowError;
+
+    /// Digits beyond 18 decimal places are truncated. Negative values can't be converted.
+    fn try_from(value: PrecDec) -> Result<Self, Self::Error> {
+        let factor = Int256::from(10u8).pow(PrecDec::DECIMAL_PLACES - Decimal256::DECIMAL_PLACES);
+        Uint256::try_from(value.0 / factor)
+            .map(Decimal256::new)
+            .map_err(|_| ConversionOverflowError::new("PrecDec", "Decimal256"))
+    }
+}
+
+impl TryFrom<PrecDec> for Uint128 {
+    type Error = ConversionOverflowError;
+
+    /// The fractional part is truncated. Negative values can't be converted.
+    fn try_from(value: PrecDec) -> Result<Self, Self::Error> {
+        Uint256::try_from(value.to_int_trunc())
+            .ok()
+            .and_then(|v| Uint128::try_from(v).ok())
+            .ok_or_else(|| ConversionOverflowError::new("PrecDec", "Uint128"))
+    }
+}
+
+impl TryFrom<PrecDec> for Int128 {
+    type Error = ConversionOverflowError;
+
+    /// The fractional part is truncated
+    fn try_from(value: PrecDec) -> Result<Self, Self::Error> {
+        Int128::try_from(value.to_int_trunc())
+            .map_err(|_| ConversionOverflowError::new("PrecDec", "Int128"))
+    }
+}
+
+impl Add for PrecDec {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("attempt to add with overflow")
+    }
+}
+
+impl AddAssign for PrecDec {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for PrecDec {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl SubAssign for PrecDec {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for PrecDec {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl Div for PrecDec {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        match self.checked_div(other) {
+            Ok(value) => value,
+            Err(CheckedFromRatioError::DivideByZero) => panic!("attempt to divide by zero"),
+            Err(CheckedFromRatioError::Overflow) => panic!("attempt to divide with overflow"),
+        }
+    }
+}
+
+/// # Panics
+///
+/// Panics on [PrecDec::MIN], which negation can't be represented, see [PrecDec::checked_neg]
+impl Neg for PrecDec {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{from_json, to_json_string};
+
+    fn dec(s: &str) -> PrecDec {
+        PrecDec::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for (input, output) in [
+            ("0", "0"),
+            ("1", "1"),
+            ("1.0001", "1.0001"),
+            ("-1.5", "-1.5"),
+            ("-0.25", "-0.25"),
+            ("000012.3400", "12.34"),
+            (
+                "0.000000000000000000000000001",
+                "0.000000000000000000000000001",
+            ),
+            ("1.000000000000000000000000000", "1"),
+        ] {
+            assert_eq!(dec(input).to_string(), output);
+        }
+
+        assert_eq!(PrecDec::MAX, dec(&PrecDec::MAX.to_string()));
+        assert_eq!(PrecDec::MIN, dec(&PrecDec::MIN.to_string()));
+
+        for invalid in [
+            "",
+            ".1",
+            "1.",
+            "1.2.3",
+            "+1",
+            "--1",
+            "1e5",
+            "0.0000000000000000000000000001",
+        ] {
+            assert!(PrecDec::from_str(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_eq!(to_json_string(&dec("1.0001")).unwrap(), "\"1.0001\"");
+        assert_eq!(
+            from_json::<PrecDec>("\"0.500000000000000000000000000\"").unwrap(),
+            dec("0.5")
+        );
+        assert!(from_json::<PrecDec>("\"abc\"").is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(dec("1.5") + dec("2.25"), dec("3.75"));
+        assert_eq!(dec("1.5") - dec("2.25"), dec("-0.75"));
+        assert_eq!(dec("1.5") * dec("-2"), dec("-3"));
+        assert_eq!(dec("1") / dec("3"), dec("0.333333333333333333333333333"));
+        assert_eq!(
+            dec("0.5")
+                .checked_mul_ceil(dec("0.000000000000000000000000001"))
+                .unwrap(),
+            dec("0.000000000000000000000000001")
+        );
+        assert_eq!(
+            dec("0.5")
+                .checked_mul(dec("0.000000000000000000000000001"))
+                .unwrap(),
+            PrecDec::zero()
+        );
+        assert_eq!(dec("1.0001").checked_pow(2).unwrap(), dec("1.00020001"));
+        assert_eq!(dec("2").checked_inv().unwrap(), dec("0.5"));
+
+        assert_eq!(
+            dec("1").checked_div(PrecDec::zero()),
+            Err(CheckedFromRatioError::DivideByZero)
+        );
+        assert!(PrecDec::MAX.checked_add(dec("1")).is_err());
+        assert!(PrecDec::MAX.checked_mul(dec("2")).is_err());
+        assert_eq!(PrecDec::MAX.saturating_add(dec("1")), PrecDec::MAX);
+        assert_eq!(PrecDec::MIN.saturating_sub(dec("1")), PrecDec::MIN);
+        assert_eq!(PrecDec::MAX.saturating_mul(dec("-2")), PrecDec::MIN);
+        assert_eq!(dec("1").saturating_div(PrecDec::zero()), PrecDec::MAX);
+
+        assert_eq!(dec("-1.5").checked_abs().unwrap(), dec("1.5"));
+        assert_eq!(dec("1.5").checked_neg().unwrap(), dec("-1.5"));
+        assert_eq!(PrecDec::MAX.checked_neg().unwrap(), -PrecDec::MAX);
+        assert!(PrecDec::MIN.checked_neg().is_err());
+        assert!(PrecDec::MIN.checked_abs().is_err());
+
+        assert!(dec("-0.1") < PrecDec::zero());
+        assert!(dec("1.0001") > dec("1"));
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(dec("1.5").to_int_floor(), Int256::from(1));
+        assert_eq!(dec("1.5").to_int_ceil(), Int256::from(2));
+        assert_eq!(dec("-1.5").to_int_floor(), Int256::from(-2));
+        assert_eq!(dec("-1.5").to_int_ceil(), Int256::from(-1));
+        assert_eq!(dec("-1.5").to_int_trunc(), Int256::from(-1));
+        assert_eq!(dec("2").to_int_ceil(), Int256::from(2));
+        assert_eq!(
+            dec("1.0001").checked_mul_int(10_000i128).unwrap(),
+            Int256::from(10_001)
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(PrecDec::from(Uint128::new(42)), dec("42"));
+        assert_eq!(PrecDec::from(Int128::new(-42)), dec("-42"));
+        assert_eq!(Uint128::try_from(dec("42.9")).unwrap(), Uint128::new(42));
+        assert_eq!(Int128::try_from(dec("-42.9")).unwrap(), Int128::new(-42));
+        assert!(Uint128::try_from(dec("-1")).is_err());
+
+        let decimal = Decimal256::from_str("1.000000000000000001").unwrap();
+        assert_eq!(
+            PrecDec::try_from(decimal).unwrap(),
+            dec("1.000000000000000001")
+        );
+        assert_eq!(
+            Decimal256::try_from(dec("1.000000000000000001999")).unwrap(),
+            decimal
+        );
+        assert!(Decimal256::try_from(dec("-1")).is_err());
+        assert!(PrecDec::try_from(Decimal256::MAX).is_err());
+
+        assert_eq!(
+            PrecDec::from_atomics(Int256::from(15), 1).unwrap(),
+            dec("1.5")
+        );
+        assert_eq!(
+            PrecDec::from_atomics(Int256::from(15), 28).unwrap(),
+            dec("0.000000000000000000000000001")
+        );
+    }
+}
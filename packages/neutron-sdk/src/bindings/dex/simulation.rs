@@ -0,0 +1,559 @@
+use crate::{
+    bindings::dex::{
+        math::quo_round,
+        types::{Liquidity, MultiHopRoute, PrecDec, TickLiquidity, TradePairID},
+    },
+    errors::error::{NeutronError, NeutronResult},
+};
+use cosmwasm_std::{Int128, StdError};
+use std::collections::BTreeMap;
+
+/// Result of a simulated taker swap through a single trade pair
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapSimulation {
+    /// Amount of the token in used by the swap
+    pub amount_in: Int128,
+    /// Amount of the token out received from the swap
+    pub amount_out: Int128,
+    /// Tick indexes (taker to maker) the swap took liquidity from, in the order they were crossed
+    pub ticks_crossed: Vec<i64>,
+}
+
+impl SwapSimulation {
+    /// Returns amount out per one token in, or zero if nothing was swapped
+    pub fn effective_price(&self) -> PrecDec {
+        effective_price(self.amount_in, self.amount_out)
+    }
+}
+
+/// Result of a simulated swap through all hops of a [MultiHopRoute]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteSimulation {
+    /// Amount of the first token in offered to the route
+    pub amount_in: Int128,
+    /// Amount of the last token out received from the route
+    pub amount_out: Int128,
+    /// Simulations of every hop of the route
+    pub hops: Vec<SwapSimulation>,
+}
+
+impl RouteSimulation {
+    /// Returns amount out per one token in, or zero if nothing was swapped
+    pub fn effective_price(&self) -> PrecDec {
+        effective_price(self.amount_in, self.amount_out)
+    }
+
+    /// Checks whether every hop swapped the whole amount it received, i.e. there was enough liquidity
+    pub fn is_filled(&self) -> bool {
+        let mut offered = self.amount_in;
+        for hop in &self.hops {
+            if hop.amount_in != offered {
+                return false;
+            }
+            offered = hop.amount_out;
+        }
+        true
+    }
+}
+
+/// Simulates a taker swap of **amount_in** of **token_in** against **liquidity** returned by
+/// `DexQuery::TickLiquidityAll`. Liquidity is taken from the best price (the lowest tick index) on,
+/// the same as the **dex** module does: within a tick pool reserves go first ordered by fee,
+/// then limit order tranches ordered by tranche key. Liquidity with the taker to maker price below **limit_price**
+/// isn't used. Items of other trade pairs are ignored.
+pub fn simulate_swap(
+    liquidity: &[TickLiquidity],
+    token_in: &str,
+    amount_in: Int128,
+    limit_price: Option<PrecDec>,
+) -> NeutronResult<SwapSimulation> {
+    let mut items: Vec<&TickLiquidity> = liquidity
+        .iter()
+        .filter(|l| liquidity_key(l).0.taker_denom == token_in)
+        .collect();
+    items.sort_by(|a, b| liquidity_order(a).cmp(&liquidity_order(b)));
+
+    let mut remaining = amount_in;
+    let mut simulation = SwapSimulation {
+        amount_in: Int128::zero(),
+        amount_out: Int128::zero(),
+        ticks_crossed: vec![],
+    };
+
+    for item in items {
+        if remaining <= Int128::zero() {
+            break;
+        }
+
+        let (_, tick_index, reserves, price) = liquidity_key(item);
+        if limit_price.is_some_and(|limit| price < limit) {
+            break;
+        }
+        if reserves <= Int128::zero() || price <= PrecDec::zero() {
+            continue;
+        }
+
+        let (used_in, out) = swap_liquidity(reserves, price, remaining)?;
+        if out.is_zero() {
+            continue;
+        }
+
+        remaining -= used_in;
+        simulation.amount_in += used_in;
+        simulation.amount_out += out;
+        if simulation.ticks_crossed.last() != Some(&tick_index) {
+            simulation.ticks_crossed.push(tick_index);
+        }
+    }
+
+    Ok(simulation)
+}
+
+/// Liquidity of the **dex** module grouped by trade pairs. Load it with `DexQuery::TickLiquidityAll`
+/// pages of the pairs of interest and simulate swaps through any number of routes without
+/// further queries.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LiquidityBook {
+    /// (taker denom, maker denom) -> liquidity
+    liquidity: BTreeMap<(String, String), Vec<TickLiquidity>>,
+}
+
+impl LiquidityBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds **liquidity**, e.g. items of an `AllTickLiquidityResponse` page
+    pub fn add(&mut self, liquidity: impl IntoIterator<Item = TickLiquidity>) -> &mut Self {
+        for item in liquidity {
+            let pair = liquidity_key(&item).0;
+            self.liquidity
+                .entry((pair.taker_denom.clone(), pair.maker_denom.clone()))
+                .or_default()
+                .push(item);
+        }
+        self
+    }
+
+    /// Keeps only the liquidity for which **f** returns true, e.g. to exclude denylisted pools
+    pub fn retain(&mut self, f: impl Fn(&TickLiquidity) -> bool) -> &mut Self {
+        for items in self.liquidity.values_mut() {
+            items.retain(&f);
+        }
+        self
+    }
+
+    /// Simulates a taker swap of **amount_in** of **token_in** to **token_out**, see [simulate_swap]
+    pub fn simulate_swap(
+        &self,
+        token_in: &str,
+        token_out: &str,
+        amount_in: Int128,
+        limit_price: Option<PrecDec>,
+    ) -> NeutronResult<SwapSimulation> {
+        let liquidity = self
+            .liquidity
+            .get(&(token_in.to_string(), token_out.to_string()))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        simulate_swap(liquidity, token_in, amount_in, limit_price)
+    }
+
+    /// Simulates a swap of **amount_in** of the first denom of the **route** through all of its hops
+    pub fn simulate_route(
+        &self,
+        route: &MultiHopRoute,
+        amount_in: Int128,
+    ) -> NeutronResult<RouteSimulation> {
+        if route.hops.len() < 2 {
+            return Err(NeutronError::Std(StdError::generic_err(
+                "route must have at least two denoms",
+            )));
+        }
+
+        let mut hops = Vec::with_capacity(route.hops.len() - 1);
+        let mut offered = amount_in;
+        for pair in route.hops.windows(2) {
+            let hop = self.simulate_swap(&pair[0], &pair[1], offered, None)?;
+            offered = hop.amount_out;
+            hops.push(hop);
+        }
+
+        Ok(RouteSimulation {
+            amount_in,
+            amount_out: offered,
+            hops,
+        })
+    }
+
+    /// Simulates all **routes** and returns the one with the largest amount out among the routes
+    /// accepted by **accept**, e.g. a slippage check. Returns **None** if no route is accepted.
+    pub fn best_route<'r>(
+        &self,
+        routes: &'r [MultiHopRoute],
+        amount_in: Int128,
+        accept: impl Fn(&MultiHopRoute, &RouteSimulation) -> bool,
+    ) -> NeutronResult<Option<(&'r MultiHopRoute, RouteSimulation)>> {
+        let mut best: Option<(&MultiHopRoute, RouteSimulation)> = None;
+        for route in routes {
+            let simulation = self.simulate_route(route, amount_in)?;
+            if !accept(route, &simulation) {
+                continue;
+            }
+            if best
+                .as_ref()
+                .map_or(true, |(_, b)| simulation.amount_out > b.amount_out)
+            {
+                best = Some((route, simulation));
+            }
+        }
+
+        Ok(best)
+    }
+}
+
+/// Returns trade pair, tick index, maker reserves and taker to maker price of the **liquidity**
+fn liquidity_key(liquidity: &TickLiquidity) -> (&TradePairID, i64, Int128, PrecDec) {
+    match &liquidity.liquidity {
+        Liquidity::PoolReserves(r) => (
+            &r.key.trade_pair_id,
+            r.key.tick_index_taker_to_maker,
+            r.reserves_maker_denom,
+            r.price_taker_to_maker,
+        ),
+        Liquidity::LimitOrderTranche(t) => (
+            &t.key.trade_pair_id,
+            t.key.tick_index_taker_to_maker,
+            t.reserves_maker_denom,
+            t.price_taker_to_maker,
+        ),
+    }
+}
+
+/// Returns the position of the **liquidity** in the **dex** module store: tick index,
+/// then pool reserves before limit order tranches, then fee or tranche key
+fn liquidity_order(liquidity: &TickLiquidity) -> (i64, u8, u64, &str) {
+    match &liquidity.liquidity {
+        Liquidity::PoolReserves(r) => (
+            r.key.tick_index_taker_to_maker,
+            0,
+            r.key.fee.unwrap_or_default(),
+            "",
+        ),
+        Liquidity::LimitOrderTranche(t) => (
+            t.key.tick_index_taker_to_maker,
+            1,
+            0,
+            t.key.tranche_key.as_str(),
+        ),
+    }
+}
+
+/// Swaps up to **max_in** against **reserves** at **price** the same as the **dex** module does:
+/// the amount out is rounded down and the amount in needed for it is rounded up
+fn swap_liquidity(
+    reserves: Int128,
+    price: PrecDec,
+    max_in: Int128,
+) -> NeutronResult<(Int128, Int128)> {
+    let max_out = Int128::try_from(price.checked_mul(PrecDec::from(max_in))?)
+        .map_err(|e| NeutronError::Std(StdError::generic_err(e.to_string())))?;
+    let out = reserves.min(max_out);
+
+    let used_in = quo_round(PrecDec::from(out), price)?.to_int_ceil();
+    let used_in = Int128::try_from(used_in)
+        .map_err(|e| NeutronError::Std(StdError::generic_err(e.to_string())))?;
+
+    Ok((used_in.min(max_in), out))
+}
+
+fn effective_price(amount_in: Int128, amount_out: Int128) -> PrecDec {
+    PrecDec::checked_from_ratio(amount_out.i128(), amount_in.i128()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::dex::math::calc_price;
+    use crate::bindings::dex::types::{
+        LimitOrderTranche, LimitOrderTrancheKey, PoolReserves, PoolReservesKey,
+    };
+    use std::str::FromStr;
+
+    fn pair(token_in: &str, token_out: &str) -> TradePairID {
+        TradePairID {
+            maker_denom: token_out.to_string(),
+            taker_denom: token_in.to_string(),
+        }
+    }
+
+    fn pool(token_in: &str, token_out: &str, tick_index: i64, reserves: i128) -> TickLiquidity {
+        pool_with_fee(token_in, token_out, tick_index, 1, reserves)
+    }
+
+    fn pool_with_fee(
+        token_in: &str,
+        token_out: &str,
+        tick_index: i64,
+        fee: u64,
+        reserves: i128,
+    ) -> TickLiquidity {
+        TickLiquidity {
+            liquidity: Liquidity::PoolReserves(PoolReserves {
+                key: PoolReservesKey {
+                    trade_pair_id: pair(token_in, token_out),
+                    tick_index_taker_to_maker: tick_index,
+                    fee: Some(fee),
+                },
+                reserves_maker_denom: Int128::new(reserves),
+                price_taker_to_maker: calc_price(tick_index).unwrap(),
+                price_opposite_taker_to_maker: calc_price(-tick_index).unwrap(),
+            }),
+        }
+    }
+
+    fn tranche(token_in: &str, token_out: &str, tick_index: i64, reserves: i128) -> TickLiquidity {
+        tranche_with_key(token_in, token_out, tick_index, "tranche", reserves)
+    }
+
+    fn tranche_with_key(
+        token_in: &str,
+        token_out: &str,
+        tick_index: i64,
+        tranche_key: &str,
+        reserves: i128,
+    ) -> TickLiquidity {
+        TickLiquidity {
+            liquidity: Liquidity::LimitOrderTranche(LimitOrderTranche {
+                key: LimitOrderTrancheKey {
+                    trade_pair_id: pair(token_in, token_out),
+                    tick_index_taker_to_maker: tick_index,
+                    tranche_key: tranche_key.to_string(),
+                },
+                reserves_maker_denom: Int128::new(reserves),
+                reserves_taker_denom: Int128::zero(),
+                total_maker_denom: Int128::new(reserves),
+                total_taker_denom: Int128::zero(),
+                expiration_time: None,
+                price_taker_to_maker: calc_price(tick_index).unwrap(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_simulate_swap() {
+        // 1 untrn = 2 uatom at tick -6932, 1 untrn ~ 1 uatom at tick 0
+        let liquidity = vec![
+            pool("untrn", "uatom", 0, 1_000),
+            tranche("untrn", "uatom", -6932, 100),
+            pool("uatom", "untrn", -6932, 1_000_000),
+        ];
+
+        let simulation = simulate_swap(&liquidity, "untrn", Int128::new(100), None).unwrap();
+        assert_eq!(simulation.ticks_crossed, vec![-6932, 0]);
+        // 50 untrn for 100 uatom of the tranche, and the rest at price 1
+        assert_eq!(simulation.amount_in, Int128::new(100));
+        assert_eq!(simulation.amount_out, Int128::new(150));
+        assert_eq!(
+            simulation.effective_price(),
+            PrecDec::from_str("1.5").unwrap()
+        );
+
+        // liquidity below the limit price isn't used
+        let simulation = simulate_swap(
+            &liquidity,
+            "untrn",
+            Int128::new(100),
+            Some(PrecDec::from_str("1.5").unwrap()),
+        )
+        .unwrap();
+        assert_eq!(simulation.amount_in, Int128::new(50));
+        assert_eq!(simulation.amount_out, Int128::new(100));
+        assert_eq!(simulation.ticks_crossed, vec![-6932]);
+    }
+
+    #[test]
+    fn test_simulate_swap_limit_price() {
+        let liquidity = vec![
+            pool("untrn", "uatom", -6932, 100),
+            pool("untrn", "uatom", 0, 1_000),
+        ];
+
+        // the limit price is inclusive
+        let simulation = simulate_swap(
+            &liquidity,
+            "untrn",
+            Int128::new(100),
+            Some(calc_price(0).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(simulation.ticks_crossed, vec![-6932, 0]);
+        assert_eq!(simulation.amount_out, Int128::new(150));
+
+        // the limit price is above the price of all liquidity
+        let simulation = simulate_swap(
+            &liquidity,
+            "untrn",
+            Int128::new(100),
+            Some(PrecDec::from_str("3").unwrap()),
+        )
+        .unwrap();
+        assert_eq!(
+            simulation,
+            SwapSimulation {
+                amount_in: Int128::zero(),
+                amount_out: Int128::zero(),
+                ticks_crossed: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_simulate_swap_same_tick() {
+        // the price at tick 1 is 0.9999, so the order of liquidity matters for rounding
+        let liquidity = vec![
+            tranche_with_key("untrn", "uatom", 1, "b", 1_000),
+            tranche_with_key("untrn", "uatom", 1, "a", 1),
+            pool_with_fee("untrn", "uatom", 1, 5, 1_000),
+            pool_with_fee("untrn", "uatom", 1, 1, 1),
+        ];
+
+        let mut items: Vec<&TickLiquidity> = liquidity.iter().collect();
+        items.sort_by(|a, b| liquidity_order(a).cmp(&liquidity_order(b)));
+        assert_eq!(
+            items.iter().map(|l| liquidity_order(l)).collect::<Vec<_>>(),
+            vec![(1, 0, 1, ""), (1, 0, 5, ""), (1, 1, 0, "a"), (1, 1, 0, "b")]
+        );
+
+        // pool with fee 1 gives 1 uatom for 2 untrn, the pool with fee 5 gives 7 uatom for 8 untrn
+        let simulation = simulate_swap(&liquidity, "untrn", Int128::new(10), None).unwrap();
+        assert_eq!(simulation.amount_in, Int128::new(10));
+        assert_eq!(simulation.amount_out, Int128::new(8));
+        assert_eq!(simulation.ticks_crossed, vec![1]);
+
+        // the pool reserves and both tranches are used up
+        let simulation = simulate_swap(&liquidity, "untrn", Int128::new(3_000), None).unwrap();
+        assert_eq!(simulation.amount_out, Int128::new(2_002));
+        assert_eq!(simulation.ticks_crossed, vec![1]);
+    }
+
+    #[test]
+    fn test_routes() {
+        let mut book = LiquidityBook::new();
+        book.add(vec![
+            pool("untrn", "uatom", 0, 1_000),
+            pool("untrn", "uosmo", -6932, 1_000),
+            pool("uosmo", "uatom", 0, 1_000),
+            pool("uosmo", "uatom", -100, 10),
+        ]);
+
+        let direct = MultiHopRoute {
+            hops: vec!["untrn".to_string(), "uatom".to_string()],
+        };
+        let via_osmo = MultiHopRoute {
+            hops: vec![
+                "untrn".to_string(),
+                "uosmo".to_string(),
+                "uatom".to_string(),
+            ],
+        };
+
+        let simulation = book.simulate_route(&via_osmo, Int128::new(100)).unwrap();
+        assert!(simulation.is_filled());
+        assert_eq!(simulation.hops[0].amount_out, Int128::new(200));
+        assert_eq!(simulation.hops[1].ticks_crossed, vec![-100, 0]);
+        assert_eq!(simulation.amount_out, Int128::new(200));
+
+        let routes = vec![direct.clone(), via_osmo.clone()];
+        let (best, _) = book
+            .best_route(&routes, Int128::new(100), |_, _| true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(best, &via_osmo);
+
+        // exclude the uosmo/uatom pools
+        book.retain(|l| {
+            liquidity_key(l).0.maker_denom != "uatom" || liquidity_key(l).0.taker_denom != "uosmo"
+        });
+        let (best, simulation) = book
+            .best_route(&routes, Int128::new(100), |_, s| s.is_filled())
+            .unwrap()
+            .unwrap();
+        assert_eq!(best, &direct);
+        assert_eq!(simulation.amount_out, Int128::new(100));
+
+        assert!(book
+            .simulate_route(
+                &MultiHopRoute {
+                    hops: vec!["untrn".to_string()]
+                },
+                Int128::new(100)
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_multi_hop_route() {
+        let mut book = LiquidityBook::new();
+        book.add(vec![
+            pool("untrn", "uosmo", -6932, 1_000),
+            pool("uosmo", "uusdc", 0, 150),
+            pool("uusdc", "uatom", -6932, 1_000),
+        ]);
+        let route = MultiHopRoute {
+            hops: vec![
+                "untrn".to_string(),
+                "uosmo".to_string(),
+                "uusdc".to_string(),
+                "uatom".to_string(),
+            ],
+        };
+
+        // 100 untrn -> 200 uosmo -> 150 uusdc, the uosmo/uusdc pool runs out -> 300 uatom
+        let simulation = book.simulate_route(&route, Int128::new(100)).unwrap();
+        assert_eq!(
+            simulation
+                .hops
+                .iter()
+                .map(|hop| (hop.amount_in, hop.amount_out))
+                .collect::<Vec<_>>(),
+            vec![
+                (Int128::new(100), Int128::new(200)),
+                (Int128::new(150), Int128::new(150)),
+                (Int128::new(150), Int128::new(300)),
+            ]
+        );
+        assert_eq!(simulation.amount_out, Int128::new(300));
+        assert!(!simulation.is_filled());
+        assert_eq!(
+            simulation.effective_price(),
+            PrecDec::from_str("3").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_route_without_liquidity() {
+        let mut book = LiquidityBook::new();
+        book.add(vec![pool("untrn", "uosmo", 0, 1_000)]);
+        let route = MultiHopRoute {
+            hops: vec![
+                "untrn".to_string(),
+                "uosmo".to_string(),
+                "uatom".to_string(),
+            ],
+        };
+
+        let simulation = book.simulate_route(&route, Int128::new(100)).unwrap();
+        assert_eq!(simulation.amount_out, Int128::zero());
+        assert_eq!(simulation.hops[1].amount_in, Int128::zero());
+        assert!(!simulation.is_filled());
+        assert_eq!(simulation.effective_price(), PrecDec::zero());
+
+        assert_eq!(
+            book.best_route(&[route], Int128::new(100), |_, s| s.is_filled())
+                .unwrap(),
+            None
+        );
+    }
+}
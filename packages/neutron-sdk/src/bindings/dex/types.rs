@@ -2,6 +2,8 @@ use cosmwasm_std::Int128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+pub use super::prec_dec::PrecDec;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum LimitOrderType {
@@ -78,28 +80,6 @@ pub struct TradePairID {
     pub taker_denom: String,
 }
 
-// TODO implement math for PrecDec
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-#[serde(from = "String")]
-#[serde(into = "String")]
-pub struct PrecDec {
-    pub i: String,
-}
-
-#[allow(clippy::from_over_into)]
-impl Into<String> for PrecDec {
-    fn into(self) -> String {
-        self.i
-    }
-}
-
-impl From<String> for PrecDec {
-    fn from(value: String) -> Self {
-        PrecDec { i: value }
-    }
-}
-
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct DepositOption {
@@ -160,6 +140,31 @@ pub struct PairID {
     pub token1: String,
 }
 
+impl PairID {
+    /// Creates a pair of **token_a** and **token_b** ordered the same as in the **dex** module
+    pub fn new(token_a: impl Into<String>, token_b: impl Into<String>) -> Self {
+        let (token_a, token_b) = (token_a.into(), token_b.into());
+        if token_a <= token_b {
+            PairID {
+                token0: token_a,
+                token1: token_b,
+            }
+        } else {
+            PairID {
+                token0: token_b,
+                token1: token_a,
+            }
+        }
+    }
+}
+
+/// Formats the pair as it's expected by **pair_id** fields of the dex queries, i.e. `token0<>token1`
+impl std::fmt::Display for PairID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}<>{}", self.token0, self.token1)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct TickLiquidity {
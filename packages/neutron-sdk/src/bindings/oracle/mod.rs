@@ -1,2 +1,4 @@
+pub mod price;
 pub mod query;
+pub mod twap;
 pub mod types;
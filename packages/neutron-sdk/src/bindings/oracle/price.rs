@@ -0,0 +1,425 @@
+use crate::bindings::marketmap::query::{MarketMapQuery, MarketResponse};
+use crate::bindings::oracle::query::{GetPriceResponse, OracleQuery};
+use crate::bindings::oracle::types::CurrencyPair;
+use crate::bindings::query::NeutronQuery;
+use crate::{NeutronError, NeutronResult};
+use cosmwasm_std::{Decimal256, Deps, Env, Int128, Uint256};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Checks applied to an oracle price before it is returned by [`query_price`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PriceValidation {
+    /// **max_age_blocks** is the max number of blocks since the last price update,
+    /// `None` disables the staleness check
+    pub max_age_blocks: Option<u64>,
+    /// **min_nonce** is the min number of price updates the currency pair must have had
+    pub min_nonce: u64,
+    /// **require_enabled** makes the query fail if the market is disabled in the market map
+    pub require_enabled: bool,
+}
+
+impl Default for PriceValidation {
+    fn default() -> Self {
+        PriceValidation {
+            max_age_blocks: None,
+            min_nonce: 1,
+            require_enabled: true,
+        }
+    }
+}
+
+impl PriceValidation {
+    pub fn max_age_blocks(mut self, max_age_blocks: u64) -> Self {
+        self.max_age_blocks = Some(max_age_blocks);
+        self
+    }
+
+    pub fn min_nonce(mut self, min_nonce: u64) -> Self {
+        self.min_nonce = min_nonce;
+        self
+    }
+
+    pub fn require_enabled(mut self, require_enabled: bool) -> Self {
+        self.require_enabled = require_enabled;
+        self
+    }
+}
+
+/// Oracle price normalized by the number of decimals of the currency pair.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OraclePrice {
+    pub currency_pair: CurrencyPair,
+    /// **price** is the amount of quote per one base
+    pub price: Decimal256,
+    /// **block_height** is the height of the block the price was last updated at
+    pub block_height: u64,
+    pub nonce: u64,
+}
+
+impl OraclePrice {
+    /// Returns the price of quote denominated in base, e.g. USD/ATOM from ATOM/USD.
+    pub fn invert(&self) -> NeutronResult<OraclePrice> {
+        Ok(OraclePrice {
+            currency_pair: CurrencyPair::new(&self.currency_pair.quote, &self.currency_pair.base),
+            price: invert_price(&self.currency_pair, self.price)?,
+            block_height: self.block_height,
+            nonce: self.nonce,
+        })
+    }
+}
+
+/// Price derived from two oracle prices sharing the same quote currency.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CrossPrice {
+    pub currency_pair: CurrencyPair,
+    pub price: Decimal256,
+    /// **base** is the price of the base currency in the common quote currency
+    pub base: OraclePrice,
+    /// **quote** is the price of the quote currency in the common quote currency
+    pub quote: OraclePrice,
+}
+
+impl CrossPrice {
+    /// Returns the height of the oldest price the cross price was derived from.
+    pub fn block_height(&self) -> u64 {
+        self.base.block_height.min(self.quote.block_height)
+    }
+}
+
+/// Converts a raw oracle price into a decimal, e.g. `1234500000` with 8 decimals is `12.345`.
+pub fn normalize_price(
+    currency_pair: &CurrencyPair,
+    price: Int128,
+    decimals: u64,
+) -> NeutronResult<Decimal256> {
+    let invalid = || NeutronError::InvalidOraclePrice {
+        currency_pair: currency_pair.to_string(),
+        price: price.to_string(),
+    };
+    if price.is_negative() {
+        return Err(invalid());
+    }
+    let decimals = u32::try_from(decimals).map_err(|_| invalid())?;
+    let atomics = Uint256::from(price.i128().unsigned_abs());
+    Ok(Decimal256::from_atomics(atomics, decimals)?)
+}
+
+/// Queries the price of the currency pair from the oracle module and validates it.
+///
+/// Fails if the price has never been set, is older than `validation.max_age_blocks`,
+/// has a nonce lower than `validation.min_nonce` or the market is disabled in the market map.
+pub fn query_price(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    currency_pair: &CurrencyPair,
+    validation: &PriceValidation,
+) -> NeutronResult<OraclePrice> {
+    if validation.require_enabled {
+        let market: MarketResponse = deps.querier.query(
+            &MarketMapQuery::Market {
+                currency_pair: currency_pair.clone(),
+            }
+            .into(),
+        )?;
+        if !market.market.ticker.enabled {
+            return Err(NeutronError::MarketDisabled {
+                currency_pair: currency_pair.to_string(),
+            });
+        }
+    }
+
+    let response: GetPriceResponse = deps.querier.query(
+        &OracleQuery::GetPrice {
+            currency_pair: currency_pair.clone(),
+        }
+        .into(),
+    )?;
+    validate_price(env, currency_pair, response, validation)
+}
+
+/// Queries the price of `base` denominated in `quote` via prices of both currencies
+/// in the common currency `via`, e.g. ATOM/NTRN from ATOM/USD and NTRN/USD.
+pub fn query_cross_price(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    base: &str,
+    quote: &str,
+    via: &str,
+    validation: &PriceValidation,
+) -> NeutronResult<CrossPrice> {
+    let base_price = query_price(deps, env, &CurrencyPair::new(base, via), validation)?;
+    let quote_price = query_price(deps, env, &CurrencyPair::new(quote, via), validation)?;
+    cross_price(base_price, quote_price)
+}
+
+/// Derives the price of `base.currency_pair.base` denominated in `quote.currency_pair.base`.
+pub fn cross_price(base: OraclePrice, quote: OraclePrice) -> NeutronResult<CrossPrice> {
+    let currency_pair = CurrencyPair::new(&base.currency_pair.base, &quote.currency_pair.base);
+    if base.currency_pair.quote != quote.currency_pair.quote {
+        return Err(NeutronError::InvalidOraclePrice {
+            currency_pair: currency_pair.to_string(),
+            price: format!(
+                "{} and {} have different quote currencies",
+                base.currency_pair, quote.currency_pair
+            ),
+        });
+    }
+    let price =
+        base.price
+            .checked_div(quote.price)
+            .map_err(|_| NeutronError::InvalidOraclePrice {
+                currency_pair: quote.currency_pair.to_string(),
+                price: quote.price.to_string(),
+            })?;
+    Ok(CrossPrice {
+        currency_pair,
+        price,
+        base,
+        quote,
+    })
+}
+
+fn validate_price(
+    env: &Env,
+    currency_pair: &CurrencyPair,
+    response: GetPriceResponse,
+    validation: &PriceValidation,
+) -> NeutronResult<OraclePrice> {
+    let block_height = match response.price.block_height {
+        Some(height) if response.nonce > 0 => height,
+        _ => {
+            return Err(NeutronError::OraclePriceNotSet {
+                currency_pair: currency_pair.to_string(),
+            })
+        }
+    };
+
+    if response.nonce < validation.min_nonce {
+        return Err(NeutronError::OracleNonceTooLow {
+            currency_pair: currency_pair.to_string(),
+            nonce: response.nonce,
+            min_nonce: validation.min_nonce,
+        });
+    }
+
+    if let Some(max_age) = validation.max_age_blocks {
+        let age = env.block.height.saturating_sub(block_height);
+        if age > max_age {
+            return Err(NeutronError::StaleOraclePrice {
+                currency_pair: currency_pair.to_string(),
+                age,
+                max_age,
+            });
+        }
+    }
+
+    Ok(OraclePrice {
+        currency_pair: currency_pair.clone(),
+        price: normalize_price(currency_pair, response.price.price, response.decimals)?,
+        block_height,
+        nonce: response.nonce,
+    })
+}
+
+fn invert_price(currency_pair: &CurrencyPair, price: Decimal256) -> NeutronResult<Decimal256> {
+    Decimal256::one()
+        .checked_div(price)
+        .map_err(|_| NeutronError::InvalidOraclePrice {
+            currency_pair: currency_pair.to_string(),
+            price: price.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::marketmap::types::{Market, Ticker};
+    use crate::bindings::oracle::types::QuotePrice;
+    use crate::testing::{mock_neutron_deps, NeutronDeps};
+    use cosmwasm_std::testing::{mock_env, MockQuerier};
+    use cosmwasm_std::{to_json_binary, ContractResult, SystemResult};
+    use std::str::FromStr;
+
+    const HEIGHT: u64 = 12_345;
+
+    fn price_response(
+        price: i128,
+        decimals: u64,
+        block_height: u64,
+        nonce: u64,
+    ) -> GetPriceResponse {
+        GetPriceResponse {
+            price: QuotePrice {
+                price: Int128::new(price),
+                block_timestamp: "2024-01-01T00:00:00Z".to_string(),
+                block_height: Some(block_height),
+            },
+            nonce,
+            decimals,
+            id: 0,
+        }
+    }
+
+    /// ATOM/USD is 9.5 updated 2 blocks ago, NTRN/USD is 0.38 updated 10 blocks ago,
+    /// ETH/USD is never updated, OSMO/USD is disabled
+    fn deps() -> NeutronDeps {
+        let querier = MockQuerier::new(&[]).with_custom_handler(|query| {
+            let response = match query {
+                NeutronQuery::Oracle(OracleQuery::GetPrice { currency_pair }) => {
+                    let response = match currency_pair.base.as_str() {
+                        "ATOM" => price_response(950_000_000, 8, HEIGHT - 2, 100),
+                        "NTRN" => price_response(380_000_000_000_000_000, 18, HEIGHT - 10, 7),
+                        "ETH" => GetPriceResponse {
+                            price: QuotePrice {
+                                price: Int128::zero(),
+                                block_timestamp: String::new(),
+                                block_height: None,
+                            },
+                            nonce: 0,
+                            decimals: 18,
+                            id: 2,
+                        },
+                        _ => price_response(1, 0, HEIGHT, 1),
+                    };
+                    to_json_binary(&response)
+                }
+                NeutronQuery::MarketMap(MarketMapQuery::Market { currency_pair }) => {
+                    to_json_binary(&MarketResponse {
+                        market: Market {
+                            ticker: Ticker {
+                                currency_pair: crate::bindings::marketmap::types::CurrencyPair {
+                                    base: currency_pair.base.clone(),
+                                    quote: currency_pair.quote.clone(),
+                                },
+                                decimals: 8,
+                                min_provider_count: 1,
+                                enabled: currency_pair.base != "OSMO",
+                                metadata_json: String::new(),
+                            },
+                            provider_configs: vec![],
+                        },
+                    })
+                }
+                _ => panic!("unexpected query"),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        });
+        mock_neutron_deps(querier)
+    }
+
+    fn env() -> Env {
+        let mut env = mock_env();
+        env.block.height = HEIGHT;
+        env
+    }
+
+    #[test]
+    fn test_query_price() {
+        let deps = deps();
+        let atom_usd = CurrencyPair::new("ATOM", "USD");
+
+        let price = query_price(
+            deps.as_ref(),
+            &env(),
+            &atom_usd,
+            &PriceValidation::default().max_age_blocks(5),
+        )
+        .unwrap();
+        assert_eq!(price.price, Decimal256::from_str("9.5").unwrap());
+        assert_eq!(price.block_height, HEIGHT - 2);
+        assert_eq!(
+            price.invert().unwrap().price,
+            Decimal256::from_str("0.105263157894736842").unwrap()
+        );
+
+        let ntrn_usd = CurrencyPair::new("NTRN", "USD");
+        assert_eq!(
+            query_price(
+                deps.as_ref(),
+                &env(),
+                &ntrn_usd,
+                &PriceValidation::default().max_age_blocks(5)
+            ),
+            Err(NeutronError::StaleOraclePrice {
+                currency_pair: "NTRN/USD".to_string(),
+                age: 10,
+                max_age: 5,
+            })
+        );
+        assert_eq!(
+            query_price(
+                deps.as_ref(),
+                &env(),
+                &ntrn_usd,
+                &PriceValidation::default().min_nonce(8)
+            ),
+            Err(NeutronError::OracleNonceTooLow {
+                currency_pair: "NTRN/USD".to_string(),
+                nonce: 7,
+                min_nonce: 8,
+            })
+        );
+        assert_eq!(
+            query_price(
+                deps.as_ref(),
+                &env(),
+                &CurrencyPair::new("ETH", "USD"),
+                &PriceValidation::default()
+            ),
+            Err(NeutronError::OraclePriceNotSet {
+                currency_pair: "ETH/USD".to_string(),
+            })
+        );
+
+        let osmo_usd = CurrencyPair::new("OSMO", "USD");
+        assert_eq!(
+            query_price(
+                deps.as_ref(),
+                &env(),
+                &osmo_usd,
+                &PriceValidation::default()
+            ),
+            Err(NeutronError::MarketDisabled {
+                currency_pair: "OSMO/USD".to_string(),
+            })
+        );
+        query_price(
+            deps.as_ref(),
+            &env(),
+            &osmo_usd,
+            &PriceValidation::default().require_enabled(false),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_query_cross_price() {
+        let deps = deps();
+
+        let atom_ntrn = query_cross_price(
+            deps.as_ref(),
+            &env(),
+            "ATOM",
+            "NTRN",
+            "USD",
+            &PriceValidation::default(),
+        )
+        .unwrap();
+        assert_eq!(atom_ntrn.currency_pair, CurrencyPair::new("ATOM", "NTRN"));
+        assert_eq!(atom_ntrn.price, Decimal256::from_str("25").unwrap());
+        assert_eq!(atom_ntrn.block_height(), HEIGHT - 10);
+
+        assert_eq!(
+            normalize_price(&CurrencyPair::new("ATOM", "USD"), Int128::new(-1), 8),
+            Err(NeutronError::InvalidOraclePrice {
+                currency_pair: "ATOM/USD".to_string(),
+                price: "-1".to_string(),
+            })
+        );
+        assert!(cross_price(atom_ntrn.base.clone(), atom_ntrn.base.invert().unwrap()).is_err());
+    }
+}
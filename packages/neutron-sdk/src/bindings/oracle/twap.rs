@@ -0,0 +1,412 @@
+use crate::bindings::oracle::price::{query_price, OraclePrice, PriceValidation};
+use crate::bindings::oracle::types::CurrencyPair;
+use crate::bindings::query::NeutronQuery;
+use crate::{NeutronError, NeutronResult};
+use cosmwasm_std::{Decimal256, Deps, Env, StdResult, Storage};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Price observation stored by [TwapAccumulator].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Observation {
+    /// **timestamp** is the block time in seconds the observation was recorded at
+    pub timestamp: u64,
+    /// **block_height** is the height of the block the oracle price was updated at
+    pub block_height: u64,
+    pub price: Decimal256,
+    /// **cumulative_price** is the sum of price × seconds from the first observation
+    /// of the currency pair up to **timestamp**
+    pub cumulative_price: Decimal256,
+}
+
+/// Records oracle prices per currency pair into a ring buffer of cumulative price × time
+/// and computes time-weighted average prices from them.
+///
+/// A price is considered constant between two observations, so the accumulator has to be poked
+/// regularly, e.g. from a cron schedule or from user actions. An interval between two
+/// observations longer than the allowed gap makes [TwapAccumulator::twap] fail instead of
+/// silently extrapolating the older price.
+///
+/// ```rust ignore
+/// const TWAP: TwapAccumulator = TwapAccumulator::new("twap_counters", "twap_observations", 100);
+///
+/// // execute, called by cron
+/// TWAP.poke(deps.as_ref(), deps.storage, &env, &pair, &PriceValidation::default().max_age_blocks(10))?;
+///
+/// // query, one hour TWAP with at most 5 minutes between observations
+/// let price = TWAP.twap(deps.storage, &env, &pair, 3600, Some(300))?;
+/// ```
+pub struct TwapAccumulator {
+    counters: Map<String, u64>,
+    observations: Map<(String, u64), Observation>,
+    capacity: u64,
+}
+
+impl TwapAccumulator {
+    /// Creates an accumulator which keeps the last **capacity** observations per currency pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if **capacity** is zero. In a `const` item it's a compile time error.
+    pub const fn new(
+        counters_namespace: &'static str,
+        observations_namespace: &'static str,
+        capacity: u64,
+    ) -> Self {
+        assert!(capacity > 0, "TwapAccumulator capacity must be positive");
+
+        TwapAccumulator {
+            counters: Map::new(counters_namespace),
+            observations: Map::new(observations_namespace),
+            capacity,
+        }
+    }
+
+    /// Queries the current price of the currency pair with [query_price] and records it.
+    /// Returns whether a new observation was recorded.
+    pub fn poke(
+        &self,
+        deps: Deps<NeutronQuery>,
+        storage: &mut dyn Storage,
+        env: &Env,
+        currency_pair: &CurrencyPair,
+        validation: &PriceValidation,
+    ) -> NeutronResult<bool> {
+        let price = query_price(deps, env, currency_pair, validation)?;
+        self.record(storage, env, &price)
+    }
+
+    /// Records the price at the current block time. Returns `false` without recording anything
+    /// if the price was not updated since the last observation of the currency pair.
+    /// A price recorded twice within the same block time replaces the previous observation.
+    pub fn record(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        price: &OraclePrice,
+    ) -> NeutronResult<bool> {
+        let key = price.currency_pair.to_string();
+        let timestamp = env.block.time.seconds();
+        let count = self.count(storage, &key)?;
+
+        let observation = match self.latest(storage, &price.currency_pair)? {
+            None => Observation {
+                timestamp,
+                block_height: price.block_height,
+                price: price.price,
+                cumulative_price: Decimal256::zero(),
+            },
+            Some(last) if price.block_height <= last.block_height => return Ok(false),
+            Some(last) if timestamp <= last.timestamp => {
+                self.observations.save(
+                    storage,
+                    (key, self.slot(count - 1)),
+                    &Observation {
+                        block_height: price.block_height,
+                        price: price.price,
+                        ..last
+                    },
+                )?;
+                return Ok(true);
+            }
+            Some(last) => Observation {
+                timestamp,
+                block_height: price.block_height,
+                price: price.price,
+                cumulative_price: last.cumulative_at(timestamp)?,
+            },
+        };
+
+        self.observations
+            .save(storage, (key.clone(), self.slot(count)), &observation)?;
+        self.counters.save(storage, key, &(count + 1))?;
+        Ok(true)
+    }
+
+    /// Returns the last observation of the currency pair
+    pub fn latest(
+        &self,
+        storage: &dyn Storage,
+        currency_pair: &CurrencyPair,
+    ) -> StdResult<Option<Observation>> {
+        let key = currency_pair.to_string();
+        match self.count(storage, &key)? {
+            0 => Ok(None),
+            count => self
+                .observations
+                .may_load(storage, (key, self.slot(count - 1))),
+        }
+    }
+
+    /// Returns the stored observations of the currency pair from the oldest to the latest
+    pub fn observations(
+        &self,
+        storage: &dyn Storage,
+        currency_pair: &CurrencyPair,
+    ) -> StdResult<Vec<Observation>> {
+        let key = currency_pair.to_string();
+        let count = self.count(storage, &key)?;
+        (self.first_index(count)..count)
+            .map(|index| {
+                self.observations
+                    .load(storage, (key.clone(), self.slot(index)))
+            })
+            .collect()
+    }
+
+    /// Returns the time-weighted average price of the currency pair over the last
+    /// **window** seconds.
+    ///
+    /// Fails if the stored observations don't cover the whole window or, when **max_gap** is set,
+    /// if any interval between observations within the window, including the interval from
+    /// the latest observation to the current block time, is longer than **max_gap** seconds.
+    pub fn twap(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        currency_pair: &CurrencyPair,
+        window: u64,
+        max_gap: Option<u64>,
+    ) -> NeutronResult<Decimal256> {
+        if window == 0 {
+            return Err(NeutronError::EmptyTwapWindow {
+                currency_pair: currency_pair.to_string(),
+            });
+        }
+
+        let key = currency_pair.to_string();
+        let now = env.block.time.seconds();
+        let start = now.saturating_sub(window);
+        let count = self.count(storage, &key)?;
+        let first_index = self.first_index(count);
+        let load = |index: u64| {
+            self.observations
+                .load(storage, (key.clone(), self.slot(index)))
+        };
+
+        let too_short = |covered: u64| NeutronError::TwapHistoryTooShort {
+            currency_pair: key.clone(),
+            window,
+            covered,
+        };
+        if count == 0 {
+            return Err(too_short(0));
+        }
+        let oldest = load(first_index)?;
+        if oldest.timestamp > start || now < window {
+            return Err(too_short(now.saturating_sub(oldest.timestamp)));
+        }
+
+        // the latest observation at or before the window start
+        let (mut low, mut high) = (first_index, count - 1);
+        while low < high {
+            let mid = low + (high - low + 1) / 2;
+            if load(mid)?.timestamp <= start {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        let mut observation = load(low)?;
+        let cumulative_start = observation.cumulative_at(start)?;
+        for index in low + 1..count {
+            let next = load(index)?;
+            self.check_gap(&key, next.timestamp - observation.timestamp, max_gap)?;
+            observation = next;
+        }
+        self.check_gap(&key, now - observation.timestamp, max_gap)?;
+        let cumulative_end = observation.cumulative_at(now)?;
+
+        // window is positive, so the division can't fail
+        Ok((cumulative_end - cumulative_start) / Decimal256::from_ratio(window, 1u64))
+    }
+
+    fn check_gap(&self, key: &str, gap: u64, max_gap: Option<u64>) -> NeutronResult<()> {
+        match max_gap {
+            Some(max_gap) if gap > max_gap => Err(NeutronError::TwapObservationGap {
+                currency_pair: key.to_string(),
+                gap,
+                max_gap,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn count(&self, storage: &dyn Storage, key: &str) -> StdResult<u64> {
+        Ok(self
+            .counters
+            .may_load(storage, key.to_string())?
+            .unwrap_or_default())
+    }
+
+    fn first_index(&self, count: u64) -> u64 {
+        count.saturating_sub(self.capacity)
+    }
+
+    fn slot(&self, index: u64) -> u64 {
+        index % self.capacity
+    }
+}
+
+impl Observation {
+    /// Returns the cumulative price at **timestamp** assuming the price didn't change since the observation
+    fn cumulative_at(&self, timestamp: u64) -> NeutronResult<Decimal256> {
+        let elapsed = Decimal256::from_ratio(timestamp.saturating_sub(self.timestamp), 1u64);
+        Ok(self
+            .cumulative_price
+            .checked_add(self.price.checked_mul(elapsed)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+    use cosmwasm_std::Timestamp;
+    use std::str::FromStr;
+
+    const TWAP: TwapAccumulator = TwapAccumulator::new("counters", "observations", 4);
+
+    fn pair() -> CurrencyPair {
+        CurrencyPair::new("ATOM", "USD")
+    }
+
+    fn env_at(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(seconds);
+        env.block.height = seconds;
+        env
+    }
+
+    fn record(storage: &mut dyn Storage, seconds: u64, price: &str) -> bool {
+        TWAP.record(
+            storage,
+            &env_at(seconds),
+            &OraclePrice {
+                currency_pair: pair(),
+                price: Decimal256::from_str(price).unwrap(),
+                block_height: seconds,
+                nonce: seconds,
+            },
+        )
+        .unwrap()
+    }
+
+    fn dec(value: &str) -> Decimal256 {
+        Decimal256::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_twap() {
+        let mut storage = MockStorage::default();
+        assert_eq!(
+            TWAP.twap(&storage, &env_at(1000), &pair(), 10, None),
+            Err(NeutronError::TwapHistoryTooShort {
+                currency_pair: "ATOM/USD".to_string(),
+                window: 10,
+                covered: 0,
+            })
+        );
+
+        assert!(record(&mut storage, 1000, "10"));
+        assert!(record(&mut storage, 1010, "20"));
+        assert!(record(&mut storage, 1030, "5"));
+
+        // 10 for 10s, 20 for 20s, 5 for 10s
+        assert_eq!(
+            TWAP.twap(&storage, &env_at(1040), &pair(), 40, None),
+            Ok(dec("13.75"))
+        );
+        // 20 for 5s, 5 for 10s
+        assert_eq!(
+            TWAP.twap(&storage, &env_at(1040), &pair(), 15, None),
+            Ok(dec("10"))
+        );
+        assert_eq!(
+            TWAP.twap(&storage, &env_at(1040), &pair(), 41, None),
+            Err(NeutronError::TwapHistoryTooShort {
+                currency_pair: "ATOM/USD".to_string(),
+                window: 41,
+                covered: 40,
+            })
+        );
+        assert_eq!(
+            TWAP.twap(&storage, &env_at(1040), &pair(), 40, Some(15)),
+            Err(NeutronError::TwapObservationGap {
+                currency_pair: "ATOM/USD".to_string(),
+                gap: 20,
+                max_gap: 15,
+            })
+        );
+        // the gap is before the window
+        assert_eq!(
+            TWAP.twap(&storage, &env_at(1040), &pair(), 10, Some(15)),
+            Ok(dec("5"))
+        );
+        // the latest observation is stale
+        assert_eq!(
+            TWAP.twap(&storage, &env_at(1060), &pair(), 10, Some(15)),
+            Err(NeutronError::TwapObservationGap {
+                currency_pair: "ATOM/USD".to_string(),
+                gap: 30,
+                max_gap: 15,
+            })
+        );
+    }
+
+    #[test]
+    fn test_record_ring_buffer() {
+        let mut storage = MockStorage::default();
+        assert!(record(&mut storage, 1000, "1"));
+        // price is not updated since the last observation
+        assert!(!TWAP
+            .record(
+                &mut storage,
+                &env_at(1005),
+                &OraclePrice {
+                    currency_pair: pair(),
+                    price: dec("2"),
+                    block_height: 1000,
+                    nonce: 1,
+                },
+            )
+            .unwrap());
+        for (seconds, price) in [(1010, "2"), (1020, "3"), (1030, "4"), (1040, "5")] {
+            assert!(record(&mut storage, seconds, price));
+        }
+
+        let observations = TWAP.observations(&storage, &pair()).unwrap();
+        assert_eq!(
+            observations
+                .iter()
+                .map(|o| (o.timestamp, o.cumulative_price))
+                .collect::<Vec<_>>(),
+            vec![
+                (1010, dec("10")),
+                (1020, dec("30")),
+                (1030, dec("60")),
+                (1040, dec("100"))
+            ]
+        );
+        assert_eq!(
+            TWAP.latest(&storage, &pair()).unwrap().unwrap().price,
+            dec("5")
+        );
+        assert_eq!(
+            TWAP.twap(&storage, &env_at(1040), &pair(), 30, None),
+            Ok(dec("3"))
+        );
+        assert!(TWAP
+            .twap(&storage, &env_at(1040), &pair(), 31, None)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "TwapAccumulator capacity must be positive")]
+    fn test_zero_capacity() {
+        TwapAccumulator::new("counters", "observations", 0);
+    }
+}
@@ -21,3 +21,18 @@ pub struct QuotePrice {
     /// **block_height** is height of block mentioned above
     pub block_height: Option<u64>,
 }
+
+impl CurrencyPair {
+    pub fn new(base: impl Into<String>, quote: impl Into<String>) -> Self {
+        CurrencyPair {
+            base: base.into(),
+            quote: quote.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for CurrencyPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.base, self.quote)
+    }
+}
@@ -179,6 +179,12 @@ impl ProtobufAny {
     }
 }
 
+impl From<crate::shim::Any> for ProtobufAny {
+    fn from(any: crate::shim::Any) -> Self {
+        ProtobufAny::new(any.type_url, Binary::new(any.value))
+    }
+}
+
 const KV_PATH_KEY_DELIMITER: &str = "/";
 const KV_KEYS_DELIMITER: &str = ",";
 
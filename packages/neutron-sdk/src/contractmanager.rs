@@ -0,0 +1,322 @@
+use crate::{
+    bindings::msg::NeutronMsg,
+    errors::error::NeutronResult,
+    proto_types::neutron::contractmanager::{ContractmanagerQuerier, Failure},
+    query::pagination::Paginator,
+    sudo::msg::SudoMsg,
+};
+use cosmwasm_std::{
+    from_json, Binary, CustomQuery, Order, QuerierWrapper, StdResult, Storage, SubMsg,
+};
+use cw_storage_plus::Map;
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+
+/// Redacted error the contract manager stores when the sudo call runs out of gas
+pub const OUT_OF_GAS_ERROR: &str = "codespace: sdk, code: 11";
+
+/// Sudo callback the failure was created for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FailureKind {
+    Response,
+    Error,
+    Timeout,
+    /// sudo payload can't be decoded as a packet callback
+    Unknown,
+}
+
+/// Failed sudo call of the contract, see [query_failures]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractFailure {
+    pub id: u64,
+    pub address: String,
+    pub kind: FailureKind,
+    /// **sudo_payload** is the JSON encoded sudo message the contract failed to handle
+    pub sudo_payload: Binary,
+    /// **error** is the redacted error of the sudo call, e.g. `codespace: wasm, code: 5`
+    pub error: String,
+}
+
+impl From<Failure> for ContractFailure {
+    fn from(failure: Failure) -> Self {
+        let kind = match from_json::<SudoMsg>(&failure.sudo_payload) {
+            Ok(SudoMsg::Response { .. }) => FailureKind::Response,
+            Ok(SudoMsg::Error { .. }) => FailureKind::Error,
+            Ok(SudoMsg::Timeout { .. }) => FailureKind::Timeout,
+            _ => FailureKind::Unknown,
+        };
+        ContractFailure {
+            id: failure.id,
+            address: failure.address,
+            kind,
+            sudo_payload: Binary::new(failure.sudo_payload),
+            error: failure.error,
+        }
+    }
+}
+
+impl ContractFailure {
+    /// Decodes the sudo payload, e.g. into [SudoMsg] or [crate::sudo::msg::TransferSudoMsg]
+    pub fn sudo_msg<T: DeserializeOwned>(&self) -> StdResult<T> {
+        from_json(&self.sudo_payload)
+    }
+
+    /// Returns codespace and code of the error
+    pub fn error_code(&self) -> Option<(&str, u32)> {
+        let (codespace, code) = self
+            .error
+            .strip_prefix("codespace: ")?
+            .split_once(", code: ")?;
+        Some((codespace, code.parse().ok()?))
+    }
+
+    /// Checks whether the sudo call ran out of gas, such failures usually succeed when resubmitted
+    pub fn is_out_of_gas(&self) -> bool {
+        self.error == OUT_OF_GAS_ERROR
+    }
+}
+
+/// Returns all failures of the contract with the **address**
+pub fn query_failures<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    address: impl Into<String>,
+) -> NeutronResult<Vec<ContractFailure>> {
+    let address = address.into();
+    let contractmanager = ContractmanagerQuerier::new(querier);
+    let failures = Paginator::default()
+        .collect(|pagination| contractmanager.failures(address.clone(), 0, pagination))?;
+
+    Ok(failures.into_iter().map(ContractFailure::from).collect())
+}
+
+/// Resubmits failures in bounded batches and counts the attempts, so a failure that keeps
+/// failing is given up after **max_attempts** resubmissions.
+///
+/// Every resubmission is sent as a submessage replying on error, so one failing resubmission
+/// doesn't revert the batch and the attempt counter. The contract has to handle the reply.
+///
+/// ```rust ignore
+/// const RECOVERY: FailureRecovery = FailureRecovery::new("resubmit_attempts", 3, 100);
+///
+/// // execute, e.g. called by cron
+/// let failures = query_failures(&deps.querier, env.contract.address)?;
+/// RECOVERY.prune(deps.storage, &failures)?;
+/// let retryable = failures.iter().filter(|f| f.kind == FailureKind::Timeout || f.is_out_of_gas());
+/// let msgs = RECOVERY.resubmit(deps.storage, retryable, 10)?;
+///
+/// // reply
+/// if RECOVERY.is_reply(msg.id) {
+///     return Ok(Response::default());
+/// }
+/// ```
+pub struct FailureRecovery {
+    attempts: Map<u64, u32>,
+    max_attempts: u32,
+    reply_id: u64,
+}
+
+impl FailureRecovery {
+    pub const fn new(namespace: &'static str, max_attempts: u32, reply_id: u64) -> Self {
+        FailureRecovery {
+            attempts: Map::new(namespace),
+            max_attempts,
+            reply_id,
+        }
+    }
+
+    /// Creates **ResubmitFailure** submessages for at most **max_batch** failures which
+    /// haven't exhausted their attempts and records an attempt for each of them
+    pub fn resubmit<'f>(
+        &self,
+        storage: &mut dyn Storage,
+        failures: impl IntoIterator<Item = &'f ContractFailure>,
+        max_batch: usize,
+    ) -> StdResult<Vec<SubMsg<NeutronMsg>>> {
+        let mut msgs = vec![];
+        for failure in failures {
+            if msgs.len() >= max_batch {
+                break;
+            }
+            let attempts = self.attempts(storage, failure.id)?;
+            if attempts >= self.max_attempts {
+                continue;
+            }
+
+            self.attempts.save(storage, failure.id, &(attempts + 1))?;
+            msgs.push(SubMsg::reply_on_error(
+                NeutronMsg::submit_resubmit_failure(failure.id),
+                self.reply_id,
+            ));
+        }
+
+        Ok(msgs)
+    }
+
+    /// Checks whether the reply **id** belongs to a failed resubmission
+    pub fn is_reply(&self, id: u64) -> bool {
+        id == self.reply_id
+    }
+
+    /// Returns the number of resubmissions of the failure
+    pub fn attempts(&self, storage: &dyn Storage, failure_id: u64) -> StdResult<u32> {
+        Ok(self
+            .attempts
+            .may_load(storage, failure_id)?
+            .unwrap_or_default())
+    }
+
+    /// Returns the failures which won't be resubmitted anymore
+    pub fn exhausted<'f>(
+        &self,
+        storage: &dyn Storage,
+        failures: impl IntoIterator<Item = &'f ContractFailure>,
+    ) -> StdResult<Vec<&'f ContractFailure>> {
+        let mut exhausted = vec![];
+        for failure in failures {
+            if self.attempts(storage, failure.id)? >= self.max_attempts {
+                exhausted.push(failure);
+            }
+        }
+        Ok(exhausted)
+    }
+
+    /// Resets the attempts of the failure, e.g. after the cause of the failure is fixed
+    pub fn reset(&self, storage: &mut dyn Storage, failure_id: u64) {
+        self.attempts.remove(storage, failure_id)
+    }
+
+    /// Removes attempt records of failures which are not in **failures** anymore,
+    /// i.e. were successfully resubmitted. **failures** must be all failures of the contract.
+    pub fn prune(&self, storage: &mut dyn Storage, failures: &[ContractFailure]) -> StdResult<()> {
+        let current: HashSet<u64> = failures.iter().map(|f| f.id).collect();
+        let resolved = self
+            .attempts
+            .keys(storage, None, None, Order::Ascending)
+            .filter(|id| id.as_ref().map_or(true, |id| !current.contains(id)))
+            .collect::<StdResult<Vec<_>>>()?;
+        for id in resolved {
+            self.attempts.remove(storage, id);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sudo::msg::{RequestPacket, TransferSudoMsg};
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::to_json_vec;
+
+    const RECOVERY: FailureRecovery = FailureRecovery::new("attempts", 2, 100);
+
+    fn failure(id: u64, sudo_msg: &SudoMsg, error: &str) -> ContractFailure {
+        Failure {
+            address: "neutron1contract".to_string(),
+            id,
+            sudo_payload: to_json_vec(sudo_msg).unwrap(),
+            error: error.to_string(),
+        }
+        .into()
+    }
+
+    fn request(seq_id: u64) -> RequestPacket {
+        RequestPacket {
+            sequence: Some(seq_id),
+            source_port: Some("transfer".to_string()),
+            source_channel: Some("channel-0".to_string()),
+            destination_port: None,
+            destination_channel: None,
+            data: None,
+            timeout_height: None,
+            timeout_timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_failure() {
+        let timeout = failure(
+            0,
+            &SudoMsg::Timeout {
+                request: request(1),
+            },
+            OUT_OF_GAS_ERROR,
+        );
+        assert_eq!(timeout.kind, FailureKind::Timeout);
+        assert!(timeout.is_out_of_gas());
+        assert_eq!(timeout.error_code(), Some(("sdk", 11)));
+        assert_eq!(
+            timeout.sudo_msg::<TransferSudoMsg>().unwrap(),
+            TransferSudoMsg::Timeout {
+                request: request(1)
+            }
+        );
+
+        let error = failure(
+            1,
+            &SudoMsg::Error {
+                request: request(2),
+                details: "error".to_string(),
+            },
+            "codespace: wasm, code: 5",
+        );
+        assert_eq!(error.kind, FailureKind::Error);
+        assert!(!error.is_out_of_gas());
+        assert_eq!(error.error_code(), Some(("wasm", 5)));
+
+        let unknown = failure(2, &SudoMsg::KVQueryResult { query_id: 1 }, "");
+        assert_eq!(unknown.kind, FailureKind::Unknown);
+        assert_eq!(unknown.error_code(), None);
+    }
+
+    #[test]
+    fn test_resubmit() {
+        let mut storage = MockStorage::default();
+        let failures: Vec<ContractFailure> = (0..3)
+            .map(|id| {
+                failure(
+                    id,
+                    &SudoMsg::Timeout {
+                        request: request(id),
+                    },
+                    "",
+                )
+            })
+            .collect();
+        let ids = |msgs: Vec<SubMsg<NeutronMsg>>| {
+            msgs.into_iter()
+                .map(|msg| match msg.msg {
+                    cosmwasm_std::CosmosMsg::Custom(NeutronMsg::ResubmitFailure { failure_id }) => {
+                        failure_id
+                    }
+                    _ => panic!("unexpected message"),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(RECOVERY.resubmit(&mut storage, &failures, 2).unwrap()),
+            vec![0, 1]
+        );
+        assert_eq!(
+            ids(RECOVERY.resubmit(&mut storage, &failures, 5).unwrap()),
+            vec![0, 1, 2]
+        );
+        // failures 0 and 1 exhausted their attempts
+        assert_eq!(
+            ids(RECOVERY.resubmit(&mut storage, &failures, 5).unwrap()),
+            vec![2]
+        );
+        assert_eq!(RECOVERY.exhausted(&storage, &failures).unwrap().len(), 3);
+
+        // failure 1 is resolved
+        let remaining = vec![failures[0].clone(), failures[2].clone()];
+        RECOVERY.prune(&mut storage, &remaining).unwrap();
+        assert_eq!(RECOVERY.attempts(&storage, 1), Ok(0));
+        RECOVERY.reset(&mut storage, 0);
+        assert_eq!(
+            ids(RECOVERY.resubmit(&mut storage, &remaining, 5).unwrap()),
+            vec![0]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    bindings::msg::{MsgExecuteContract, NeutronMsg},
+    errors::error::{NeutronError, NeutronResult},
+    proto_types::neutron::cron::{CronQuerier, QueryGetScheduleResponse, Schedule},
+    query::pagination::Paginator,
+};
+use cosmwasm_std::{to_json_string, CustomQuery, QuerierWrapper};
+use serde::Serialize;
+
+/// Builds a cron schedule executing contract messages every **period** blocks.
+///
+/// Adding a schedule is permissioned: the cron module of the Neutron version supported by this SDK
+/// has no admin module messages for schedules, so the DAO (or another address allowed by the
+/// cron module) submits the [NeutronMsg::AddSchedule] message built by [ScheduleBuilder::build]
+/// directly, and removes it with [NeutronMsg::RemoveSchedule].
+/// This Neutron version doesn't support choosing the block stage a schedule is executed at either,
+/// so all schedules are executed at the stage defined by the cron module.
+///
+/// ```rust ignore
+/// let msg = ScheduleBuilder::new("rebalance", 100)
+///     .execute(env.contract.address, &ExecuteMsg::Rebalance {})?
+///     .build()?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduleBuilder {
+    name: String,
+    period: u64,
+    msgs: Vec<MsgExecuteContract>,
+}
+
+impl ScheduleBuilder {
+    pub fn new(name: impl Into<String>, period: u64) -> Self {
+        ScheduleBuilder {
+            name: name.into(),
+            period,
+            msgs: vec![],
+        }
+    }
+
+    /// Adds a call of the **contract** with the JSON encoded **msg**
+    pub fn execute(self, contract: impl Into<String>, msg: &impl Serialize) -> NeutronResult<Self> {
+        Ok(self.execute_raw(contract, to_json_string(msg)?))
+    }
+
+    /// Adds a call of the **contract** with the already JSON encoded **msg**
+    pub fn execute_raw(mut self, contract: impl Into<String>, msg: impl Into<String>) -> Self {
+        self.msgs.push(MsgExecuteContract {
+            contract: contract.into(),
+            msg: msg.into(),
+        });
+        self
+    }
+
+    /// Creates **AddSchedule** message, works only if the sender is allowed to manage schedules
+    pub fn build(self) -> NeutronResult<NeutronMsg> {
+        self.validate()?;
+        Ok(NeutronMsg::submit_add_schedule(
+            self.name,
+            self.period,
+            self.msgs,
+        ))
+    }
+
+    fn validate(&self) -> NeutronResult<()> {
+        if self.name.is_empty() {
+            return Err(NeutronError::InvalidCronSchedule(
+                "name is empty".to_string(),
+            ));
+        }
+        if self.period == 0 {
+            return Err(NeutronError::InvalidCronSchedule(format!(
+                "period of schedule {} is zero",
+                self.name
+            )));
+        }
+        if self.msgs.is_empty() {
+            return Err(NeutronError::InvalidCronSchedule(format!(
+                "schedule {} has no messages",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the schedule with the **name**
+pub fn query_schedule<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    name: impl Into<String>,
+) -> NeutronResult<Schedule> {
+    let name = name.into();
+    let QueryGetScheduleResponse { schedule } = CronQuerier::new(querier).schedule(name.clone())?;
+    schedule.ok_or(NeutronError::CronScheduleNotFound { name })
+}
+
+/// Returns all schedules of the cron module
+pub fn query_schedules<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+) -> NeutronResult<Vec<Schedule>> {
+    let cron = CronQuerier::new(querier);
+    Ok(Paginator::default().collect(|pagination| cron.schedules(pagination))?)
+}
+
+/// Returns the height of the block the schedule with the **name** was last executed at
+pub fn query_last_execute_height<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    name: impl Into<String>,
+) -> NeutronResult<u64> {
+    Ok(query_schedule(querier, name)?.last_execute_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum ExecuteMsg {
+        Rebalance { pool: u64 },
+    }
+
+    #[test]
+    fn test_schedule_builder() {
+        let schedule = ScheduleBuilder::new("rebalance", 10)
+            .execute("neutron1contract", &ExecuteMsg::Rebalance { pool: 1 })
+            .unwrap();
+
+        assert_eq!(
+            schedule.build(),
+            Ok(NeutronMsg::AddSchedule {
+                name: "rebalance".to_string(),
+                period: 10,
+                msgs: vec![MsgExecuteContract {
+                    contract: "neutron1contract".to_string(),
+                    msg: r#"{"rebalance":{"pool":1}}"#.to_string(),
+                }],
+            })
+        );
+        assert!(ScheduleBuilder::new("empty", 10).build().is_err());
+        assert!(ScheduleBuilder::new("zero", 0)
+            .execute_raw("neutron1contract", "{}")
+            .build()
+            .is_err());
+    }
+}
@@ -1,4 +1,6 @@
-use cosmwasm_std::{Decimal256RangeExceeded, DecimalRangeExceeded, OverflowError, StdError};
+use cosmwasm_std::{
+    Decimal256RangeExceeded, DecimalRangeExceeded, OverflowError, StdError, Uint128,
+};
 use serde_json_wasm;
 use thiserror::Error;
 
@@ -45,14 +47,213 @@ pub enum NeutronError {
     #[error("Invalid query result format: {0}")]
     InvalidQueryResultFormat(String),
 
+    #[error("Interchain query {query_id:?} result is {age:?} blocks old, max allowed age is {max_age:?} blocks")]
+    StaleQueryResult {
+        query_id: u64,
+        age: u64,
+        max_age: u64,
+    },
+
     #[error("Integration tests mock is active")]
     IntegrationTestsMock {},
 
     #[error("Too many transaction filters, max allowed: {max:?}")]
     TooManyTransactionFilters { max: usize },
 
+    #[error("Invalid transactions filter: {0}")]
+    InvalidTransactionsFilter(String),
+
+    #[error("Interchain query with label {label:?} is already registered")]
+    IcqLabelAlreadyRegistered { label: String },
+
+    #[error("Interchain query with label {label:?} is not registered")]
+    IcqLabelNotFound { label: String },
+
+    #[error("Interchain query {query_id:?} is not registered")]
+    IcqNotRegistered { query_id: u64 },
+
+    #[error("No handler for interchain query with label {label:?}")]
+    IcqHandlerNotFound { label: String },
+
+    #[error("Interchain query {label:?} result type is {expected:?}, got {actual:?}")]
+    IcqResultTypeMismatch {
+        label: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Interchain account {interchain_account_id:?} is already registered")]
+    IcaAlreadyRegistered { interchain_account_id: String },
+
+    #[error("Interchain account {interchain_account_id:?} is not registered")]
+    IcaNotRegistered { interchain_account_id: String },
+
+    #[error("Interchain account {interchain_account_id:?} is not ready yet, state: {state}")]
+    IcaNotReady {
+        interchain_account_id: String,
+        state: String,
+    },
+
+    #[error("Interchain account {interchain_account_id:?} is reopened with address {actual:?} instead of {expected:?}")]
+    IcaAddressMismatch {
+        interchain_account_id: String,
+        expected: String,
+        actual: String,
+    },
+
     #[error("Can't deconstruct account denom balance key: {0}")]
     AccountDenomBalanceKeyDeconstructionError(String),
+
+    #[error("Transaction filter field can't be checked against a decoded transaction: {field:?}")]
+    UnsupportedTransactionFilterField { field: String },
+
+    #[error("Interchain transaction has no messages")]
+    EmptyInterchainTx {},
+
+    #[error("Too many messages in interchain transaction, max allowed: {max:?}, got: {actual:?}")]
+    TooManyInterchainTxMessages { max: usize, actual: usize },
+
+    #[error("Interchain transaction acknowledgement has {actual:?} message responses, expected {expected:?}")]
+    InvalidInterchainTxAck { expected: usize, actual: usize },
+
+    #[error("No handler for interchain transaction message callback {callback:?}")]
+    InterchainTxCallbackNotFound { callback: String },
+
+    #[error("Handlers of interchain transaction message callbacks {first:?} and {second:?} both set response data")]
+    ConflictingInterchainTxResponseData { first: String, second: String },
+
+    #[error("Tick index {tick_index:?} is outside of the supported range")]
+    TickOutsideRange { tick_index: i64 },
+
+    #[error("Price {price:?} is outside of the supported range")]
+    PriceOutsideRange { price: String },
+
+    #[error("Invalid dex message: {0}")]
+    InvalidDexMsg(String),
+
+    #[error("Oracle price for {currency_pair:?} is not set")]
+    OraclePriceNotSet { currency_pair: String },
+
+    #[error("Oracle price for {currency_pair:?} is invalid: {price}")]
+    InvalidOraclePrice {
+        currency_pair: String,
+        price: String,
+    },
+
+    #[error("Oracle price for {currency_pair:?} is {age:?} blocks old, max allowed age is {max_age:?} blocks")]
+    StaleOraclePrice {
+        currency_pair: String,
+        age: u64,
+        max_age: u64,
+    },
+
+    #[error("Oracle price for {currency_pair:?} has nonce {nonce:?}, min allowed nonce is {min_nonce:?}")]
+    OracleNonceTooLow {
+        currency_pair: String,
+        nonce: u64,
+        min_nonce: u64,
+    },
+
+    #[error("Market {currency_pair:?} is disabled in the market map")]
+    MarketDisabled { currency_pair: String },
+
+    #[error("TWAP window for {currency_pair:?} must be positive")]
+    EmptyTwapWindow { currency_pair: String },
+
+    #[error("Not enough price observations for {currency_pair:?} to cover {window:?} seconds, covered: {covered:?} seconds")]
+    TwapHistoryTooShort {
+        currency_pair: String,
+        window: u64,
+        covered: u64,
+    },
+
+    #[error("Price observations for {currency_pair:?} have a {gap:?} seconds gap, max allowed gap is {max_gap:?} seconds")]
+    TwapObservationGap {
+        currency_pair: String,
+        gap: u64,
+        max_gap: u64,
+    },
+
+    #[error("Denom {subdenom:?} already exists")]
+    DenomAlreadyExists { subdenom: String },
+
+    #[error("Denom {subdenom:?} is not found")]
+    DenomNotFound { subdenom: String },
+
+    #[error("Denom {subdenom:?} is not created yet")]
+    DenomNotCreated { subdenom: String },
+
+    #[error("Contract is not the admin of denom {denom:?}, admin: {admin:?}")]
+    NotDenomAdmin { denom: String, admin: String },
+
+    #[error("Supply cap {cap} of denom {denom:?} is exceeded, supply: {supply}")]
+    SupplyCapExceeded {
+        denom: String,
+        cap: Uint128,
+        supply: Uint128,
+    },
+
+    #[error("Invalid denom metadata: {0}")]
+    InvalidDenomMetadata(String),
+
+    #[error("Transfer from or to {address:?} is not allowed")]
+    TransferNotAllowed { address: String },
+
+    #[error("Address {address:?} is frozen")]
+    AddressFrozen { address: String },
+
+    #[error("Transfer cap {cap} of {address:?} is exceeded, sent: {sent}")]
+    TransferCapExceeded {
+        address: String,
+        cap: Uint128,
+        sent: Uint128,
+    },
+
+    #[error("Address {address:?} is locked until {unlock_time:?}")]
+    AddressLocked { address: String, unlock_time: u64 },
+
+    #[error("Invalid cron schedule: {0}")]
+    InvalidCronSchedule(String),
+
+    #[error("Cron schedule {name:?} is not found")]
+    CronScheduleNotFound { name: String },
+
+    #[error("Invalid IBC memo: {0}")]
+    InvalidIbcMemo(String),
+
+    #[error("Only IbcTransfer messages can be tracked")]
+    NotIbcTransfer {},
+
+    #[error("Refunded balance {balance} {denom} of {address:?} is less than {amount}")]
+    InsufficientRefundBalance {
+        address: String,
+        denom: String,
+        balance: Uint128,
+        amount: Uint128,
+    },
+
+    #[error("Invalid denom trace: {0}")]
+    InvalidDenomTrace(String),
+
+    #[error("Denom trace of {denom:?} is not found")]
+    DenomTraceNotFound { denom: String },
+
+    #[error("Denom {denom:?} has trace {actual:?}, expected {expected:?}")]
+    DenomTraceMismatch {
+        denom: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("IBC fee can't be paid in {denom:?}")]
+    IbcFeeDenomNotAllowed { denom: String },
+
+    #[error("Insufficient IBC fee: {required}{denom} required, {provided}{denom} provided")]
+    InsufficientIbcFee {
+        denom: String,
+        required: Uint128,
+        provided: Uint128,
+    },
 }
 
 impl From<serde_json_wasm::de::Error> for NeutronError {
@@ -0,0 +1,252 @@
+use crate::{
+    errors::error::{NeutronError, NeutronResult},
+    proto_types::ibc::applications::transfer::v1::{DenomTrace, TransferQuerier},
+};
+use cosmwasm_std::{CustomQuery, QuerierWrapper};
+use sha2::{Digest, Sha256};
+
+/// Prefix of the denoms of tokens received over IBC
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+
+/// Port and channel the token was transferred over
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceHop {
+    pub port: String,
+    pub channel: String,
+}
+
+/// Returns the IBC denom of the token with the **base_denom** received through the **path**,
+/// i.e. `ibc/{SHA256(path/base_denom)}`. A token with an empty path is native and keeps the base denom.
+pub fn ibc_denom(path: &str, base_denom: &str) -> String {
+    if path.is_empty() {
+        return base_denom.to_string();
+    }
+    format!(
+        "{}{}",
+        IBC_DENOM_PREFIX,
+        trace_hash(&format!("{}/{}", path, base_denom))
+    )
+}
+
+/// Returns the IBC denom of the token received over the **port** and **channel** from the chain
+/// where the token has the **denom**
+pub fn ibc_denom_from_hop(port: &str, channel: &str, denom: &str) -> NeutronResult<String> {
+    let trace = parse_denom_trace(&format!("{}/{}/{}", port, channel, denom))?;
+    Ok(denom_trace_ibc_denom(&trace))
+}
+
+/// Returns the IBC denom of the **trace**
+pub fn denom_trace_ibc_denom(trace: &DenomTrace) -> String {
+    ibc_denom(&trace.path, &trace.base_denom)
+}
+
+/// Parses the full denom, e.g. `transfer/channel-0/transfer/channel-1/uatom`, into its trace.
+/// Base denom may contain slashes, e.g. `transfer/channel-0/factory/neutron1.../token`.
+pub fn parse_denom_trace(full_denom: &str) -> NeutronResult<DenomTrace> {
+    let parts: Vec<&str> = full_denom.split('/').collect();
+    let mut path_len = 0;
+    while path_len + 2 < parts.len()
+        && !parts[path_len].is_empty()
+        && is_channel_id(parts[path_len + 1])
+    {
+        path_len += 2;
+    }
+
+    let trace = DenomTrace {
+        path: parts[..path_len].join("/"),
+        base_denom: parts[path_len..].join("/"),
+    };
+    validate_denom_trace(&trace)?;
+    Ok(trace)
+}
+
+/// Returns the full denom of the **trace**, e.g. `transfer/channel-0/uatom`
+pub fn full_denom(trace: &DenomTrace) -> String {
+    if trace.path.is_empty() {
+        return trace.base_denom.clone();
+    }
+    format!("{}/{}", trace.path, trace.base_denom)
+}
+
+/// Returns the hops of the **trace** starting from the last one the token was transferred over
+pub fn trace_hops(trace: &DenomTrace) -> Vec<TraceHop> {
+    if trace.path.is_empty() {
+        return vec![];
+    }
+    trace
+        .path
+        .split('/')
+        .collect::<Vec<_>>()
+        .chunks(2)
+        .map(|hop| TraceHop {
+            port: hop[0].to_string(),
+            channel: hop.get(1).unwrap_or(&"").to_string(),
+        })
+        .collect()
+}
+
+/// Returns the trace the token gets on the receiving chain when the packet with the full **denom**
+/// is sent from **source_port**/**source_channel** and received on **dest_port**/**dest_channel**.
+///
+/// A token returning to the chain it came from loses the first hop of its path,
+/// any other token gets the destination port and channel prepended to its path.
+pub fn receive_denom_trace(
+    denom: &str,
+    source_port: &str,
+    source_channel: &str,
+    dest_port: &str,
+    dest_channel: &str,
+) -> NeutronResult<DenomTrace> {
+    let source_prefix = format!("{}/{}/", source_port, source_channel);
+    match denom.strip_prefix(&source_prefix) {
+        Some(unprefixed) => parse_denom_trace(unprefixed),
+        None => parse_denom_trace(&format!("{}/{}/{}", dest_port, dest_channel, denom)),
+    }
+}
+
+/// Checks whether sending the token over the **port** and **channel** returns it to the chain
+/// it came from, i.e. unwinds the last hop of its path instead of adding a new one
+pub fn is_unwind(trace: &DenomTrace, port: &str, channel: &str) -> bool {
+    trace_hops(trace)
+        .first()
+        .is_some_and(|hop| hop.port == port && hop.channel == channel)
+}
+
+/// Queries the trace of the IBC **denom**, either `ibc/{hash}` or the hash, and checks
+/// that the trace hashes to the denom
+pub fn query_denom_trace<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    denom: &str,
+) -> NeutronResult<DenomTrace> {
+    let hash = denom.strip_prefix(IBC_DENOM_PREFIX).unwrap_or(denom);
+    let trace = TransferQuerier::new(querier)
+        .denom_trace(hash.to_string())?
+        .denom_trace
+        .ok_or_else(|| NeutronError::DenomTraceNotFound {
+            denom: denom.to_string(),
+        })?;
+
+    validate_denom_trace(&trace)?;
+    if denom_trace_ibc_denom(&trace) != format!("{}{}", IBC_DENOM_PREFIX, hash.to_uppercase()) {
+        return Err(NeutronError::InvalidDenomTrace(format!(
+            "trace {} doesn't match denom {}",
+            full_denom(&trace),
+            denom
+        )));
+    }
+    Ok(trace)
+}
+
+/// Checks that the IBC **denom** is the token with the **base_denom** received through the **path**,
+/// e.g. that a deposited token is ATOM from the expected channel
+pub fn verify_denom_trace<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    denom: &str,
+    path: &str,
+    base_denom: &str,
+) -> NeutronResult<()> {
+    let trace = query_denom_trace(querier, denom)?;
+    if trace.path != path || trace.base_denom != base_denom {
+        return Err(NeutronError::DenomTraceMismatch {
+            denom: denom.to_string(),
+            expected: format!("{}/{}", path, base_denom),
+            actual: full_denom(&trace),
+        });
+    }
+    Ok(())
+}
+
+fn validate_denom_trace(trace: &DenomTrace) -> NeutronResult<()> {
+    if trace.base_denom.trim().is_empty() {
+        return Err(NeutronError::InvalidDenomTrace(format!(
+            "base denom of {} is empty",
+            full_denom(trace)
+        )));
+    }
+    if trace_hops(trace)
+        .iter()
+        .any(|hop| hop.port.is_empty() || !is_channel_id(&hop.channel))
+    {
+        return Err(NeutronError::InvalidDenomTrace(format!(
+            "invalid path {}",
+            trace.path
+        )));
+    }
+    Ok(())
+}
+
+fn is_channel_id(id: &str) -> bool {
+    id.strip_prefix("channel-")
+        .is_some_and(|seq| !seq.is_empty() && seq.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn trace_hash(full_denom: &str) -> String {
+    hex::encode_upper(Sha256::digest(full_denom.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ATOM_ON_OSMOSIS: &str =
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+    #[test]
+    fn test_ibc_denom() {
+        assert_eq!(ibc_denom("transfer/channel-0", "uatom"), ATOM_ON_OSMOSIS);
+        assert_eq!(
+            ibc_denom_from_hop("transfer", "channel-0", "uatom").unwrap(),
+            ATOM_ON_OSMOSIS
+        );
+        assert_eq!(ibc_denom("", "untrn"), "untrn");
+
+        let trace = parse_denom_trace("transfer/channel-1/transfer/channel-0/factory/neutron1a/tk")
+            .unwrap();
+        assert_eq!(trace.path, "transfer/channel-1/transfer/channel-0");
+        assert_eq!(trace.base_denom, "factory/neutron1a/tk");
+        assert_eq!(
+            trace_hops(&trace),
+            vec![
+                TraceHop {
+                    port: "transfer".to_string(),
+                    channel: "channel-1".to_string(),
+                },
+                TraceHop {
+                    port: "transfer".to_string(),
+                    channel: "channel-0".to_string(),
+                },
+            ]
+        );
+        assert!(is_unwind(&trace, "transfer", "channel-1"));
+        assert!(!is_unwind(&trace, "transfer", "channel-0"));
+
+        let native = parse_denom_trace("uatom").unwrap();
+        assert_eq!(native.path, "");
+        assert!(!is_unwind(&native, "transfer", "channel-0"));
+
+        assert!(parse_denom_trace("transfer/channel-0/").is_err());
+        assert!(parse_denom_trace("").is_err());
+    }
+
+    #[test]
+    fn test_receive_denom_trace() {
+        // uatom sent from the hub over channel-141 and received on channel-0
+        let received =
+            receive_denom_trace("uatom", "transfer", "channel-141", "transfer", "channel-0")
+                .unwrap();
+        assert_eq!(full_denom(&received), "transfer/channel-0/uatom");
+        assert_eq!(denom_trace_ibc_denom(&received), ATOM_ON_OSMOSIS);
+
+        // the same token sent back to the hub
+        let returned = receive_denom_trace(
+            &full_denom(&received),
+            "transfer",
+            "channel-0",
+            "transfer",
+            "channel-141",
+        )
+        .unwrap();
+        assert_eq!(full_denom(&returned), "uatom");
+        assert_eq!(denom_trace_ibc_denom(&returned), "uatom");
+    }
+}
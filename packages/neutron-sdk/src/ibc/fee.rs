@@ -0,0 +1,303 @@
+use crate::{
+    bindings::{msg::IbcFee, query::NeutronQuery},
+    errors::error::{NeutronError, NeutronResult},
+    query::min_ibc_fee::query_min_ibc_fee,
+    sudo::msg::{RequestPacket, TransferSudoMsg},
+};
+use cosmwasm_std::{coin, Coin, Deps, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// IBC fee paid from the funds sent to the contract, see [pay_ibc_fee]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IbcFeePayment {
+    /// **fee** is the minimal fee in the fee denom, to be set as the fee of the IBC message
+    pub fee: IbcFee,
+    /// **leftover** is the funds left after paying the fee, e.g. the tokens to transfer
+    pub leftover: Vec<Coin>,
+}
+
+/// Returns the minimal fee paid in the **denom**. The fee module accepts fees paid in any denom
+/// of the min fee, so only the **denom** part of the min fee is required.
+pub fn min_ibc_fee_in(min_fee: &IbcFee, denom: &str) -> NeutronResult<IbcFee> {
+    let fee_in = |fees: &[Coin]| {
+        fees.iter()
+            .find(|fee| fee.denom == denom)
+            .map(|fee| vec![fee.clone()])
+            .ok_or_else(|| NeutronError::IbcFeeDenomNotAllowed {
+                denom: denom.to_string(),
+            })
+    };
+
+    Ok(IbcFee {
+        recv_fee: vec![],
+        ack_fee: fee_in(&min_fee.ack_fee)?,
+        timeout_fee: fee_in(&min_fee.timeout_fee)?,
+    })
+}
+
+/// Pays the minimal ack and timeout fees in the **denom** from the **funds**
+pub fn pay_ibc_fee(min_fee: &IbcFee, denom: &str, funds: &[Coin]) -> NeutronResult<IbcFeePayment> {
+    let fee = min_ibc_fee_in(min_fee, denom)?;
+    let required = total(&fee.ack_fee, denom) + total(&fee.timeout_fee, denom);
+    let provided = total(funds, denom);
+    if provided < required {
+        return Err(NeutronError::InsufficientIbcFee {
+            denom: denom.to_string(),
+            required,
+            provided,
+        });
+    }
+
+    let leftover = funds
+        .iter()
+        .filter(|c| c.denom != denom)
+        .cloned()
+        .chain((provided > required).then(|| coin((provided - required).u128(), denom)))
+        .filter(|c| !c.amount.is_zero())
+        .collect();
+    Ok(IbcFeePayment { fee, leftover })
+}
+
+/// Queries the min IBC fee and pays it in the **denom** from the **funds**
+pub fn query_ibc_fee_payment(
+    deps: Deps<NeutronQuery>,
+    denom: &str,
+    funds: &[Coin],
+) -> NeutronResult<IbcFeePayment> {
+    let min_fee = query_min_ibc_fee(deps)?.min_fee;
+    pay_ibc_fee(&min_fee, denom, funds)
+}
+
+/// IBC fee locked by the fee module for the packet
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct EscrowedFee {
+    /// **payer** is the address the refunded fee belongs to
+    pub payer: String,
+    pub fee: IbcFee,
+}
+
+/// Part of the fee the fee module refunded to the contract, see [FeeEscrow::release]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefundedFee {
+    pub payer: String,
+    pub amount: Vec<Coin>,
+}
+
+/// Accounts the IBC fees the contract paid on behalf of its users.
+///
+/// The fee module locks both ack and timeout fees when a packet is sent. The relayer gets
+/// the ack fee if the packet is acknowledged or the timeout fee if it times out, and the other
+/// fee is refunded to the contract. [FeeEscrow::release] returns the refunded part, so the contract
+/// can return it to the payer.
+///
+/// ```rust ignore
+/// const FEE_ESCROW: FeeEscrow = FeeEscrow::new("fee_escrow");
+///
+/// // reply, when the sequence id of the packet is known
+/// FEE_ESCROW.lock(deps.storage, channel_id, seq_id, payer, &fee)?;
+///
+/// // sudo
+/// if let Some(refund) = FEE_ESCROW.release(deps.storage, &msg)? {
+///     msgs.push(BankMsg::Send { to_address: refund.payer, amount: refund.amount });
+/// }
+/// ```
+pub struct FeeEscrow {
+    fees: Map<(String, u64), EscrowedFee>,
+}
+
+impl FeeEscrow {
+    pub const fn new(namespace: &'static str) -> Self {
+        FeeEscrow {
+            fees: Map::new(namespace),
+        }
+    }
+
+    /// Records the **fee** the **payer** paid for the packet with **seq_id** sent from **channel_id**
+    pub fn lock(
+        &self,
+        storage: &mut dyn Storage,
+        channel_id: String,
+        seq_id: u64,
+        payer: impl Into<String>,
+        fee: &IbcFee,
+    ) -> StdResult<()> {
+        self.fees.save(
+            storage,
+            (channel_id, seq_id),
+            &EscrowedFee {
+                payer: payer.into(),
+                fee: fee.clone(),
+            },
+        )
+    }
+
+    /// Returns the fee locked for the packet with **seq_id** sent from **channel_id**
+    pub fn load(
+        &self,
+        storage: &dyn Storage,
+        channel_id: String,
+        seq_id: u64,
+    ) -> StdResult<Option<EscrowedFee>> {
+        self.fees.may_load(storage, (channel_id, seq_id))
+    }
+
+    /// Removes the fee of the packet the sudo message is for and returns the part refunded by the fee
+    /// module: the timeout fee if the packet is acknowledged, the ack fee if the packet timed out
+    pub fn release(
+        &self,
+        storage: &mut dyn Storage,
+        msg: &TransferSudoMsg,
+    ) -> StdResult<Option<RefundedFee>> {
+        let (request, timed_out) = match msg {
+            TransferSudoMsg::Response { request, .. } | TransferSudoMsg::Error { request, .. } => {
+                (request, false)
+            }
+            TransferSudoMsg::Timeout { request } => (request, true),
+        };
+        let Some(key) = packet_key(request) else {
+            return Ok(None);
+        };
+        let Some(escrowed) = self.fees.may_load(storage, key.clone())? else {
+            return Ok(None);
+        };
+        self.fees.remove(storage, key);
+
+        let amount = if timed_out {
+            escrowed.fee.ack_fee
+        } else {
+            escrowed.fee.timeout_fee
+        };
+        Ok(Some(RefundedFee {
+            payer: escrowed.payer,
+            amount: amount.into_iter().filter(|c| !c.amount.is_zero()).collect(),
+        }))
+    }
+}
+
+fn packet_key(request: &RequestPacket) -> Option<(String, u64)> {
+    Some((request.source_channel.clone()?, request.sequence?))
+}
+
+fn total(coins: &[Coin], denom: &str) -> Uint128 {
+    coins
+        .iter()
+        .filter(|c| c.denom == denom)
+        .map(|c| c.amount)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::Binary;
+
+    fn min_fee() -> IbcFee {
+        IbcFee {
+            recv_fee: vec![],
+            ack_fee: vec![coin(1000, "untrn"), coin(10, "uatom")],
+            timeout_fee: vec![coin(500, "untrn"), coin(5, "uatom")],
+        }
+    }
+
+    fn request(seq_id: u64) -> RequestPacket {
+        RequestPacket {
+            sequence: Some(seq_id),
+            source_port: Some("transfer".to_string()),
+            source_channel: Some("channel-0".to_string()),
+            destination_port: None,
+            destination_channel: None,
+            data: None,
+            timeout_height: None,
+            timeout_timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_pay_ibc_fee() {
+        let payment = pay_ibc_fee(
+            &min_fee(),
+            "untrn",
+            &[coin(2000, "untrn"), coin(100, "uosmo")],
+        )
+        .unwrap();
+        assert_eq!(
+            payment,
+            IbcFeePayment {
+                fee: IbcFee {
+                    recv_fee: vec![],
+                    ack_fee: vec![coin(1000, "untrn")],
+                    timeout_fee: vec![coin(500, "untrn")],
+                },
+                leftover: vec![coin(100, "uosmo"), coin(500, "untrn")],
+            }
+        );
+        assert_eq!(
+            pay_ibc_fee(&min_fee(), "untrn", &[coin(1500, "untrn")])
+                .unwrap()
+                .leftover,
+            vec![]
+        );
+
+        assert_eq!(
+            pay_ibc_fee(
+                &min_fee(),
+                "untrn",
+                &[coin(1499, "untrn"), coin(100, "uatom")]
+            ),
+            Err(NeutronError::InsufficientIbcFee {
+                denom: "untrn".to_string(),
+                required: Uint128::new(1500),
+                provided: Uint128::new(1499),
+            })
+        );
+        assert_eq!(
+            pay_ibc_fee(&min_fee(), "uosmo", &[coin(1000, "uosmo")]),
+            Err(NeutronError::IbcFeeDenomNotAllowed {
+                denom: "uosmo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_fee_escrow() {
+        let mut storage = MockStorage::default();
+        let escrow = FeeEscrow::new("fees");
+        let fee = min_ibc_fee_in(&min_fee(), "untrn").unwrap();
+        escrow
+            .lock(&mut storage, "channel-0".to_string(), 1, "alice", &fee)
+            .unwrap();
+        escrow
+            .lock(&mut storage, "channel-0".to_string(), 2, "bob", &fee)
+            .unwrap();
+
+        let ack = TransferSudoMsg::Response {
+            request: request(1),
+            data: Binary::default(),
+        };
+        assert_eq!(
+            escrow.release(&mut storage, &ack),
+            Ok(Some(RefundedFee {
+                payer: "alice".to_string(),
+                amount: vec![coin(500, "untrn")],
+            }))
+        );
+        // released only once
+        assert_eq!(escrow.release(&mut storage, &ack), Ok(None));
+
+        let timeout = TransferSudoMsg::Timeout {
+            request: request(2),
+        };
+        assert_eq!(
+            escrow.release(&mut storage, &timeout),
+            Ok(Some(RefundedFee {
+                payer: "bob".to_string(),
+                amount: vec![coin(1000, "untrn")],
+            }))
+        );
+        assert_eq!(escrow.load(&storage, "channel-0".to_string(), 2), Ok(None));
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{NeutronError, NeutronResult};
+use cosmwasm_std::to_json_string;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Max length of an ICS-20 transfer memo in bytes, longer memos are rejected by the transfer module
+pub const MAX_MEMO_LENGTH: usize = 32_768;
+/// Max length of an ICS-20 transfer receiver in bytes
+pub const MAX_RECEIVER_LENGTH: usize = 2_048;
+/// Default port packet-forward-middleware forwards the tokens over
+pub const DEFAULT_FORWARD_PORT: &str = "transfer";
+
+/// Memo of an ICS-20 transfer understood by packet-forward-middleware and ibc-hooks,
+/// used as the `memo` field of [crate::bindings::msg::NeutronMsg::IbcTransfer].
+///
+/// ```rust ignore
+/// // Neutron -> Osmosis -> Stride, then execute a contract on Stride
+/// let memo = Memo::forward(
+///     Forward::new("osmo1...", "channel-326")
+///         .timeout(600)
+///         .retries(2)
+///         .next(Memo::wasm("stride1contract...", &ExecuteMsg::Stake {})?),
+/// )
+/// .to_json_string()?;
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct Memo {
+    /// **forward** makes packet-forward-middleware send the tokens further
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forward: Option<Forward>,
+    /// **wasm** makes ibc-hooks execute a contract with the received tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm: Option<Wasm>,
+    /// **ibc_callback** is a contract ibc-hooks notifies about the ack or timeout of the packet,
+    /// must be the sender of the transfer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ibc_callback: Option<String>,
+}
+
+/// Forwarding of the received tokens by packet-forward-middleware
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Forward {
+    /// **receiver** of the tokens on the next chain
+    pub receiver: String,
+    pub port: String,
+    /// **channel** on the intermediate chain to forward the tokens over
+    pub channel: String,
+    /// **timeout** of the forwarded packet, e.g. `600s`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    /// **retries** is the number of attempts to forward the packet on timeout
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    /// **next** is the memo of the forwarded packet
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<Memo>>,
+}
+
+/// Contract execution by ibc-hooks, the transfer receiver must be the contract address
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Wasm {
+    pub contract: String,
+    pub msg: Value,
+}
+
+impl Memo {
+    /// Creates a memo forwarding the tokens
+    pub fn forward(forward: Forward) -> Self {
+        Memo {
+            forward: Some(forward),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a memo executing the **contract** with the **msg**
+    pub fn wasm(contract: impl Into<String>, msg: &impl Serialize) -> NeutronResult<Self> {
+        Ok(Memo {
+            wasm: Some(Wasm {
+                contract: contract.into(),
+                msg: serde_json::to_value(msg).map_err(|e| invalid(e.to_string()))?,
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// Creates a memo forwarding the tokens through the **hops** of (receiver, channel),
+    /// with **last** as the memo of the packet received by the last receiver
+    pub fn forward_path(
+        hops: impl IntoIterator<Item = (String, String)>,
+        last: Option<Memo>,
+    ) -> Option<Self> {
+        let hops: Vec<_> = hops.into_iter().collect();
+        hops.into_iter()
+            .rev()
+            .fold(last, |next, (receiver, channel)| {
+                let forward = Forward::new(receiver, channel);
+                Some(Memo::forward(match next {
+                    Some(next) => forward.next(next),
+                    None => forward,
+                }))
+            })
+    }
+
+    /// Sets the contract ibc-hooks calls back with the ack or timeout of the packet
+    pub fn with_ibc_callback(mut self, contract: impl Into<String>) -> Self {
+        self.ibc_callback = Some(contract.into());
+        self
+    }
+
+    /// Validates the memo and all nested memos
+    pub fn validate(&self) -> NeutronResult<()> {
+        if self.forward.is_some() && self.wasm.is_some() {
+            return Err(invalid(
+                "memo can't both forward the tokens and execute a contract",
+            ));
+        }
+        if let Some(forward) = &self.forward {
+            if forward.receiver.is_empty() || forward.receiver.len() > MAX_RECEIVER_LENGTH {
+                return Err(invalid(format!(
+                    "forward receiver length must be between 1 and {}",
+                    MAX_RECEIVER_LENGTH
+                )));
+            }
+            if forward.port.is_empty() || forward.channel.is_empty() {
+                return Err(invalid("forward port and channel can't be empty"));
+            }
+            if let Some(next) = &forward.next {
+                next.validate()?;
+            }
+        }
+        if let Some(wasm) = &self.wasm {
+            if wasm.contract.is_empty() {
+                return Err(invalid("wasm contract can't be empty"));
+            }
+            if !wasm.msg.is_object() {
+                return Err(invalid("wasm msg must be a JSON object"));
+            }
+        }
+        if self.ibc_callback.as_deref() == Some("") {
+            return Err(invalid("ibc callback contract can't be empty"));
+        }
+        Ok(())
+    }
+
+    /// Validates the memo and encodes it into the memo string of the transfer
+    pub fn to_json_string(&self) -> NeutronResult<String> {
+        self.validate()?;
+        let memo = to_json_string(self)?;
+        if memo.len() > MAX_MEMO_LENGTH {
+            return Err(invalid(format!(
+                "memo length {} exceeds max length {}",
+                memo.len(),
+                MAX_MEMO_LENGTH
+            )));
+        }
+        Ok(memo)
+    }
+}
+
+impl Forward {
+    /// Creates forwarding to the **receiver** over the **channel** of the transfer port
+    pub fn new(receiver: impl Into<String>, channel: impl Into<String>) -> Self {
+        Forward {
+            receiver: receiver.into(),
+            port: DEFAULT_FORWARD_PORT.to_string(),
+            channel: channel.into(),
+            timeout: None,
+            retries: None,
+            next: None,
+        }
+    }
+
+    pub fn port(mut self, port: impl Into<String>) -> Self {
+        self.port = port.into();
+        self
+    }
+
+    /// Sets the timeout of the forwarded packet in seconds
+    pub fn timeout(mut self, seconds: u64) -> Self {
+        self.timeout = Some(format!("{}s", seconds));
+        self
+    }
+
+    pub fn retries(mut self, retries: u8) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    /// Sets the memo of the forwarded packet
+    pub fn next(mut self, next: Memo) -> Self {
+        self.next = Some(Box::new(next));
+        self
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> NeutronError {
+    NeutronError::InvalidIbcMemo(reason.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::from_json;
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum ExecuteMsg {
+        Stake { validator: String },
+    }
+
+    #[test]
+    fn test_memo() {
+        let memo = Memo::forward(
+            Forward::new("osmo1receiver", "channel-1")
+                .timeout(600)
+                .retries(2)
+                .next(
+                    Memo::wasm(
+                        "stride1contract",
+                        &ExecuteMsg::Stake {
+                            validator: "val".to_string(),
+                        },
+                    )
+                    .unwrap(),
+                ),
+        )
+        .with_ibc_callback("neutron1contract");
+
+        let json = memo.to_json_string().unwrap();
+        assert_eq!(
+            json,
+            r#"{"forward":{"receiver":"osmo1receiver","port":"transfer","channel":"channel-1","timeout":"600s","retries":2,"next":{"wasm":{"contract":"stride1contract","msg":{"stake":{"validator":"val"}}}}},"ibc_callback":"neutron1contract"}"#
+        );
+        assert_eq!(from_json::<Memo>(&json).unwrap(), memo);
+
+        assert_eq!(
+            Memo::forward_path(
+                [
+                    ("osmo1a".to_string(), "channel-1".to_string()),
+                    ("cosmos1b".to_string(), "channel-2".to_string()),
+                ],
+                None,
+            )
+            .unwrap()
+            .to_json_string()
+            .unwrap(),
+            r#"{"forward":{"receiver":"osmo1a","port":"transfer","channel":"channel-1","next":{"forward":{"receiver":"cosmos1b","port":"transfer","channel":"channel-2"}}}}"#
+        );
+    }
+
+    #[test]
+    fn test_invalid_memo() {
+        let wasm = Memo::wasm(
+            "contract",
+            &ExecuteMsg::Stake {
+                validator: "val".to_string(),
+            },
+        )
+        .unwrap();
+        let mut both = Memo::forward(Forward::new("osmo1receiver", "channel-1"));
+        both.wasm = wasm.wasm;
+        assert!(both.to_json_string().is_err());
+
+        let nested = Memo::forward(
+            Forward::new("osmo1receiver", "channel-1")
+                .next(Memo::forward(Forward::new("", "channel-2"))),
+        );
+        assert!(nested.to_json_string().is_err());
+
+        assert!(Memo::wasm("contract", &"not an object")
+            .unwrap()
+            .to_json_string()
+            .is_err());
+
+        let large = Memo::wasm(
+            "contract",
+            &ExecuteMsg::Stake {
+                validator: "v".repeat(MAX_MEMO_LENGTH),
+            },
+        )
+        .unwrap();
+        assert!(large.to_json_string().is_err());
+    }
+}
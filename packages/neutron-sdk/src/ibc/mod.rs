@@ -0,0 +1,4 @@
+pub mod denom;
+pub mod fee;
+pub mod memo;
+pub mod transfer;
@@ -0,0 +1,608 @@
+use crate::{
+    bindings::msg::{IbcFee, NeutronMsg},
+    errors::error::{NeutronError, NeutronResult},
+    sudo::{
+        msg::{RequestPacket, RequestPacketTimeoutHeight, TransferSudoMsg},
+        payload_store::SudoPayloadStore,
+    },
+};
+use cosmwasm_std::{BankMsg, Coin, Env, Order, Reply, StdResult, Storage, SubMsg, Uint128};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferState {
+    /// Waiting for the acknowledgement or timeout
+    Pending,
+    /// Acknowledged with a successful response
+    Completed,
+    /// Failed or timed out, the tokens are credited to the internal balance of the sender
+    Refunded,
+    /// Failed or timed out, the tokens are sent again with a new transfer
+    Resent,
+}
+
+/// Outgoing IBC transfer tracked by [TransferTracker]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TrackedTransfer {
+    /// **sender** is the owner of the transferred tokens, e.g. the user who called the contract
+    pub sender: String,
+    pub source_port: String,
+    pub source_channel: String,
+    pub token: Coin,
+    pub receiver: String,
+    pub memo: String,
+    pub fee: IbcFee,
+    pub state: TransferState,
+    /// **attempt** is the number of times the tokens were sent before this transfer
+    pub attempt: u32,
+    /// **error** is the error details of the failed transfer
+    pub error: Option<String>,
+}
+
+/// What to do with the tokens of a failed or timed out transfer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefundPolicy {
+    /// Credit the tokens to the internal balance of the sender
+    Credit,
+    /// Send the tokens again with **timeout_seconds** timeout, up to **max_attempts** times,
+    /// then credit them to the internal balance of the sender
+    Resend {
+        max_attempts: u32,
+        timeout_seconds: u64,
+    },
+}
+
+/// Result of [TransferTracker::handle_sudo]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferOutcome {
+    Completed(TrackedTransfer),
+    Refunded(TrackedTransfer),
+    /// The tokens are sent again with the submessage
+    Resent(TrackedTransfer, Box<SubMsg<NeutronMsg>>),
+    /// The sudo message is not for a tracked transfer
+    Untracked,
+}
+
+/// Tracks outgoing IBC transfers by channel and sequence together with the owner of the tokens
+/// and returns the tokens to the owner if the transfer fails.
+///
+/// The flow is [TransferTracker::transfer] -> reply() -> [TransferTracker::handle_reply] ->
+/// sudo() -> [TransferTracker::handle_sudo]. Refunded tokens are kept by the contract and
+/// credited to the internal balance of the owner, who can withdraw them with [TransferTracker::withdraw].
+///
+/// Only pending and failed transfers are kept in the storage: completed transfers are removed
+/// once acknowledged, failed transfers are kept until the contract removes them with
+/// [TransferTracker::remove_failed].
+///
+/// ```rust ignore
+/// const TRANSFERS: TransferTracker = TransferTracker::new("transfer_replies", "transfer_payloads", "transfers", "failed_transfers", "refunds", 2_000_000);
+///
+/// // execute
+/// let submsg = TRANSFERS.transfer(deps.storage, info.sender, NeutronMsg::IbcTransfer { .. })?;
+///
+/// // reply
+/// if TRANSFERS.is_reply(msg.id) {
+///     TRANSFERS.handle_reply(deps.storage, msg)?;
+/// }
+///
+/// // sudo
+/// match TRANSFERS.handle_sudo(deps.storage, &env, &msg, RefundPolicy::Credit)? { .. }
+/// ```
+pub struct TransferTracker {
+    payloads: SudoPayloadStore<TrackedTransfer>,
+    pending: Map<(String, u64), TrackedTransfer>,
+    failed: Map<(String, u64), TrackedTransfer>,
+    balances: Map<(String, String), Uint128>,
+}
+
+impl TransferTracker {
+    /// Creates a tracker under the given namespaces. Reply ids from **reply_id_start** to
+    /// **reply_id_start** + [crate::sudo::payload_store::SUDO_PAYLOAD_REPLY_ID_RANGE_SIZE] are reserved by the tracker.
+    pub const fn new(
+        reply_namespace: &'static str,
+        payloads_namespace: &'static str,
+        pending_namespace: &'static str,
+        failed_namespace: &'static str,
+        balances_namespace: &'static str,
+        reply_id_start: u64,
+    ) -> Self {
+        TransferTracker {
+            payloads: SudoPayloadStore::new(reply_namespace, payloads_namespace, reply_id_start),
+            pending: Map::new(pending_namespace),
+            failed: Map::new(failed_namespace),
+            balances: Map::new(balances_namespace),
+        }
+    }
+
+    /// Wraps the **IbcTransfer** message into a submessage and starts tracking the transfer
+    /// of the tokens owned by **sender**
+    pub fn transfer(
+        &self,
+        storage: &mut dyn Storage,
+        sender: impl Into<String>,
+        msg: NeutronMsg,
+    ) -> NeutronResult<SubMsg<NeutronMsg>> {
+        let NeutronMsg::IbcTransfer {
+            source_port,
+            source_channel,
+            token,
+            receiver,
+            memo,
+            fee,
+            ..
+        } = &msg
+        else {
+            return Err(NeutronError::NotIbcTransfer {});
+        };
+
+        let transfer = TrackedTransfer {
+            sender: sender.into(),
+            source_port: source_port.clone(),
+            source_channel: source_channel.clone(),
+            token: token.clone(),
+            receiver: receiver.clone(),
+            memo: memo.clone(),
+            fee: fee.clone(),
+            state: TransferState::Pending,
+            attempt: 0,
+            error: None,
+        };
+        self.payloads.with_sudo_callback(storage, msg, &transfer)
+    }
+
+    /// Checks whether the reply **id** belongs to the tracker
+    pub fn is_reply(&self, id: u64) -> bool {
+        self.payloads.is_reply(id)
+    }
+
+    /// Binds the transfer of the reply to the channel and sequence id of the sent packet
+    pub fn handle_reply(
+        &self,
+        storage: &mut dyn Storage,
+        reply: Reply,
+    ) -> NeutronResult<(String, u64)> {
+        let (channel_id, seq_id) = self.payloads.handle_reply(storage, reply)?;
+        if let Some(transfer) = self.payloads.take(storage, channel_id.clone(), seq_id)? {
+            self.pending
+                .save(storage, (channel_id.clone(), seq_id), &transfer)?;
+        }
+
+        Ok((channel_id, seq_id))
+    }
+
+    /// Updates the transfer state on the acknowledgement or timeout of its packet.
+    /// Tokens of a failed or timed out transfer are refunded according to the **policy**.
+    /// A completed transfer is removed from the storage, a failed one is moved to the failed transfers.
+    pub fn handle_sudo(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        msg: &TransferSudoMsg,
+        policy: RefundPolicy,
+    ) -> NeutronResult<TransferOutcome> {
+        let (request, error) = match msg {
+            TransferSudoMsg::Response { request, .. } => (request, None),
+            TransferSudoMsg::Error { request, details } => (request, Some(details.clone())),
+            TransferSudoMsg::Timeout { request } => (request, Some("timeout".to_string())),
+        };
+        // only pending transfers are loaded, so a repeated sudo message is untracked
+        let Some((key, mut transfer)) = self.load_pending_for_request(storage, request)? else {
+            return Ok(TransferOutcome::Untracked);
+        };
+        self.pending.remove(storage, key.clone());
+
+        let Some(error) = error else {
+            transfer.state = TransferState::Completed;
+            return Ok(TransferOutcome::Completed(transfer));
+        };
+        transfer.error = Some(error);
+
+        match policy {
+            RefundPolicy::Resend {
+                max_attempts,
+                timeout_seconds,
+            } if transfer.attempt < max_attempts => {
+                transfer.state = TransferState::Resent;
+                self.failed.save(storage, key, &transfer)?;
+
+                let resend = TrackedTransfer {
+                    state: TransferState::Pending,
+                    attempt: transfer.attempt + 1,
+                    error: None,
+                    ..transfer.clone()
+                };
+                let msg = NeutronMsg::IbcTransfer {
+                    source_port: resend.source_port.clone(),
+                    source_channel: resend.source_channel.clone(),
+                    token: resend.token.clone(),
+                    sender: env.contract.address.to_string(),
+                    receiver: resend.receiver.clone(),
+                    timeout_height: RequestPacketTimeoutHeight {
+                        revision_number: None,
+                        revision_height: None,
+                    },
+                    timeout_timestamp: env.block.time.plus_seconds(timeout_seconds).nanos(),
+                    memo: resend.memo.clone(),
+                    fee: resend.fee.clone(),
+                };
+                let submsg = self.payloads.with_sudo_callback(storage, msg, &resend)?;
+                Ok(TransferOutcome::Resent(transfer, Box::new(submsg)))
+            }
+            _ => {
+                transfer.state = TransferState::Refunded;
+                self.failed.save(storage, key, &transfer)?;
+                self.credit(storage, &transfer.sender, &transfer.token)?;
+                Ok(TransferOutcome::Refunded(transfer))
+            }
+        }
+    }
+
+    /// Returns the pending or failed transfer of the packet with **seq_id** sent from **channel_id**.
+    /// Completed transfers are not kept, so **None** is returned for them.
+    pub fn load(
+        &self,
+        storage: &dyn Storage,
+        channel_id: String,
+        seq_id: u64,
+    ) -> StdResult<Option<TrackedTransfer>> {
+        let key = (channel_id, seq_id);
+        match self.pending.may_load(storage, key.clone())? {
+            Some(transfer) => Ok(Some(transfer)),
+            None => self.failed.may_load(storage, key),
+        }
+    }
+
+    /// Returns up to **limit** transfers waiting for the acknowledgement or timeout,
+    /// ordered by channel and sequence id and starting after the **start_after** key
+    pub fn pending(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<(String, u64)>,
+        limit: usize,
+    ) -> StdResult<Vec<((String, u64), TrackedTransfer)>> {
+        Self::page(&self.pending, storage, start_after, limit)
+    }
+
+    /// Returns up to **limit** failed or timed out transfers,
+    /// ordered by channel and sequence id and starting after the **start_after** key
+    pub fn failed(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<(String, u64)>,
+        limit: usize,
+    ) -> StdResult<Vec<((String, u64), TrackedTransfer)>> {
+        Self::page(&self.failed, storage, start_after, limit)
+    }
+
+    /// Removes the failed transfer of the packet with **seq_id** sent from **channel_id**,
+    /// e.g. once the contract has processed it. Its refunded tokens stay on the balance of the sender.
+    pub fn remove_failed(&self, storage: &mut dyn Storage, channel_id: String, seq_id: u64) {
+        self.failed.remove(storage, (channel_id, seq_id));
+    }
+
+    /// Returns the refunded amount of **denom** owned by the **address**
+    pub fn balance(&self, storage: &dyn Storage, address: &str, denom: &str) -> StdResult<Uint128> {
+        Ok(self
+            .balances
+            .may_load(storage, (address.to_string(), denom.to_string()))?
+            .unwrap_or_default())
+    }
+
+    /// Debits the refunded **amount** from the balance of the **address** and sends it to the address
+    pub fn withdraw(
+        &self,
+        storage: &mut dyn Storage,
+        address: impl Into<String>,
+        amount: Coin,
+    ) -> NeutronResult<BankMsg> {
+        let address = address.into();
+        let key = (address.clone(), amount.denom.clone());
+        let balance = self
+            .balances
+            .may_load(storage, key.clone())?
+            .unwrap_or_default();
+        let balance = balance.checked_sub(amount.amount).map_err(|_| {
+            NeutronError::InsufficientRefundBalance {
+                address: address.clone(),
+                denom: amount.denom.clone(),
+                balance,
+                amount: amount.amount,
+            }
+        })?;
+        if balance.is_zero() {
+            self.balances.remove(storage, key);
+        } else {
+            self.balances.save(storage, key, &balance)?;
+        }
+
+        Ok(BankMsg::Send {
+            to_address: address,
+            amount: vec![amount],
+        })
+    }
+
+    fn credit(&self, storage: &mut dyn Storage, address: &str, amount: &Coin) -> StdResult<()> {
+        let balance = self
+            .balance(storage, address, &amount.denom)?
+            .checked_add(amount.amount)?;
+        self.balances.save(
+            storage,
+            (address.to_string(), amount.denom.clone()),
+            &balance,
+        )
+    }
+
+    fn load_pending_for_request(
+        &self,
+        storage: &dyn Storage,
+        request: &RequestPacket,
+    ) -> StdResult<Option<((String, u64), TrackedTransfer)>> {
+        let (Some(channel_id), Some(seq_id)) = (request.source_channel.clone(), request.sequence)
+        else {
+            return Ok(None);
+        };
+        let key = (channel_id, seq_id);
+        Ok(self
+            .pending
+            .may_load(storage, key.clone())?
+            .map(|transfer| (key, transfer)))
+    }
+
+    fn page(
+        transfers: &Map<(String, u64), TrackedTransfer>,
+        storage: &dyn Storage,
+        start_after: Option<(String, u64)>,
+        limit: usize,
+    ) -> StdResult<Vec<((String, u64), TrackedTransfer)>> {
+        transfers
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto_types::neutron::transfer::MsgTransferResponse;
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+    use cosmwasm_std::{coin, Binary, MsgResponse, SubMsgResponse, SubMsgResult};
+    use prost::Message;
+
+    const TRANSFERS: TransferTracker =
+        TransferTracker::new("replies", "payloads", "pending", "failed", "balances", 100);
+
+    fn transfer_msg(amount: u128) -> NeutronMsg {
+        NeutronMsg::IbcTransfer {
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            token: coin(amount, "untrn"),
+            sender: "neutron1contract".to_string(),
+            receiver: "cosmos1receiver".to_string(),
+            timeout_height: RequestPacketTimeoutHeight {
+                revision_number: None,
+                revision_height: None,
+            },
+            timeout_timestamp: 0,
+            memo: String::new(),
+            fee: IbcFee {
+                recv_fee: vec![],
+                ack_fee: vec![],
+                timeout_fee: vec![],
+            },
+        }
+    }
+
+    /// Sends the submessage and handles its reply with the **seq_id** sequence
+    fn send(storage: &mut dyn Storage, submsg: SubMsg<NeutronMsg>, seq_id: u64) {
+        #[allow(deprecated)]
+        let response = SubMsgResponse {
+            events: vec![],
+            data: None,
+            msg_responses: vec![MsgResponse {
+                type_url: MsgTransferResponse::TYPE_URL.to_string(),
+                value: Binary::new(
+                    MsgTransferResponse {
+                        sequence_id: seq_id,
+                        channel: "channel-0".to_string(),
+                    }
+                    .encode_to_vec(),
+                ),
+            }],
+        };
+        TRANSFERS
+            .handle_reply(
+                storage,
+                Reply {
+                    id: submsg.id,
+                    payload: Binary::default(),
+                    gas_used: 0,
+                    result: SubMsgResult::Ok(response),
+                },
+            )
+            .unwrap();
+    }
+
+    fn request(seq_id: u64) -> RequestPacket {
+        RequestPacket {
+            sequence: Some(seq_id),
+            source_port: Some("transfer".to_string()),
+            source_channel: Some("channel-0".to_string()),
+            destination_port: None,
+            destination_channel: None,
+            data: None,
+            timeout_height: None,
+            timeout_timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_complete_and_refund() {
+        let mut storage = MockStorage::default();
+        let env = mock_env();
+
+        let submsg = TRANSFERS
+            .transfer(&mut storage, "alice", transfer_msg(100))
+            .unwrap();
+        send(&mut storage, submsg, 1);
+        let submsg = TRANSFERS
+            .transfer(&mut storage, "bob", transfer_msg(50))
+            .unwrap();
+        send(&mut storage, submsg, 2);
+        assert_eq!(TRANSFERS.pending(&storage, None, 10).unwrap().len(), 2);
+        let page = TRANSFERS
+            .pending(&storage, Some(("channel-0".to_string(), 1)), 10)
+            .unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].1.sender, "bob");
+
+        let outcome = TRANSFERS
+            .handle_sudo(
+                &mut storage,
+                &env,
+                &TransferSudoMsg::Response {
+                    request: request(1),
+                    data: Binary::default(),
+                },
+                RefundPolicy::Credit,
+            )
+            .unwrap();
+        assert!(matches!(outcome, TransferOutcome::Completed(t) if t.sender == "alice"));
+        // completed transfers are not kept
+        assert_eq!(
+            TRANSFERS.load(&storage, "channel-0".to_string(), 1),
+            Ok(None)
+        );
+
+        let outcome = TRANSFERS
+            .handle_sudo(
+                &mut storage,
+                &env,
+                &TransferSudoMsg::Timeout {
+                    request: request(2),
+                },
+                RefundPolicy::Credit,
+            )
+            .unwrap();
+        assert!(matches!(outcome, TransferOutcome::Refunded(t) if t.sender == "bob"));
+        assert_eq!(
+            TRANSFERS.balance(&storage, "bob", "untrn"),
+            Ok(Uint128::new(50))
+        );
+        assert!(TRANSFERS.pending(&storage, None, 10).unwrap().is_empty());
+        assert_eq!(
+            TRANSFERS.failed(&storage, None, 10).unwrap()[0].0,
+            ("channel-0".to_string(), 2)
+        );
+        assert!(TRANSFERS
+            .failed(&storage, Some(("channel-0".to_string(), 2)), 10)
+            .unwrap()
+            .is_empty());
+
+        // repeated sudo doesn't credit twice
+        assert_eq!(
+            TRANSFERS.handle_sudo(
+                &mut storage,
+                &env,
+                &TransferSudoMsg::Timeout {
+                    request: request(2),
+                },
+                RefundPolicy::Credit,
+            ),
+            Ok(TransferOutcome::Untracked)
+        );
+
+        assert!(TRANSFERS
+            .withdraw(&mut storage, "bob", coin(51, "untrn"))
+            .is_err());
+        assert_eq!(
+            TRANSFERS.withdraw(&mut storage, "bob", coin(50, "untrn")),
+            Ok(BankMsg::Send {
+                to_address: "bob".to_string(),
+                amount: vec![coin(50, "untrn")],
+            })
+        );
+        assert_eq!(
+            TRANSFERS.balance(&storage, "bob", "untrn"),
+            Ok(Uint128::zero())
+        );
+
+        TRANSFERS.remove_failed(&mut storage, "channel-0".to_string(), 2);
+        assert!(TRANSFERS.failed(&storage, None, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_credit_overflow() {
+        let mut storage = MockStorage::default();
+        TRANSFERS
+            .credit(&mut storage, "alice", &coin(u128::MAX, "untrn"))
+            .unwrap();
+        assert!(TRANSFERS
+            .credit(&mut storage, "alice", &coin(1, "untrn"))
+            .is_err());
+        assert_eq!(
+            TRANSFERS.balance(&storage, "alice", "untrn"),
+            Ok(Uint128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_resend() {
+        let mut storage = MockStorage::default();
+        let env = mock_env();
+        let policy = RefundPolicy::Resend {
+            max_attempts: 1,
+            timeout_seconds: 600,
+        };
+
+        let submsg = TRANSFERS
+            .transfer(&mut storage, "alice", transfer_msg(100))
+            .unwrap();
+        send(&mut storage, submsg, 1);
+
+        let error = TransferSudoMsg::Error {
+            request: request(1),
+            details: "error".to_string(),
+        };
+        let TransferOutcome::Resent(_, submsg) = TRANSFERS
+            .handle_sudo(&mut storage, &env, &error, policy)
+            .unwrap()
+        else {
+            panic!("transfer is not resent");
+        };
+        let cosmwasm_std::CosmosMsg::Custom(NeutronMsg::IbcTransfer {
+            timeout_timestamp, ..
+        }) = &submsg.msg
+        else {
+            panic!("unexpected message");
+        };
+        assert_eq!(*timeout_timestamp, env.block.time.plus_seconds(600).nanos());
+        send(&mut storage, *submsg, 2);
+
+        let outcome = TRANSFERS
+            .handle_sudo(
+                &mut storage,
+                &env,
+                &TransferSudoMsg::Error {
+                    request: request(2),
+                    details: "error".to_string(),
+                },
+                policy,
+            )
+            .unwrap();
+        assert!(matches!(outcome, TransferOutcome::Refunded(t) if t.attempt == 1));
+        assert_eq!(
+            TRANSFERS.balance(&storage, "alice", "untrn"),
+            Ok(Uint128::new(100))
+        );
+    }
+}
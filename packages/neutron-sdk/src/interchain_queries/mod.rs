@@ -1,7 +1,14 @@
 pub mod helpers;
 pub mod queries;
+pub mod registry;
+pub mod tx;
+pub mod tx_filter;
 pub mod types;
 pub mod v045;
 pub mod v047;
+pub mod v050;
 
-pub use queries::{check_query_type, get_registered_query, query_kv_result};
+pub use queries::{
+    check_query_type, get_registered_query, query_kv_result, query_kv_result_fresh,
+    query_kv_result_with_heights,
+};
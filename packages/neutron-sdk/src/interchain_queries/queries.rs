@@ -4,7 +4,9 @@ use crate::bindings::query::{
 use crate::errors::error::NeutronResult;
 use crate::interchain_queries::types::{KVReconstruct, QueryType};
 use crate::NeutronError;
-use cosmwasm_std::Deps;
+use cosmwasm_std::{Deps, Env};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 /// Checks **actual** query type is **expected** query type
 pub fn check_query_type(actual: QueryType, expected: QueryType) -> NeutronResult<()> {
@@ -39,6 +41,65 @@ pub fn query_kv_result<T: KVReconstruct>(
     KVReconstruct::reconstruct(&registered_query_result.result.kv_results)
 }
 
+/// Reconstructed result of a KV Interchain Query together with the heights it was obtained at
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct KVResultWithHeights<T> {
+    /// **value** is the reconstructed query result
+    pub value: T,
+    /// **remote_height** is a height of remote chain the result was read at
+    pub remote_height: u64,
+    /// **revision** is a revision of remote chain the result was read at
+    pub revision: u64,
+    /// **last_submitted_local_height** is a local chain height the result was submitted at
+    pub last_submitted_local_height: u64,
+}
+
+/// Reads submitted raw KV values for Interchain Query with **query_id** from the storage,
+/// reconstructs the result and returns it together with remote and local heights of the result
+pub fn query_kv_result_with_heights<T: KVReconstruct>(
+    deps: Deps<NeutronQuery>,
+    query_id: u64,
+) -> NeutronResult<KVResultWithHeights<T>> {
+    let registered_query = get_registered_query(deps, query_id)?;
+    let registered_query_result = get_raw_interchain_query_result(deps, query_id)?;
+
+    Ok(KVResultWithHeights {
+        value: KVReconstruct::reconstruct(&registered_query_result.result.kv_results)?,
+        remote_height: registered_query_result.result.height,
+        revision: registered_query_result.result.revision,
+        last_submitted_local_height: registered_query
+            .registered_query
+            .last_submitted_result_local_height,
+    })
+}
+
+/// Reads submitted raw KV values for Interchain Query with **query_id** from the storage and reconstructs the result
+/// only if the result was submitted no more than **max_age_blocks** local blocks ago.
+/// Fails with [NeutronError::StaleQueryResult] otherwise, including when no result was submitted yet.
+pub fn query_kv_result_fresh<T: KVReconstruct>(
+    deps: Deps<NeutronQuery>,
+    env: Env,
+    query_id: u64,
+    max_age_blocks: u64,
+) -> NeutronResult<T> {
+    let registered_query = get_registered_query(deps, query_id)?;
+    let last_submitted_local_height = registered_query
+        .registered_query
+        .last_submitted_result_local_height;
+
+    let age = env.block.height.saturating_sub(last_submitted_local_height);
+    if last_submitted_local_height == 0 || age > max_age_blocks {
+        return Err(NeutronError::StaleQueryResult {
+            query_id,
+            age,
+            max_age: max_age_blocks,
+        });
+    }
+
+    query_kv_result(deps, query_id)
+}
+
 /// Queries raw interchain query result (raw KV storage values or transactions) from Interchain Queries Module.
 /// Usually it is better to implement [KVReconstruct] for your own type and then use [query_kv_result],
 /// but in cases when Rust forbids to implement foreign trait [KVReconstruct] for some foreign type,
@@ -53,3 +114,135 @@ pub fn get_raw_interchain_query_result(
     let res = deps.querier.query(&interchain_query.into())?;
     Ok(res)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::query::{
+        QueryRegisteredQueryResponse, QueryRegisteredQueryResultResponse,
+    };
+    use crate::bindings::types::{Height, InterchainQueryResult, RegisteredQuery, StorageValue};
+    use crate::interchain_queries::types::QueryType;
+    use crate::testing::{mock_neutron_deps, NeutronDeps};
+    use cosmwasm_std::testing::{mock_env, MockQuerier};
+    use cosmwasm_std::{to_json_binary, Binary, ContractResult, SystemResult, Uint128};
+
+    const LAST_SUBMITTED_LOCAL_HEIGHT: u64 = 12_300;
+
+    fn mock_deps(last_submitted_local_height: u64) -> NeutronDeps {
+        let querier = MockQuerier::new(&[]).with_custom_handler(move |query| match query {
+            NeutronQuery::RegisteredInterchainQuery { query_id } => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&QueryRegisteredQueryResponse {
+                        registered_query: RegisteredQuery {
+                            id: *query_id,
+                            owner: "neutron1owner".to_string(),
+                            keys: vec![],
+                            query_type: QueryType::KV,
+                            transactions_filter: "".to_string(),
+                            connection_id: "connection-0".to_string(),
+                            update_period: 10,
+                            last_submitted_result_local_height: last_submitted_local_height,
+                            last_submitted_result_remote_height: Height {
+                                revision_number: 1,
+                                revision_height: 5_000,
+                            },
+                            deposit: vec![],
+                            submit_timeout: 0,
+                            registered_at_height: 1,
+                        },
+                    })
+                    .unwrap(),
+                ))
+            }
+            NeutronQuery::InterchainQueryResult { .. } => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&QueryRegisteredQueryResultResponse {
+                    result: InterchainQueryResult {
+                        kv_results: vec![StorageValue {
+                            storage_prefix: "bank".to_string(),
+                            key: Binary::default(),
+                            value: to_json_binary(&Uint128::new(42)).unwrap(),
+                        }],
+                        height: 5_000,
+                        revision: 1,
+                    },
+                })
+                .unwrap(),
+            )),
+            _ => unimplemented!(),
+        });
+
+        mock_neutron_deps(querier)
+    }
+
+    fn env_at(height: u64) -> Env {
+        let mut env = mock_env();
+        env.block.height = height;
+        env
+    }
+
+    #[test]
+    fn test_query_kv_result_with_heights() {
+        let deps = mock_deps(LAST_SUBMITTED_LOCAL_HEIGHT);
+
+        let result: KVResultWithHeights<Uint128> =
+            query_kv_result_with_heights(deps.as_ref(), 1).unwrap();
+
+        assert_eq!(
+            result,
+            KVResultWithHeights {
+                value: Uint128::new(42),
+                remote_height: 5_000,
+                revision: 1,
+                last_submitted_local_height: LAST_SUBMITTED_LOCAL_HEIGHT,
+            }
+        );
+    }
+
+    #[test]
+    fn test_query_kv_result_fresh() {
+        struct TestCase {
+            last_submitted_local_height: u64,
+            block_height: u64,
+            expected_result: NeutronResult<Uint128>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                last_submitted_local_height: LAST_SUBMITTED_LOCAL_HEIGHT,
+                block_height: LAST_SUBMITTED_LOCAL_HEIGHT,
+                expected_result: Ok(Uint128::new(42)),
+            },
+            TestCase {
+                last_submitted_local_height: LAST_SUBMITTED_LOCAL_HEIGHT,
+                block_height: LAST_SUBMITTED_LOCAL_HEIGHT + 100,
+                expected_result: Ok(Uint128::new(42)),
+            },
+            TestCase {
+                last_submitted_local_height: LAST_SUBMITTED_LOCAL_HEIGHT,
+                block_height: LAST_SUBMITTED_LOCAL_HEIGHT + 101,
+                expected_result: Err(NeutronError::StaleQueryResult {
+                    query_id: 1,
+                    age: 101,
+                    max_age: 100,
+                }),
+            },
+            TestCase {
+                last_submitted_local_height: 0,
+                block_height: 50,
+                expected_result: Err(NeutronError::StaleQueryResult {
+                    query_id: 1,
+                    age: 50,
+                    max_age: 100,
+                }),
+            },
+        ];
+
+        for tc in test_cases {
+            let deps = mock_deps(tc.last_submitted_local_height);
+            let result: NeutronResult<Uint128> =
+                query_kv_result_fresh(deps.as_ref(), env_at(tc.block_height), 1, 100);
+            assert_eq!(result, tc.expected_result);
+        }
+    }
+}
@@ -0,0 +1,561 @@
+use crate::{
+    bindings::{msg::NeutronMsg, query::NeutronQuery, types::KVKey},
+    errors::error::{NeutronError, NeutronResult},
+    interchain_queries::{
+        queries::query_kv_result,
+        types::{KVResultType, QUERY_TYPE_KV_VALUE},
+    },
+    interchain_txs::helpers::decode_message_response,
+    proto_types::neutron::interchainqueries::MsgRegisterInterchainQueryResponse,
+};
+use cosmwasm_std::{
+    Deps, DepsMut, Empty, Env, Order, Reply, Response, StdError, StdResult, Storage, SubMsg,
+};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Number of reply ids reserved by a registry, i.e. how many queries can be registered in a single transaction
+pub const ICQ_REGISTRY_REPLY_ID_RANGE_SIZE: u64 = 1_000;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes an Interchain Query stored in the [IcqRegistry]
+pub struct RegisteredIcq {
+    /// **label** is a contract defined name of the query, e.g. "hub_balance"
+    pub label: String,
+    /// **result_type** is [KVResultType::RESULT_TYPE] of the type the query result is reconstructed to
+    pub result_type: String,
+}
+
+/// Keeps track of KV Interchain Queries registered by a contract.
+///
+/// Queries are registered under a user label with [IcqRegistry::register] in a submessage.
+/// The registry saves the pending label under a reply id from its own range, and binds the label
+/// to the query id returned by the **interchainqueries** module in [IcqRegistry::handle_reply].
+/// KV query results are then routed to per-label handlers with [IcqRegistry::handle_kv_query_result].
+///
+/// ```rust ignore
+/// const ICQ_REGISTRY: IcqRegistry =
+///     IcqRegistry::new("icq_queries", "icq_labels", "icq_pending", 1_000_000);
+///
+/// // execute
+/// let msg = new_register_balances_query_msg(connection_id, addr, denoms, update_period)?;
+/// let submsg = ICQ_REGISTRY.register::<Balances>(deps.storage, "hub_balance", msg)?;
+///
+/// // reply
+/// if ICQ_REGISTRY.is_reply(msg.id) {
+///     ICQ_REGISTRY.handle_reply(deps.storage, msg)?;
+/// }
+///
+/// // sudo
+/// SudoMsg::KVQueryResult { query_id } => ICQ_REGISTRY.handle_kv_query_result(
+///     deps,
+///     env,
+///     query_id,
+///     &IcqHandlers::new().on("hub_balance", on_hub_balance),
+/// ),
+/// ```
+pub struct IcqRegistry {
+    queries: Map<u64, RegisteredIcq>,
+    labels: Map<String, u64>,
+    pending: Map<u64, RegisteredIcq>,
+    reply_id_start: u64,
+}
+
+impl IcqRegistry {
+    /// Creates a registry stored under the given namespaces. Reply ids from **reply_id_start**
+    /// to **reply_id_start** + [ICQ_REGISTRY_REPLY_ID_RANGE_SIZE] are reserved by the registry.
+    pub const fn new(
+        queries_namespace: &'static str,
+        labels_namespace: &'static str,
+        pending_namespace: &'static str,
+        reply_id_start: u64,
+    ) -> Self {
+        IcqRegistry {
+            queries: Map::new(queries_namespace),
+            labels: Map::new(labels_namespace),
+            pending: Map::new(pending_namespace),
+            reply_id_start,
+        }
+    }
+
+    /// Wraps a **RegisterInterchainQuery** KV message into a submessage which registers the query
+    /// under the **label**. The query result is expected to be reconstructed to **T**.
+    pub fn register<T: KVResultType>(
+        &self,
+        storage: &mut dyn Storage,
+        label: &str,
+        msg: NeutronMsg,
+    ) -> NeutronResult<SubMsg<NeutronMsg>> {
+        match &msg {
+            NeutronMsg::RegisterInterchainQuery { query_type, .. } => {
+                if query_type != QUERY_TYPE_KV_VALUE {
+                    return Err(NeutronError::InvalidQueryType {
+                        query_type: query_type.clone(),
+                    });
+                }
+            }
+            _ => {
+                return Err(NeutronError::Std(StdError::generic_err(
+                    "only RegisterInterchainQuery message can be registered in the registry",
+                )))
+            }
+        }
+
+        let label_is_pending = self
+            .pending
+            .range(storage, None, None, Order::Ascending)
+            .any(|item| matches!(item, Ok((_, pending)) if pending.label == label));
+        if self.labels.has(storage, label.to_string()) || label_is_pending {
+            return Err(NeutronError::IcqLabelAlreadyRegistered {
+                label: label.to_string(),
+            });
+        }
+
+        let reply_id = self.next_reply_id(storage)?;
+        self.pending.save(
+            storage,
+            reply_id,
+            &RegisteredIcq {
+                label: label.to_string(),
+                result_type: T::RESULT_TYPE.to_string(),
+            },
+        )?;
+
+        Ok(SubMsg::reply_on_success(msg, reply_id))
+    }
+
+    /// Checks whether the reply **id** belongs to the registry
+    pub fn is_reply(&self, id: u64) -> bool {
+        (self.reply_id_start..self.reply_id_start + ICQ_REGISTRY_REPLY_ID_RANGE_SIZE).contains(&id)
+    }
+
+    /// Binds the pending label to the id of the registered query and returns the id
+    pub fn handle_reply(&self, storage: &mut dyn Storage, reply: Reply) -> NeutronResult<u64> {
+        let pending = self.pending.load(storage, reply.id)?;
+
+        let response = reply.result.into_result().map_err(StdError::generic_err)?;
+        let msg_response = response.msg_responses.first().ok_or_else(|| {
+            StdError::generic_err("no msg response in RegisterInterchainQuery reply")
+        })?;
+        let query_id = decode_message_response::<MsgRegisterInterchainQueryResponse>(
+            &msg_response.value.to_vec(),
+        )?
+        .id;
+
+        self.pending.remove(storage, reply.id);
+        self.labels
+            .save(storage, pending.label.clone(), &query_id)?;
+        self.queries.save(storage, query_id, &pending)?;
+
+        Ok(query_id)
+    }
+
+    /// Returns id of the query registered under the **label**
+    pub fn query_id(&self, storage: &dyn Storage, label: &str) -> NeutronResult<u64> {
+        self.labels
+            .may_load(storage, label.to_string())?
+            .ok_or_else(|| NeutronError::IcqLabelNotFound {
+                label: label.to_string(),
+            })
+    }
+
+    /// Returns the registry entry of the query with **query_id**
+    pub fn get(&self, storage: &dyn Storage, query_id: u64) -> NeutronResult<RegisteredIcq> {
+        self.queries
+            .may_load(storage, query_id)?
+            .ok_or(NeutronError::IcqNotRegistered { query_id })
+    }
+
+    /// Returns all the registered queries ordered by their ids
+    pub fn queries(&self, storage: &dyn Storage) -> StdResult<Vec<(u64, RegisteredIcq)>> {
+        self.queries
+            .range(storage, None, None, Order::Ascending)
+            .collect()
+    }
+
+    /// Creates a message to update the query registered under the **label**
+    ///
+    /// * **new_keys** is the new query keys to retrieve;
+    /// * **new_update_period** is a new update period of the query.
+    pub fn update(
+        &self,
+        storage: &dyn Storage,
+        label: &str,
+        new_keys: Option<Vec<KVKey>>,
+        new_update_period: Option<u64>,
+    ) -> NeutronResult<NeutronMsg> {
+        let query_id = self.query_id(storage, label)?;
+
+        NeutronMsg::update_interchain_query(query_id, new_keys, new_update_period, None)
+    }
+
+    /// Forgets the query registered under the **label** and creates a message to remove it
+    /// from the **interchainqueries** module
+    pub fn remove(&self, storage: &mut dyn Storage, label: &str) -> NeutronResult<NeutronMsg> {
+        let query_id = self.query_id(storage, label)?;
+
+        self.labels.remove(storage, label.to_string());
+        self.queries.remove(storage, query_id);
+
+        Ok(NeutronMsg::remove_interchain_query(query_id))
+    }
+
+    /// Reads the result of the query registered under the **label** and reconstructs it to **T**
+    pub fn query_result<T: KVResultType>(
+        &self,
+        deps: Deps<NeutronQuery>,
+        label: &str,
+    ) -> NeutronResult<T> {
+        let query_id = self.query_id(deps.storage, label)?;
+        check_result_type::<T>(&self.get(deps.storage, query_id)?)?;
+
+        query_kv_result(deps, query_id)
+    }
+
+    /// Routes **SudoMsg::KVQueryResult** of the query with **query_id** to the handler
+    /// registered for its label
+    pub fn handle_kv_query_result<C>(
+        &self,
+        deps: DepsMut<NeutronQuery>,
+        env: Env,
+        query_id: u64,
+        handlers: &IcqHandlers<C>,
+    ) -> NeutronResult<Response<C>> {
+        let registered = self.get(deps.storage, query_id)?;
+
+        let handler = handlers
+            .handlers
+            .iter()
+            .find(|h| h.label == registered.label)
+            .ok_or_else(|| NeutronError::IcqHandlerNotFound {
+                label: registered.label.clone(),
+            })?;
+        if handler.result_type != registered.result_type {
+            return Err(NeutronError::IcqResultTypeMismatch {
+                label: registered.label,
+                expected: registered.result_type,
+                actual: handler.result_type.to_string(),
+            });
+        }
+
+        (handler.handle)(deps, env, query_id)
+    }
+
+    fn next_reply_id(&self, storage: &dyn Storage) -> NeutronResult<u64> {
+        let last = self
+            .pending
+            .keys(storage, None, None, Order::Descending)
+            .next()
+            .transpose()?;
+        let id = last.map_or(self.reply_id_start, |id| id + 1);
+
+        if !self.is_reply(id) {
+            return Err(NeutronError::Std(StdError::generic_err(
+                "too many interchain queries registered in a single transaction",
+            )));
+        }
+
+        Ok(id)
+    }
+}
+
+fn check_result_type<T: KVResultType>(registered: &RegisteredIcq) -> NeutronResult<()> {
+    if registered.result_type != T::RESULT_TYPE {
+        return Err(NeutronError::IcqResultTypeMismatch {
+            label: registered.label.clone(),
+            expected: registered.result_type.clone(),
+            actual: T::RESULT_TYPE.to_string(),
+        });
+    }
+    Ok(())
+}
+
+type KVResultHandler<'a, C> =
+    Box<dyn Fn(DepsMut<NeutronQuery>, Env, u64) -> NeutronResult<Response<C>> + 'a>;
+
+struct IcqHandler<'a, C> {
+    label: String,
+    result_type: &'static str,
+    handle: KVResultHandler<'a, C>,
+}
+
+/// Set of per-label handlers of KV query results for [IcqRegistry::handle_kv_query_result]
+pub struct IcqHandlers<'a, C = Empty> {
+    handlers: Vec<IcqHandler<'a, C>>,
+}
+
+impl<'a, C> Default for IcqHandlers<'a, C> {
+    fn default() -> Self {
+        IcqHandlers { handlers: vec![] }
+    }
+}
+
+impl<'a, C> IcqHandlers<'a, C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a **handler** of results of the query registered under the **label**.
+    /// The result is reconstructed to **T** before it is passed to the handler.
+    pub fn on<T: KVResultType + 'a>(
+        mut self,
+        label: impl Into<String>,
+        handler: impl Fn(DepsMut<NeutronQuery>, Env, T) -> NeutronResult<Response<C>> + 'a,
+    ) -> Self {
+        self.handlers.push(IcqHandler {
+            label: label.into(),
+            result_type: T::RESULT_TYPE,
+            handle: Box::new(move |deps, env, query_id| {
+                let result: T = query_kv_result(deps.as_ref(), query_id)?;
+                handler(deps, env, result)
+            }),
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::query::QueryRegisteredQueryResultResponse;
+    use crate::bindings::types::{InterchainQueryResult, StorageValue};
+    use crate::interchain_queries::v045::register_queries::new_register_balances_query_msg;
+    use crate::interchain_queries::v045::types::Balances;
+    use crate::testing::{mock_neutron_deps, NeutronDeps};
+    use cosmwasm_std::testing::{mock_env, MockQuerier};
+    use cosmwasm_std::{
+        to_json_binary, Binary, ContractResult, MsgResponse, SubMsgResponse, SubMsgResult,
+        SystemResult, Uint128,
+    };
+    use prost::Message;
+
+    const REGISTRY: IcqRegistry = IcqRegistry::new("queries", "labels", "pending", 100);
+
+    fn mock_deps() -> NeutronDeps {
+        let querier = MockQuerier::new(&[]).with_custom_handler(|query| match query {
+            NeutronQuery::InterchainQueryResult { .. } => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&QueryRegisteredQueryResultResponse {
+                    result: InterchainQueryResult {
+                        kv_results: vec![StorageValue {
+                            storage_prefix: "bank".to_string(),
+                            key: Binary::default(),
+                            value: to_json_binary(&Uint128::new(42)).unwrap(),
+                        }],
+                        height: 1,
+                        revision: 0,
+                    },
+                })
+                .unwrap(),
+            )),
+            _ => unimplemented!(),
+        });
+
+        mock_neutron_deps(querier)
+    }
+
+    fn register_msg() -> NeutronMsg {
+        new_register_balances_query_msg(
+            "connection-0".to_string(),
+            "cosmos17dtl0mjt3t77kpuhg2edqzjpszulwhgzuj9ljs".to_string(),
+            vec!["uatom".to_string()],
+            10,
+        )
+        .unwrap()
+    }
+
+    fn register_reply(id: u64, query_id: u64) -> Reply {
+        #[allow(deprecated)]
+        let response = SubMsgResponse {
+            events: vec![],
+            data: None,
+            msg_responses: vec![MsgResponse {
+                type_url: MsgRegisterInterchainQueryResponse::TYPE_URL.to_string(),
+                value: Binary::new(
+                    MsgRegisterInterchainQueryResponse { id: query_id }.encode_to_vec(),
+                ),
+            }],
+        };
+        Reply {
+            id,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: SubMsgResult::Ok(response),
+        }
+    }
+
+    #[test]
+    fn test_register_and_reply() {
+        let mut deps = mock_deps();
+
+        let first = REGISTRY
+            .register::<Uint128>(deps.as_mut().storage, "first", register_msg())
+            .unwrap();
+        let second = REGISTRY
+            .register::<Uint128>(deps.as_mut().storage, "second", register_msg())
+            .unwrap();
+        assert_eq!((first.id, second.id), (100, 101));
+        assert!(REGISTRY.is_reply(first.id));
+        assert!(!REGISTRY.is_reply(100 + ICQ_REGISTRY_REPLY_ID_RANGE_SIZE));
+
+        assert_eq!(
+            REGISTRY
+                .register::<Uint128>(deps.as_mut().storage, "first", register_msg())
+                .unwrap_err(),
+            NeutronError::IcqLabelAlreadyRegistered {
+                label: "first".to_string()
+            }
+        );
+
+        assert_eq!(
+            REGISTRY
+                .handle_reply(deps.as_mut().storage, register_reply(first.id, 7))
+                .unwrap(),
+            7
+        );
+        assert_eq!(
+            REGISTRY
+                .handle_reply(deps.as_mut().storage, register_reply(second.id, 8))
+                .unwrap(),
+            8
+        );
+
+        assert_eq!(
+            REGISTRY.query_id(deps.as_ref().storage, "first").unwrap(),
+            7
+        );
+        assert_eq!(
+            REGISTRY.get(deps.as_ref().storage, 8).unwrap(),
+            RegisteredIcq {
+                label: "second".to_string(),
+                result_type: Uint128::RESULT_TYPE.to_string(),
+            }
+        );
+        assert_eq!(REGISTRY.queries(deps.as_ref().storage).unwrap().len(), 2);
+
+        // reply ids are reused once the pending registrations are done
+        let third = REGISTRY
+            .register::<Uint128>(deps.as_mut().storage, "third", register_msg())
+            .unwrap();
+        assert_eq!(third.id, 100);
+    }
+
+    #[test]
+    fn test_register_tx_query() {
+        let mut deps = mock_deps();
+
+        let msg =
+            crate::interchain_queries::v045::register_queries::new_register_transfers_query_msg(
+                "connection-0".to_string(),
+                "cosmos17dtl0mjt3t77kpuhg2edqzjpszulwhgzuj9ljs".to_string(),
+                10,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            REGISTRY
+                .register::<Uint128>(deps.as_mut().storage, "transfers", msg)
+                .unwrap_err(),
+            NeutronError::InvalidQueryType {
+                query_type: "tx".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_route_kv_query_result() {
+        let mut deps = mock_deps();
+
+        let submsg = REGISTRY
+            .register::<Uint128>(deps.as_mut().storage, "balance", register_msg())
+            .unwrap();
+        REGISTRY
+            .handle_reply(deps.as_mut().storage, register_reply(submsg.id, 1))
+            .unwrap();
+
+        let handlers = IcqHandlers::<Empty>::new().on("balance", |_, _, balance: Uint128| {
+            Ok(Response::new().add_attribute("balance", balance.to_string()))
+        });
+        let response = REGISTRY
+            .handle_kv_query_result(deps.as_mut(), mock_env(), 1, &handlers)
+            .unwrap();
+        assert_eq!(response.attributes[0].value, "42");
+
+        assert_eq!(
+            REGISTRY
+                .query_result::<Uint128>(deps.as_ref(), "balance")
+                .unwrap(),
+            Uint128::new(42)
+        );
+
+        let wrong_handlers =
+            IcqHandlers::<Empty>::new().on("balance", |_, _, _: Balances| Ok(Response::new()));
+        assert!(matches!(
+            REGISTRY.handle_kv_query_result(deps.as_mut(), mock_env(), 1, &wrong_handlers),
+            Err(NeutronError::IcqResultTypeMismatch { .. })
+        ));
+
+        assert_eq!(
+            REGISTRY
+                .handle_kv_query_result(deps.as_mut(), mock_env(), 1, &IcqHandlers::<Empty>::new())
+                .unwrap_err(),
+            NeutronError::IcqHandlerNotFound {
+                label: "balance".to_string()
+            }
+        );
+        assert_eq!(
+            REGISTRY
+                .handle_kv_query_result(deps.as_mut(), mock_env(), 2, &handlers)
+                .unwrap_err(),
+            NeutronError::IcqNotRegistered { query_id: 2 }
+        );
+    }
+
+    #[test]
+    fn test_update_and_remove() {
+        let mut deps = mock_deps();
+
+        let submsg = REGISTRY
+            .register::<Uint128>(deps.as_mut().storage, "balance", register_msg())
+            .unwrap();
+        REGISTRY
+            .handle_reply(deps.as_mut().storage, register_reply(submsg.id, 3))
+            .unwrap();
+
+        assert_eq!(
+            REGISTRY
+                .update(deps.as_ref().storage, "balance", None, Some(20))
+                .unwrap(),
+            NeutronMsg::UpdateInterchainQuery {
+                query_id: 3,
+                new_keys: None,
+                new_update_period: Some(20),
+                new_transactions_filter: None,
+            }
+        );
+
+        assert_eq!(
+            REGISTRY.remove(deps.as_mut().storage, "balance").unwrap(),
+            NeutronMsg::RemoveInterchainQuery { query_id: 3 }
+        );
+        assert_eq!(
+            REGISTRY
+                .query_id(deps.as_ref().storage, "balance")
+                .unwrap_err(),
+            NeutronError::IcqLabelNotFound {
+                label: "balance".to_string()
+            }
+        );
+        assert_eq!(
+            REGISTRY.get(deps.as_ref().storage, 3).unwrap_err(),
+            NeutronError::IcqNotRegistered { query_id: 3 }
+        );
+
+        // the label can be reused after removal
+        REGISTRY
+            .register::<Uint128>(deps.as_mut().storage, "balance", register_msg())
+            .unwrap();
+    }
+}
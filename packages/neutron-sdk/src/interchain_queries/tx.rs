@@ -0,0 +1,621 @@
+use crate::{
+    bindings::types::{Height, ProtobufAny},
+    errors::error::{NeutronError, NeutronResult},
+    interchain_queries::{
+        types::{TransactionFilterItem, TransactionFilterOp, TransactionFilterValue},
+        v045::types::HEIGHT_FIELD,
+    },
+    proto_types::{
+        cosmos::{
+            bank::v1beta1::{MsgMultiSend, MsgSend},
+            distribution::v1beta1::MsgWithdrawDelegatorReward,
+            gov::{v1::MsgVote, v1beta1::MsgVote as MsgVoteV1Beta1},
+            staking::v1beta1::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate},
+            tx::v1beta1::{AuthInfo, TxBody, TxRaw},
+        },
+        cosmwasm::wasm::v1::MsgExecuteContract,
+        ibc::applications::transfer::v1::MsgTransfer,
+    },
+    shim::Any,
+    try_proto_to_cosmwasm_coins,
+};
+use cosmwasm_std::{Binary, Coin};
+use prost::Message;
+
+/// Event attributes which can be checked against a decoded transaction by [`DecodedTx::matches_filters`].
+/// Values of these attributes are derived from the decoded transaction messages, so any other
+/// attribute (e.g. emitted by a module on execution) can't be verified by the contract.
+pub const SUPPORTED_FILTER_FIELDS: &[&str] = &[
+    HEIGHT_FIELD,
+    "message.action",
+    "message.sender",
+    "transfer.sender",
+    "transfer.recipient",
+    "delegate.validator",
+    "delegate.delegator",
+    "unbond.validator",
+    "unbond.delegator",
+    "redelegate.source_validator",
+    "redelegate.destination_validator",
+    "redelegate.delegator",
+    "withdraw_rewards.validator",
+    "withdraw_rewards.delegator",
+    "proposal_vote.proposal_id",
+    "proposal_vote.voter",
+    "ibc_transfer.sender",
+    "ibc_transfer.receiver",
+    "execute._contract_address",
+    "wasm._contract_address",
+];
+
+/// A transaction from remote chain decoded from the data of a `SudoMsg::TxQueryResult` callback.
+///
+/// Only the transaction envelope is decoded eagerly. Messages and auth info are decoded on demand,
+/// so a contract pays only for the parts it reads and can bound the number of decoded messages.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedTx {
+    /// **height** is the remote chain height of the block the transaction was included in
+    pub height: Height,
+    /// **messages** are the raw transaction body messages, see [`DecodedTx::decode_messages`]
+    pub messages: Vec<Any>,
+    /// **memo** is the transaction memo
+    pub memo: String,
+    /// **timeout_height** is the block height after which the transaction is not allowed to be committed
+    pub timeout_height: u64,
+    /// **auth_info** is the encoded `AuthInfo` of the transaction, see [`DecodedTx::fee`]
+    pub auth_info: Binary,
+}
+
+/// Fee paid for a transaction on remote chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxFee {
+    pub amount: Vec<Coin>,
+    pub gas_limit: u64,
+    pub payer: String,
+    pub granter: String,
+}
+
+/// A transaction message decoded according to its type url
+#[derive(Clone, Debug, PartialEq)]
+pub enum TxMsg {
+    BankSend(MsgSend),
+    BankMultiSend(MsgMultiSend),
+    Delegate(MsgDelegate),
+    Undelegate(MsgUndelegate),
+    BeginRedelegate(MsgBeginRedelegate),
+    WithdrawDelegatorReward(MsgWithdrawDelegatorReward),
+    Vote(MsgVote),
+    VoteV1Beta1(MsgVoteV1Beta1),
+    IbcTransfer(MsgTransfer),
+    ExecuteContract(MsgExecuteContract),
+    /// A message of a type which is not known to the SDK, left undecoded
+    Unknown(ProtobufAny),
+    /// A message of a known type which failed to decode, left as is
+    Undecodable(ProtobufAny),
+}
+
+impl TxMsg {
+    /// Decodes a transaction message wrapped into **Any** according to its type url.
+    /// Messages of unknown types are returned as [`TxMsg::Unknown`].
+    pub fn decode(msg: Any) -> NeutronResult<TxMsg> {
+        let value = msg.value.as_slice();
+
+        Ok(match msg.type_url.as_str() {
+            MsgSend::TYPE_URL => TxMsg::BankSend(MsgSend::decode(value)?),
+            MsgMultiSend::TYPE_URL => TxMsg::BankMultiSend(MsgMultiSend::decode(value)?),
+            MsgDelegate::TYPE_URL => TxMsg::Delegate(MsgDelegate::decode(value)?),
+            MsgUndelegate::TYPE_URL => TxMsg::Undelegate(MsgUndelegate::decode(value)?),
+            MsgBeginRedelegate::TYPE_URL => {
+                TxMsg::BeginRedelegate(MsgBeginRedelegate::decode(value)?)
+            }
+            MsgWithdrawDelegatorReward::TYPE_URL => {
+                TxMsg::WithdrawDelegatorReward(MsgWithdrawDelegatorReward::decode(value)?)
+            }
+            MsgVote::TYPE_URL => TxMsg::Vote(MsgVote::decode(value)?),
+            MsgVoteV1Beta1::TYPE_URL => TxMsg::VoteV1Beta1(MsgVoteV1Beta1::decode(value)?),
+            MsgTransfer::TYPE_URL => TxMsg::IbcTransfer(MsgTransfer::decode(value)?),
+            MsgExecuteContract::TYPE_URL => {
+                TxMsg::ExecuteContract(MsgExecuteContract::decode(value)?)
+            }
+            _ => TxMsg::Unknown(ProtobufAny::new(msg.type_url, Binary::new(msg.value))),
+        })
+    }
+
+    /// Returns the protobuf type url of the message
+    pub fn type_url(&self) -> &str {
+        match self {
+            TxMsg::BankSend(_) => MsgSend::TYPE_URL,
+            TxMsg::BankMultiSend(_) => MsgMultiSend::TYPE_URL,
+            TxMsg::Delegate(_) => MsgDelegate::TYPE_URL,
+            TxMsg::Undelegate(_) => MsgUndelegate::TYPE_URL,
+            TxMsg::BeginRedelegate(_) => MsgBeginRedelegate::TYPE_URL,
+            TxMsg::WithdrawDelegatorReward(_) => MsgWithdrawDelegatorReward::TYPE_URL,
+            TxMsg::Vote(_) => MsgVote::TYPE_URL,
+            TxMsg::VoteV1Beta1(_) => MsgVoteV1Beta1::TYPE_URL,
+            TxMsg::IbcTransfer(_) => MsgTransfer::TYPE_URL,
+            TxMsg::ExecuteContract(_) => MsgExecuteContract::TYPE_URL,
+            TxMsg::Unknown(msg) | TxMsg::Undecodable(msg) => msg.type_url.as_str(),
+        }
+    }
+
+    /// Returns event attributes the message emits on remote chain which can be derived from the message itself
+    pub fn event_attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attributes = vec![("message.action", self.type_url().to_string())];
+
+        match self {
+            TxMsg::BankSend(msg) => attributes.extend([
+                ("message.sender", msg.from_address.clone()),
+                ("transfer.sender", msg.from_address.clone()),
+                ("transfer.recipient", msg.to_address.clone()),
+            ]),
+            TxMsg::BankMultiSend(msg) => {
+                for input in &msg.inputs {
+                    attributes.push(("message.sender", input.address.clone()));
+                    attributes.push(("transfer.sender", input.address.clone()));
+                }
+                for output in &msg.outputs {
+                    attributes.push(("transfer.recipient", output.address.clone()));
+                }
+            }
+            TxMsg::Delegate(msg) => attributes.extend([
+                ("message.sender", msg.delegator_address.clone()),
+                ("delegate.delegator", msg.delegator_address.clone()),
+                ("delegate.validator", msg.validator_address.clone()),
+            ]),
+            TxMsg::Undelegate(msg) => attributes.extend([
+                ("message.sender", msg.delegator_address.clone()),
+                ("unbond.delegator", msg.delegator_address.clone()),
+                ("unbond.validator", msg.validator_address.clone()),
+            ]),
+            TxMsg::BeginRedelegate(msg) => attributes.extend([
+                ("message.sender", msg.delegator_address.clone()),
+                ("redelegate.delegator", msg.delegator_address.clone()),
+                (
+                    "redelegate.source_validator",
+                    msg.validator_src_address.clone(),
+                ),
+                (
+                    "redelegate.destination_validator",
+                    msg.validator_dst_address.clone(),
+                ),
+            ]),
+            TxMsg::WithdrawDelegatorReward(msg) => attributes.extend([
+                ("message.sender", msg.delegator_address.clone()),
+                ("withdraw_rewards.delegator", msg.delegator_address.clone()),
+                ("withdraw_rewards.validator", msg.validator_address.clone()),
+            ]),
+            TxMsg::Vote(msg) => attributes.extend([
+                ("message.sender", msg.voter.clone()),
+                ("proposal_vote.voter", msg.voter.clone()),
+                ("proposal_vote.proposal_id", msg.proposal_id.to_string()),
+            ]),
+            TxMsg::VoteV1Beta1(msg) => attributes.extend([
+                ("message.sender", msg.voter.clone()),
+                ("proposal_vote.voter", msg.voter.clone()),
+                ("proposal_vote.proposal_id", msg.proposal_id.to_string()),
+            ]),
+            TxMsg::IbcTransfer(msg) => attributes.extend([
+                ("message.sender", msg.sender.clone()),
+                ("ibc_transfer.sender", msg.sender.clone()),
+                ("ibc_transfer.receiver", msg.receiver.clone()),
+            ]),
+            TxMsg::ExecuteContract(msg) => attributes.extend([
+                ("message.sender", msg.sender.clone()),
+                ("execute._contract_address", msg.contract.clone()),
+                ("wasm._contract_address", msg.contract.clone()),
+            ]),
+            TxMsg::Unknown(_) | TxMsg::Undecodable(_) => {}
+        }
+
+        attributes
+    }
+}
+
+impl DecodedTx {
+    /// Checks whether the transaction satisfies all the **filters** of a registered TX Interchain Query.
+    /// A filter is satisfied if at least one of the transaction messages emits a matching event attribute.
+    ///
+    /// Only the first **max_messages** messages are checked, see [`DecodedTx::decode_messages`].
+    ///
+    /// Returns [`NeutronError::UnsupportedTransactionFilterField`] if a filter field is not one of
+    /// [`SUPPORTED_FILTER_FIELDS`], since such a filter can't be checked using the transaction data only.
+    pub fn matches_filters(
+        &self,
+        filters: &[TransactionFilterItem],
+        max_messages: usize,
+    ) -> NeutronResult<bool> {
+        let messages = self.decode_messages(max_messages);
+        for filter in filters {
+            if !SUPPORTED_FILTER_FIELDS.contains(&filter.field.as_str()) {
+                return Err(NeutronError::UnsupportedTransactionFilterField {
+                    field: filter.field.clone(),
+                });
+            }
+
+            let matched = if filter.field == HEIGHT_FIELD {
+                filter_matches(filter, &self.height.revision_height.to_string())
+            } else {
+                messages
+                    .iter()
+                    .flat_map(|msg| msg.event_attributes())
+                    .any(|(field, value)| field == filter.field && filter_matches(filter, &value))
+            };
+
+            if !matched {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Decodes the first **max_messages** messages of the transaction to known types.
+    /// Messages of known types which fail to decode are returned as [`TxMsg::Undecodable`],
+    /// so one malformed message doesn't make the whole transaction unusable.
+    pub fn decode_messages(&self, max_messages: usize) -> Vec<TxMsg> {
+        self.messages
+            .iter()
+            .take(max_messages)
+            .map(|msg| {
+                TxMsg::decode(msg.clone()).unwrap_or_else(|_| {
+                    TxMsg::Undecodable(ProtobufAny::new(
+                        msg.type_url.clone(),
+                        Binary::new(msg.value.clone()),
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Decodes the auth info of the transaction and returns the fee paid for it
+    pub fn fee(&self) -> NeutronResult<Option<TxFee>> {
+        let auth_info = AuthInfo::decode(self.auth_info.as_slice())?;
+        auth_info
+            .fee
+            .map(|fee| {
+                Ok(TxFee {
+                    amount: try_proto_to_cosmwasm_coins(fee.amount)?,
+                    gas_limit: fee.gas_limit,
+                    payer: fee.payer,
+                    granter: fee.granter,
+                })
+            })
+            .transpose()
+    }
+}
+
+/// Checks whether an event attribute **value** satisfies the **filter**.
+/// String values can only be compared for equality, numeric values support all the operators.
+fn filter_matches(filter: &TransactionFilterItem, value: &str) -> bool {
+    match &filter.value {
+        TransactionFilterValue::String(expected) => {
+            filter.op == TransactionFilterOp::Eq && value == expected
+        }
+        TransactionFilterValue::Int(expected) => match value.parse::<u64>() {
+            Ok(actual) => match filter.op {
+                TransactionFilterOp::Eq => actual == *expected,
+                TransactionFilterOp::Lt => actual < *expected,
+                TransactionFilterOp::Gt => actual > *expected,
+                TransactionFilterOp::Lte => actual <= *expected,
+                TransactionFilterOp::Gte => actual >= *expected,
+            },
+            Err(_) => false,
+        },
+    }
+}
+
+/// Decodes **data** of a `SudoMsg::TxQueryResult` callback into a transaction
+///
+/// * **height** is the remote chain height the transaction was included at;
+/// * **data** is the raw transaction (`TxRaw`) submitted by a relayer.
+pub fn decode_tx_query_result(height: Height, data: &Binary) -> NeutronResult<DecodedTx> {
+    let tx: TxRaw = TxRaw::decode(data.as_slice())?;
+    let body: TxBody = TxBody::decode(tx.body_bytes.as_slice())?;
+
+    Ok(DecodedTx {
+        height,
+        messages: body.messages,
+        memo: body.memo,
+        timeout_height: body.timeout_height,
+        auth_info: Binary::new(tx.auth_info_bytes),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interchain_queries::v045::types::RECIPIENT_FIELD;
+    use crate::proto_types::cosmos::base::v1beta1::Coin as ProtoCoin;
+
+    const MAX_DECODED_MESSAGES: usize = 20;
+
+    // a sending of 10000 stake from neutron10h9stc5v6ntgeygf5xf945njqq5h32r54rf7kf
+    // to neutron1fj6yqrkpw6fmp7f7jhj57dujfpwal4m25dafzx
+    const BANK_SEND_TX: &str = "CpMBCpABChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEnAKLm5ldXRyb24xMGg5c3RjNXY2bnRnZXlnZjV4Zjk0NW5qcXE1aDMycjU0cmY3a2YSLm5ldXRyb24xZmo2eXFya3B3NmZtcDdmN2poajU3ZHVqZnB3YWw0bTI1ZGFmengaDgoFc3Rha2USBTEwMDAwEmcKUApGCh8vY29zbW9zLmNyeXB0by5zZWNwMjU2azEuUHViS2V5EiMKIQJPYibh+Zef13ZkulPqI27rV5xswZ0H/vh1Tnymp1RHPhIECgIIARgAEhMKDQoFc3Rha2USBDEwMDAQwJoMGkAIiXNJXmA57KhyaWpKcLLr3602A5+hlvv/b4PgcDDm9y0qikC+biNZXin1dEMpHOvX9DwOWJ9utv6EKljiSyfT";
+
+    fn decoded_bank_send_tx() -> DecodedTx {
+        decode_tx_query_result(
+            Height {
+                revision_number: 0,
+                revision_height: 100,
+            },
+            &Binary::from_base64(BANK_SEND_TX).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn filter(
+        field: &str,
+        op: TransactionFilterOp,
+        value: TransactionFilterValue,
+    ) -> TransactionFilterItem {
+        TransactionFilterItem {
+            field: field.to_string(),
+            op,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_decode_tx_query_result() {
+        let tx = decoded_bank_send_tx();
+
+        assert_eq!(
+            tx.height,
+            Height {
+                revision_number: 0,
+                revision_height: 100,
+            }
+        );
+        assert_eq!(tx.memo, "");
+        assert_eq!(tx.timeout_height, 0);
+        assert_eq!(tx.messages.len(), 1);
+        assert_eq!(
+            tx.decode_messages(MAX_DECODED_MESSAGES),
+            vec![TxMsg::BankSend(MsgSend {
+                from_address: "neutron10h9stc5v6ntgeygf5xf945njqq5h32r54rf7kf".to_string(),
+                to_address: "neutron1fj6yqrkpw6fmp7f7jhj57dujfpwal4m25dafzx".to_string(),
+                amount: vec![ProtoCoin {
+                    denom: "stake".to_string(),
+                    amount: "10000".to_string(),
+                }],
+            })]
+        );
+        assert_eq!(
+            tx.fee().unwrap(),
+            Some(TxFee {
+                amount: vec![Coin::new(1000u128, "stake")],
+                gas_limit: 200000,
+                payer: "".to_string(),
+                granter: "".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_messages_keeps_malformed() {
+        let mut tx = decoded_bank_send_tx();
+        let send = tx.messages[0].clone();
+        tx.messages = vec![
+            Any {
+                type_url: MsgSend::TYPE_URL.to_string(),
+                value: vec![0xff, 0xff],
+            },
+            send.clone(),
+            send,
+        ];
+        // a bad auth info doesn't affect the messages
+        tx.auth_info = Binary::new(vec![0xff, 0xff]);
+
+        let messages = tx.decode_messages(MAX_DECODED_MESSAGES);
+        assert_eq!(messages.len(), 3);
+        assert_eq!(
+            messages[0],
+            TxMsg::Undecodable(ProtobufAny::new(
+                MsgSend::TYPE_URL.to_string(),
+                Binary::new(vec![0xff, 0xff]),
+            ))
+        );
+        assert_eq!(messages[0].type_url(), MsgSend::TYPE_URL);
+        assert!(matches!(messages[1], TxMsg::BankSend(_)));
+        // only the first messages are decoded
+        assert_eq!(tx.decode_messages(2).len(), 2);
+        assert!(tx.fee().is_err());
+    }
+
+    #[test]
+    fn test_decode_unknown_msg() {
+        let msg = Any {
+            type_url: "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn".to_string(),
+            value: vec![1, 2, 3],
+        };
+
+        let decoded = TxMsg::decode(msg).unwrap();
+        assert_eq!(
+            decoded,
+            TxMsg::Unknown(ProtobufAny::new(
+                "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn".to_string(),
+                Binary::new(vec![1, 2, 3])
+            ))
+        );
+        assert_eq!(
+            decoded.type_url(),
+            "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn"
+        );
+        assert_eq!(
+            decoded.event_attributes(),
+            vec![(
+                "message.action",
+                "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_decode_malformed_msg() {
+        let msg = Any {
+            type_url: MsgSend::TYPE_URL.to_string(),
+            value: vec![0xff, 0xff],
+        };
+
+        assert!(matches!(
+            TxMsg::decode(msg),
+            Err(NeutronError::ProstProtobuf(_))
+        ));
+    }
+
+    #[test]
+    fn test_matches_filters() {
+        struct TestCase {
+            filters: Vec<TransactionFilterItem>,
+            expected_result: NeutronResult<bool>,
+        }
+
+        let test_cases: Vec<TestCase> = vec![
+            TestCase {
+                filters: vec![],
+                expected_result: Ok(true),
+            },
+            TestCase {
+                filters: vec![filter(
+                    RECIPIENT_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String(
+                        "neutron1fj6yqrkpw6fmp7f7jhj57dujfpwal4m25dafzx".to_string(),
+                    ),
+                )],
+                expected_result: Ok(true),
+            },
+            TestCase {
+                filters: vec![filter(
+                    RECIPIENT_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String(
+                        "neutron14uxvu22lhrazyxadaqv5d6lswu0p276ll7hrkl".to_string(),
+                    ),
+                )],
+                expected_result: Ok(false),
+            },
+            TestCase {
+                filters: vec![
+                    filter(
+                        RECIPIENT_FIELD,
+                        TransactionFilterOp::Eq,
+                        TransactionFilterValue::String(
+                            "neutron1fj6yqrkpw6fmp7f7jhj57dujfpwal4m25dafzx".to_string(),
+                        ),
+                    ),
+                    filter(
+                        HEIGHT_FIELD,
+                        TransactionFilterOp::Gte,
+                        TransactionFilterValue::Int(100),
+                    ),
+                    filter(
+                        "message.action",
+                        TransactionFilterOp::Eq,
+                        TransactionFilterValue::String(MsgSend::TYPE_URL.to_string()),
+                    ),
+                ],
+                expected_result: Ok(true),
+            },
+            TestCase {
+                filters: vec![filter(
+                    HEIGHT_FIELD,
+                    TransactionFilterOp::Gt,
+                    TransactionFilterValue::Int(100),
+                )],
+                expected_result: Ok(false),
+            },
+            TestCase {
+                // strings can only be compared for equality
+                filters: vec![filter(
+                    "transfer.sender",
+                    TransactionFilterOp::Lte,
+                    TransactionFilterValue::String(
+                        "neutron10h9stc5v6ntgeygf5xf945njqq5h32r54rf7kf".to_string(),
+                    ),
+                )],
+                expected_result: Ok(false),
+            },
+            TestCase {
+                // the transaction has no delegations at all
+                filters: vec![filter(
+                    "delegate.validator",
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String("neutronvaloper1".to_string()),
+                )],
+                expected_result: Ok(false),
+            },
+            TestCase {
+                filters: vec![filter(
+                    "coin_received.receiver",
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String(
+                        "neutron1fj6yqrkpw6fmp7f7jhj57dujfpwal4m25dafzx".to_string(),
+                    ),
+                )],
+                expected_result: Err(NeutronError::UnsupportedTransactionFilterField {
+                    field: "coin_received.receiver".to_string(),
+                }),
+            },
+        ];
+
+        let tx = decoded_bank_send_tx();
+        for ts in test_cases {
+            assert_eq!(
+                tx.matches_filters(&ts.filters, MAX_DECODED_MESSAGES),
+                ts.expected_result
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_numeric_attribute_filters() {
+        let tx = DecodedTx {
+            height: Height::default(),
+            messages: vec![MsgVote {
+                proposal_id: 42,
+                voter: "cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud".to_string(),
+                option: 1,
+                metadata: "".to_string(),
+            }
+            .to_any()],
+            memo: "".to_string(),
+            timeout_height: 0,
+            auth_info: Binary::default(),
+        };
+
+        let proposal_filter = |op, id| {
+            vec![filter(
+                "proposal_vote.proposal_id",
+                op,
+                TransactionFilterValue::Int(id),
+            )]
+        };
+
+        assert!(tx
+            .matches_filters(
+                &proposal_filter(TransactionFilterOp::Eq, 42),
+                MAX_DECODED_MESSAGES
+            )
+            .unwrap());
+        assert!(tx
+            .matches_filters(
+                &proposal_filter(TransactionFilterOp::Lt, 43),
+                MAX_DECODED_MESSAGES
+            )
+            .unwrap());
+        assert!(!tx
+            .matches_filters(
+                &proposal_filter(TransactionFilterOp::Lt, 42),
+                MAX_DECODED_MESSAGES
+            )
+            .unwrap());
+        assert!(tx
+            .matches_filters(
+                &proposal_filter(TransactionFilterOp::Lte, 42),
+                MAX_DECODED_MESSAGES
+            )
+            .unwrap());
+    }
+}
@@ -0,0 +1,381 @@
+use crate::{
+    bindings::msg::NeutronMsg,
+    errors::error::{NeutronError, NeutronResult},
+    interchain_queries::{
+        types::{
+            QueryPayload, TransactionFilterItem, TransactionFilterOp, TransactionFilterValue,
+            MAX_TX_FILTERS,
+        },
+        v045::types::HEIGHT_FIELD,
+    },
+};
+use cosmwasm_std::StdError;
+use serde_json_wasm::{from_str, to_string};
+
+/// Event attributes which values are always numeric, so they can't be compared with strings
+pub const NUMERIC_FILTER_FIELDS: &[&str] = &[HEIGHT_FIELD];
+
+/// Builds a list of transactions filters for a TX Interchain Query.
+///
+/// Each filter is an event attribute condition, e.g. `transfer.recipient = "cosmos1..."`
+/// or `tx.height >= 100`, and a transaction must satisfy all of them to be submitted to the contract.
+/// The filters are validated on [`TxFilterBuilder::build`], so invalid combinations are
+/// rejected before they reach the chain.
+///
+/// ```rust ignore
+/// let msg = TxFilterBuilder::new()
+///     .eq("ibc_transfer.sender", "cosmos1...")
+///     .height_range(Some(100), None)
+///     .register_msg(connection_id, update_period)?;
+/// ```
+#[derive(Debug, Default)]
+pub struct TxFilterBuilder {
+    filters: Vec<TransactionFilterItem>,
+}
+
+impl TxFilterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restores a builder from the **transactions_filter** of a registered query,
+    /// e.g. to extend the filters and update the query.
+    pub fn from_json(transactions_filter: &str) -> NeutronResult<Self> {
+        let filters: Vec<TransactionFilterItem> = from_str(transactions_filter)?;
+
+        Ok(Self { filters })
+    }
+
+    /// Adds a filter with an arbitrary **op** on the **field** event attribute
+    pub fn filter(
+        mut self,
+        field: impl Into<String>,
+        op: TransactionFilterOp,
+        value: impl Into<TransactionFilterValue>,
+    ) -> Self {
+        self.filters.push(TransactionFilterItem {
+            field: field.into(),
+            op,
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Adds a filter to match transactions with **field** event attribute equal to **value**
+    pub fn eq(self, field: impl Into<String>, value: impl Into<TransactionFilterValue>) -> Self {
+        self.filter(field, TransactionFilterOp::Eq, value)
+    }
+
+    /// Adds a filter to match transactions with **field** event attribute less than **value**
+    pub fn lt(self, field: impl Into<String>, value: u64) -> Self {
+        self.filter(field, TransactionFilterOp::Lt, value)
+    }
+
+    /// Adds a filter to match transactions with **field** event attribute greater than **value**
+    pub fn gt(self, field: impl Into<String>, value: u64) -> Self {
+        self.filter(field, TransactionFilterOp::Gt, value)
+    }
+
+    /// Adds a filter to match transactions with **field** event attribute less than or equal to **value**
+    pub fn lte(self, field: impl Into<String>, value: u64) -> Self {
+        self.filter(field, TransactionFilterOp::Lte, value)
+    }
+
+    /// Adds a filter to match transactions with **field** event attribute greater than or equal to **value**
+    pub fn gte(self, field: impl Into<String>, value: u64) -> Self {
+        self.filter(field, TransactionFilterOp::Gte, value)
+    }
+
+    /// Adds filters to match transactions included in blocks between **min_height** and
+    /// **max_height** (both inclusive) on remote chain.
+    pub fn height_range(mut self, min_height: Option<u64>, max_height: Option<u64>) -> Self {
+        if let Some(min_height) = min_height {
+            self = self.gte(HEIGHT_FIELD, min_height);
+        }
+        if let Some(max_height) = max_height {
+            self = self.lte(HEIGHT_FIELD, max_height);
+        }
+        self
+    }
+
+    /// Validates the filters and returns them
+    pub fn build(self) -> NeutronResult<Vec<TransactionFilterItem>> {
+        validate_transactions_filters(&self.filters)?;
+
+        Ok(self.filters)
+    }
+
+    /// Validates the filters and serializes them to the JSON expected by the **interchainqueries** module
+    pub fn build_json(self) -> NeutronResult<String> {
+        let filters = self.build()?;
+
+        to_string(&filters).map_err(|e| StdError::generic_err(e.to_string()).into())
+    }
+
+    /// Validates the filters and creates a message to register a TX Interchain Query with them
+    ///
+    /// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+    /// * **update_period** is used to say how often the query must be updated.
+    pub fn register_msg(
+        self,
+        connection_id: String,
+        update_period: u64,
+    ) -> NeutronResult<NeutronMsg> {
+        NeutronMsg::register_interchain_query(
+            QueryPayload::TX(self.build()?),
+            connection_id,
+            update_period,
+        )
+    }
+
+    /// Validates the filters and creates a message to replace filters of a registered TX Interchain Query
+    ///
+    /// * **query_id** is an identifier of the registered query;
+    /// * **new_update_period** is used to update period of how often the query must be updated.
+    pub fn update_msg(
+        self,
+        query_id: u64,
+        new_update_period: Option<u64>,
+    ) -> NeutronResult<NeutronMsg> {
+        NeutronMsg::update_interchain_query(query_id, None, new_update_period, Some(self.build()?))
+    }
+}
+
+/// Checks the transactions filters are accepted by the **interchainqueries** module and
+/// make sense together:
+/// * there are no more than [`MAX_TX_FILTERS`] filters;
+/// * every filter has a non-empty field;
+/// * strings are only compared for equality and numeric fields are only compared with numbers;
+/// * a field is matched for equality at most once;
+/// * numeric bounds of a field don't exclude each other.
+pub fn validate_transactions_filters(filters: &[TransactionFilterItem]) -> NeutronResult<()> {
+    if filters.len() > MAX_TX_FILTERS {
+        return Err(NeutronError::TooManyTransactionFilters {
+            max: MAX_TX_FILTERS,
+        });
+    }
+
+    for (i, filter) in filters.iter().enumerate() {
+        if filter.field.trim().is_empty() {
+            return Err(NeutronError::InvalidTransactionsFilter(
+                "filter field can't be empty".to_string(),
+            ));
+        }
+
+        if let TransactionFilterValue::String(_) = filter.value {
+            if NUMERIC_FILTER_FIELDS.contains(&filter.field.as_str()) {
+                return Err(NeutronError::InvalidTransactionsFilter(format!(
+                    "field {} is numeric and can't be compared with a string",
+                    filter.field
+                )));
+            }
+            if filter.op != TransactionFilterOp::Eq {
+                return Err(NeutronError::InvalidTransactionsFilter(format!(
+                    "string value of field {} can only be compared with Eq operator",
+                    filter.field
+                )));
+            }
+        }
+
+        if filter.op == TransactionFilterOp::Eq
+            && filters[..i]
+                .iter()
+                .any(|f| f.field == filter.field && f.op == TransactionFilterOp::Eq)
+        {
+            return Err(NeutronError::InvalidTransactionsFilter(format!(
+                "field {} is matched for equality more than once",
+                filter.field
+            )));
+        }
+    }
+
+    for filter in filters {
+        let (lower, upper) = numeric_bounds(filters, &filter.field);
+        if let (Some(lower), Some(upper)) = (lower, upper) {
+            if lower > upper {
+                return Err(NeutronError::InvalidTransactionsFilter(format!(
+                    "conditions on field {} can't be satisfied at the same time",
+                    filter.field
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the inclusive numeric range allowed by all the filters on the **field**.
+/// Unbounded sides are returned as **None**.
+fn numeric_bounds(filters: &[TransactionFilterItem], field: &str) -> (Option<u64>, Option<u64>) {
+    let mut lower: Option<u64> = None;
+    let mut upper: Option<u64> = None;
+
+    for filter in filters.iter().filter(|f| f.field == field) {
+        let value = match filter.value {
+            TransactionFilterValue::Int(value) => value,
+            TransactionFilterValue::String(_) => continue,
+        };
+
+        // an exclusive bound is converted to inclusive one, and a bound which can't be
+        // converted (e.g. `< 0`) makes the range empty
+        let (new_lower, new_upper) = match filter.op {
+            TransactionFilterOp::Eq => (Some(value), Some(value)),
+            TransactionFilterOp::Gte => (Some(value), None),
+            TransactionFilterOp::Lte => (None, Some(value)),
+            TransactionFilterOp::Gt => match value.checked_add(1) {
+                Some(v) => (Some(v), None),
+                None => return (Some(u64::MAX), Some(0)),
+            },
+            TransactionFilterOp::Lt => match value.checked_sub(1) {
+                Some(v) => (None, Some(v)),
+                None => return (Some(u64::MAX), Some(0)),
+            },
+        };
+
+        if let Some(new_lower) = new_lower {
+            lower = Some(lower.map_or(new_lower, |l| l.max(new_lower)));
+        }
+        if let Some(new_upper) = new_upper {
+            upper = Some(upper.map_or(new_upper, |u| u.min(new_upper)));
+        }
+    }
+
+    (lower, upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interchain_queries::v045::types::RECIPIENT_FIELD;
+
+    #[test]
+    fn test_build_json() {
+        let json = TxFilterBuilder::new()
+            .eq(
+                RECIPIENT_FIELD,
+                "cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud",
+            )
+            .eq("message.action", "/cosmos.bank.v1beta1.MsgSend")
+            .height_range(Some(100), Some(200))
+            .build_json()
+            .unwrap();
+
+        assert_eq!(
+            json,
+            r#"[{"field":"transfer.recipient","op":"Eq","value":"cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud"},{"field":"message.action","op":"Eq","value":"/cosmos.bank.v1beta1.MsgSend"},{"field":"tx.height","op":"Gte","value":100},{"field":"tx.height","op":"Lte","value":200}]"#
+        );
+    }
+
+    #[test]
+    fn test_from_json_round_trip() {
+        let transactions_filter = r#"[{"field":"wasm._contract_address","op":"Eq","value":"neutron1fj6yqrkpw6fmp7f7jhj57dujfpwal4m25dafzx"},{"field":"tx.height","op":"Gt","value":1000}]"#;
+
+        let builder = TxFilterBuilder::from_json(transactions_filter).unwrap();
+        assert_eq!(builder.build_json().unwrap(), transactions_filter);
+
+        let extended = TxFilterBuilder::from_json(transactions_filter)
+            .unwrap()
+            .lt(HEIGHT_FIELD, 2000)
+            .build()
+            .unwrap();
+        assert_eq!(extended.len(), 3);
+    }
+
+    #[test]
+    fn test_register_msg() {
+        let msg = TxFilterBuilder::new()
+            .eq(
+                "ibc_transfer.sender",
+                "neutron1fj6yqrkpw6fmp7f7jhj57dujfpwal4m25dafzx",
+            )
+            .register_msg("connection-0".to_string(), 10)
+            .unwrap();
+
+        assert_eq!(
+            msg,
+            NeutronMsg::RegisterInterchainQuery {
+                query_type: "tx".to_string(),
+                keys: vec![],
+                transactions_filter: r#"[{"field":"ibc_transfer.sender","op":"Eq","value":"neutron1fj6yqrkpw6fmp7f7jhj57dujfpwal4m25dafzx"}]"#.to_string(),
+                connection_id: "connection-0".to_string(),
+                update_period: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        struct TestCase {
+            builder: TxFilterBuilder,
+            expected_result: NeutronResult<()>,
+        }
+
+        let test_cases: Vec<TestCase> = vec![
+            TestCase {
+                builder: TxFilterBuilder::new()
+                    .eq(RECIPIENT_FIELD, "addr")
+                    .eq("message.sender", "addr")
+                    .gt(HEIGHT_FIELD, 10)
+                    .lt(HEIGHT_FIELD, 12),
+                expected_result: Ok(()),
+            },
+            TestCase {
+                builder: TxFilterBuilder::new()
+                    .eq(RECIPIENT_FIELD, "addr1")
+                    .eq(RECIPIENT_FIELD, "addr2"),
+                expected_result: Err(NeutronError::InvalidTransactionsFilter(
+                    "field transfer.recipient is matched for equality more than once".to_string(),
+                )),
+            },
+            TestCase {
+                builder: TxFilterBuilder::new().eq(HEIGHT_FIELD, "100"),
+                expected_result: Err(NeutronError::InvalidTransactionsFilter(
+                    "field tx.height is numeric and can't be compared with a string".to_string(),
+                )),
+            },
+            TestCase {
+                builder: TxFilterBuilder::new().filter(
+                    RECIPIENT_FIELD,
+                    TransactionFilterOp::Gte,
+                    "addr",
+                ),
+                expected_result: Err(NeutronError::InvalidTransactionsFilter(
+                    "string value of field transfer.recipient can only be compared with Eq operator"
+                        .to_string(),
+                )),
+            },
+            TestCase {
+                builder: TxFilterBuilder::new().eq(" ", "addr"),
+                expected_result: Err(NeutronError::InvalidTransactionsFilter(
+                    "filter field can't be empty".to_string(),
+                )),
+            },
+            TestCase {
+                builder: TxFilterBuilder::new().gt(HEIGHT_FIELD, 10).lt(HEIGHT_FIELD, 11),
+                expected_result: Err(NeutronError::InvalidTransactionsFilter(
+                    "conditions on field tx.height can't be satisfied at the same time"
+                        .to_string(),
+                )),
+            },
+            TestCase {
+                builder: TxFilterBuilder::new().lt(HEIGHT_FIELD, 0),
+                expected_result: Err(NeutronError::InvalidTransactionsFilter(
+                    "conditions on field tx.height can't be satisfied at the same time"
+                        .to_string(),
+                )),
+            },
+            TestCase {
+                builder: (0..=MAX_TX_FILTERS).fold(TxFilterBuilder::new(), |builder, i| {
+                    builder.eq(format!("message.field{}", i), "value")
+                }),
+                expected_result: Err(NeutronError::TooManyTransactionFilters {
+                    max: MAX_TX_FILTERS,
+                }),
+            },
+        ];
+
+        for ts in test_cases {
+            assert_eq!(ts.builder.build().map(|_| ()), ts.expected_result);
+        }
+    }
+}
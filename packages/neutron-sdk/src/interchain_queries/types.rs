@@ -59,6 +59,24 @@ impl Serialize for TransactionFilterValue {
     }
 }
 
+impl From<&str> for TransactionFilterValue {
+    fn from(value: &str) -> Self {
+        TransactionFilterValue::String(value.to_string())
+    }
+}
+
+impl From<String> for TransactionFilterValue {
+    fn from(value: String) -> Self {
+        TransactionFilterValue::String(value)
+    }
+}
+
+impl From<u64> for TransactionFilterValue {
+    fn from(value: u64) -> Self {
+        TransactionFilterValue::Int(value)
+    }
+}
+
 pub const MAX_TX_FILTERS: usize = 32;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -124,6 +142,19 @@ pub trait KVReconstruct: Sized {
     fn reconstruct(kvs: &[StorageValue]) -> NeutronResult<Self>;
 }
 
+/// A [KVReconstruct] type with a stable name, required to use the type with
+/// [crate::interchain_queries::registry::IcqRegistry].
+pub trait KVResultType: KVReconstruct {
+    /// Stable name of the reconstructed type, e.g. `v045/balances`. It's saved in the contract
+    /// storage by [crate::interchain_queries::registry::IcqRegistry], so it must not change
+    /// between contract versions and must be unique among the types a contract queries.
+    const RESULT_TYPE: &'static str;
+}
+
+impl KVResultType for Uint128 {
+    const RESULT_TYPE: &'static str = "uint128";
+}
+
 impl KVReconstruct for Uint128 {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Uint128> {
         let value = storage_values
@@ -1,9 +1,8 @@
-use crate::interchain_queries::types::{
-    QueryPayload, TransactionFilterItem, TransactionFilterOp, TransactionFilterValue,
-};
+use crate::interchain_queries::tx_filter::TxFilterBuilder;
+use crate::interchain_queries::types::QueryPayload;
 use crate::interchain_queries::v045::types::{
-    BANK_STORE_KEY, DISTRIBUTION_STORE_KEY, HEIGHT_FIELD, KEY_BOND_DENOM, PARAMS_STORE_KEY,
-    RECIPIENT_FIELD, SLASHING_STORE_KEY, STAKING_STORE_KEY, WASM_STORE_KEY,
+    BANK_STORE_KEY, DISTRIBUTION_STORE_KEY, KEY_BOND_DENOM, PARAMS_STORE_KEY, RECIPIENT_FIELD,
+    SLASHING_STORE_KEY, STAKING_STORE_KEY, WASM_STORE_KEY,
 };
 use crate::{
     bindings::{msg::NeutronMsg, types::KVKey},
@@ -333,22 +332,8 @@ pub fn new_register_transfers_query_msg(
     update_period: u64,
     min_height: Option<u64>,
 ) -> NeutronResult<NeutronMsg> {
-    let mut query_data = vec![TransactionFilterItem {
-        field: RECIPIENT_FIELD.to_string(),
-        op: TransactionFilterOp::Eq,
-        value: TransactionFilterValue::String(recipient),
-    }];
-    if let Some(min_height) = min_height {
-        query_data.push(TransactionFilterItem {
-            field: HEIGHT_FIELD.to_string(),
-            op: TransactionFilterOp::Gte,
-            value: TransactionFilterValue::Int(min_height),
-        })
-    }
-
-    NeutronMsg::register_interchain_query(
-        QueryPayload::TX(query_data),
-        connection_id,
-        update_period,
-    )
+    TxFilterBuilder::new()
+        .eq(RECIPIENT_FIELD, recipient)
+        .height_range(min_height, None)
+        .register_msg(connection_id, update_period)
 }
@@ -1,5 +1,5 @@
 use crate::interchain_queries::helpers::uint256_to_u128;
-use crate::interchain_queries::types::KVReconstruct;
+use crate::interchain_queries::types::{KVReconstruct, KVResultType};
 use crate::interchain_queries::v045::helpers::deconstruct_account_denom_balance_key;
 use crate::{
     bindings::types::StorageValue,
@@ -108,6 +108,10 @@ pub struct Balances {
     pub coins: Vec<Coin>,
 }
 
+impl KVResultType for Balances {
+    const RESULT_TYPE: &'static str = "v045/balances";
+}
+
 impl KVReconstruct for Balances {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Balances> {
         let mut coins: Vec<Coin> = Vec::with_capacity(storage_values.len());
@@ -135,6 +139,10 @@ pub struct TotalSupply {
     pub coins: Vec<Coin>,
 }
 
+impl KVResultType for TotalSupply {
+    const RESULT_TYPE: &'static str = "v045/total_supply";
+}
+
 impl KVReconstruct for TotalSupply {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<TotalSupply> {
         let mut coins: Vec<Coin> = Vec::with_capacity(storage_values.len());
@@ -157,6 +165,10 @@ pub struct FeePool {
     pub coins: Vec<Coin>,
 }
 
+impl KVResultType for FeePool {
+    const RESULT_TYPE: &'static str = "v045/fee_pool";
+}
+
 impl KVReconstruct for FeePool {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<FeePool> {
         let mut coins: Vec<Coin> = Vec::with_capacity(storage_values.len());
@@ -231,6 +243,10 @@ pub struct StakingValidator {
     pub validators: Vec<Validator>,
 }
 
+impl KVResultType for StakingValidator {
+    const RESULT_TYPE: &'static str = "v045/staking_validator";
+}
+
 impl KVReconstruct for StakingValidator {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<StakingValidator> {
         let mut validators = Vec::with_capacity(storage_values.len());
@@ -297,6 +313,10 @@ pub struct SigningInfo {
     pub signing_infos: Vec<ValidatorSigningInfo>,
 }
 
+impl KVResultType for SigningInfo {
+    const RESULT_TYPE: &'static str = "v045/signing_info";
+}
+
 impl KVReconstruct for SigningInfo {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<SigningInfo> {
         let mut signing_infos = Vec::with_capacity(storage_values.len());
@@ -351,6 +371,10 @@ pub struct GovernmentProposal {
     pub proposals: Vec<Proposal>,
 }
 
+impl KVResultType for GovernmentProposal {
+    const RESULT_TYPE: &'static str = "v045/government_proposal";
+}
+
 impl KVReconstruct for GovernmentProposal {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<GovernmentProposal> {
         let mut proposals = Vec::with_capacity(storage_values.len());
@@ -413,6 +437,10 @@ pub struct GovernmentProposalVotes {
     pub proposal_votes: Vec<ProposalVote>,
 }
 
+impl KVResultType for GovernmentProposalVotes {
+    const RESULT_TYPE: &'static str = "v045/government_proposal_votes";
+}
+
 impl KVReconstruct for GovernmentProposalVotes {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<GovernmentProposalVotes> {
         let mut proposal_votes = Vec::with_capacity(storage_values.len());
@@ -459,6 +487,10 @@ pub struct Delegations {
     pub delegations: Vec<StdDelegation>,
 }
 
+impl KVResultType for Delegations {
+    const RESULT_TYPE: &'static str = "v045/delegations";
+}
+
 impl KVReconstruct for Delegations {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Delegations> {
         // We are taking 2 items chunks from starage_value to calculate one delegation
@@ -565,6 +597,10 @@ pub struct UnbondingDelegations {
     pub unbonding_responses: Vec<UnbondingResponse>,
 }
 
+impl KVResultType for UnbondingDelegations {
+    const RESULT_TYPE: &'static str = "v045/unbonding_delegations";
+}
+
 impl KVReconstruct for UnbondingDelegations {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<UnbondingDelegations> {
         let mut unbonding_responses: Vec<UnbondingResponse> =
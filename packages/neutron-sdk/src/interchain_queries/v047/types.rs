@@ -6,7 +6,7 @@ pub use crate::interchain_queries::v045::types::*;
 // But at the same time we replace some structs from v045 with structs below to create structures
 // compatible with Cosmos SDK 0.47
 
-use crate::interchain_queries::types::KVReconstruct;
+use crate::interchain_queries::types::{KVReconstruct, KVResultType};
 use crate::{bindings::types::StorageValue, errors::error::NeutronResult, NeutronError};
 
 use crate::interchain_queries::helpers::uint256_to_u128;
@@ -31,6 +31,10 @@ pub struct Balances {
     pub coins: Vec<Coin>,
 }
 
+impl KVResultType for Balances {
+    const RESULT_TYPE: &'static str = "v047/balances";
+}
+
 impl KVReconstruct for Balances {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Balances> {
         let mut coins: Vec<Coin> = Vec::with_capacity(storage_values.len());
@@ -57,6 +61,10 @@ pub struct Delegations {
     pub delegations: Vec<StdDelegation>,
 }
 
+impl KVResultType for Delegations {
+    const RESULT_TYPE: &'static str = "v047/delegations";
+}
+
 impl KVReconstruct for Delegations {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Delegations> {
         // We are taking 2 items chunks from starage_value to calculate one delegation
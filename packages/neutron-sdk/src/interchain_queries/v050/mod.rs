@@ -0,0 +1,11 @@
+// import all unchanged helpers and methods from v045 and v047 packages
+// to make it available from v050 package (kinda proxy) since the store keys
+// of these modules remain byte-compatible in Cosmos SDK 0.50 collections
+pub use crate::interchain_queries::v045::helpers;
+pub use crate::interchain_queries::v047::register_queries;
+
+pub mod queries;
+pub mod types;
+
+#[cfg(test)]
+mod testing;
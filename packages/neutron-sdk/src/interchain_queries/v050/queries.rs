@@ -0,0 +1,75 @@
+// import all queries from v047 package
+// to make it available from v050 package (kinda proxy) since they work with Cosmos SDK 0.50 as usual
+pub use crate::interchain_queries::v047::queries::*;
+
+// But at the same time we redefine some methods from v047 with methods below to create methods
+// compatible with Cosmos SDK 0.50
+
+use crate::{
+    bindings::query::NeutronQuery,
+    interchain_queries::{
+        queries::{check_query_type, get_registered_query, query_kv_result},
+        types::QueryType,
+        v050::types::{GovernmentProposal, GovernmentProposalVotes},
+    },
+    NeutronResult,
+};
+use cosmwasm_std::{Deps, Env};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ProposalResponse {
+    pub proposals: GovernmentProposal,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ProposalVotesResponse {
+    pub votes: GovernmentProposalVotes,
+    pub last_submitted_local_height: u64,
+}
+
+/// Returns list of government proposals on the remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_government_proposals(
+    deps: Deps<NeutronQuery>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<ProposalResponse> {
+    let registered_query = get_registered_query(deps, registered_query_id)?;
+
+    check_query_type(registered_query.registered_query.query_type, QueryType::KV)?;
+
+    let proposals: GovernmentProposal = query_kv_result(deps, registered_query_id)?;
+
+    Ok(ProposalResponse {
+        last_submitted_local_height: registered_query
+            .registered_query
+            .last_submitted_result_local_height,
+        proposals,
+    })
+}
+
+/// Returns list of government proposal votes on the remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_government_proposal_votes(
+    deps: Deps<NeutronQuery>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<ProposalVotesResponse> {
+    let registered_query = get_registered_query(deps, registered_query_id)?;
+
+    check_query_type(registered_query.registered_query.query_type, QueryType::KV)?;
+
+    let votes: GovernmentProposalVotes = query_kv_result(deps, registered_query_id)?;
+
+    Ok(ProposalVotesResponse {
+        last_submitted_local_height: registered_query
+            .registered_query
+            .last_submitted_result_local_height,
+        votes,
+    })
+}
@@ -0,0 +1,706 @@
+use crate::bindings::types::{ProtobufAny, StorageValue};
+use crate::interchain_queries::helpers::decode_and_convert;
+use crate::interchain_queries::types::KVReconstruct;
+use crate::interchain_queries::v050::helpers::{
+    create_account_denom_balance_key, create_delegation_key, create_gov_proposal_key,
+    create_gov_proposal_voter_votes_key, create_gov_proposal_votes_key, create_total_denom_key,
+    create_validator_key,
+};
+use crate::interchain_queries::v050::types::{
+    Balances, Delegations, FeePool, GovernmentProposal, GovernmentProposalVotes, Proposal,
+    ProposalVote, SigningInfo, StakingValidator, StdDelegation, TallyResult, TotalSupply,
+    UnbondingDelegations, UnbondingEntry, UnbondingResponse, Validator as ContractValidator,
+    ValidatorSigningInfo, WeightedVoteOption, BALANCES_PREFIX, DELEGATION_KEY,
+    PROPOSALS_KEY_PREFIX, STAKING_PARAMS_KEY, STAKING_STORE_KEY, VALIDATORS_KEY, VOTES_KEY_PREFIX,
+};
+use crate::proto_types::cosmos::base::v1beta1::Coin;
+use crate::proto_types::cosmos::gov::v1::{
+    Proposal as CosmosProposal, TallyResult as CosmosTallyResult, Vote,
+    WeightedVoteOption as CosmosWeightedVoteOption,
+};
+use crate::proto_types::cosmos::staking::v1beta1::{
+    Delegation, Params, Validator as CosmosValidator,
+};
+use crate::shim::{Any, Timestamp};
+use crate::NeutronResult;
+use base64::prelude::*;
+use base64::Engine;
+use cosmwasm_std::{Addr, Binary, Coin as StdCoin, Decimal, Timestamp as StdTimestamp, Uint128};
+use hex;
+use prost::Message;
+use std::str::FromStr;
+
+// Values of the Cosmos SDK 0.50 stores in the store value encoding of the SDK:
+// bank balances and supply are stored as `math.Int` strings, everything else as gogoproto messages.
+// They are encoded from the 0.50 protos of `proto_types` rather than dumped from a running chain
+// with https://github.com/neutron-org/icq-compliance-officer like the v045 and v047 fixtures,
+// and should be replaced by such dumps once a 0.50 chain is wired into the compliance tooling.
+// Unlike the v047 fixtures they populate the fields which are new or changed since 0.47
+// (gov/v1 `title`, `summary`, `proposer`, `expedited`, `failed_reason`,
+// staking `unbonding_ids` and `unbonding_on_hold_ref_count`).
+pub const BALANCES_HEX_RESPONSE: &str = "31323530303030";
+pub const TOTAL_SUPPLY_HEX_RESPONSE: &str = "3136343431373139383839303537";
+// community pool of 1234567890.123456789012345678uatom
+pub const FEE_POOL_HEX_RESPONSE: &str =
+    "0a250a057561746f6d121c31323334353637383930313233343536373839303132333435363738";
+pub const VALIDATOR_SIGNING_INFO_HEX_RESPONSE: &str = "0a34636f736d6f7376616c636f6e7331657a3433796535716e3371327a77683875767377707076647563776e6b7136776a716338376410b00918db8902220608858fedb2063005";
+// unbonding delegation with two entries, the second one is partially slashed and on hold
+pub const DELEGATOR_UNBONDING_DELEGATIONS_HEX_RESPONSE: &str = "0a2d636f736d6f73316d396c33353878756e6868776473303536387a6134396d7a68767578783975787265357475641234636f736d6f7376616c6f7065723138686c356339786e35647a6532673530756177306c326d723032657735377a6b3061756b746e1a2708a051120c0885cbd6b30610f6abf18f021a0835303030303030302208353030303030303028071a28089b52120b0884d2d6b30610e59a89011a0833303030303030302208323937303030303028093001";
+// a failed expedited community pool spend proposal
+pub const GOV_PROPOSAL_HEX_RESPONSE: &str = "082a1294010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e6412740a2d636f736d6f73313064303779323635676d6d757674347a30773961773838306a6e73723730306a367a6e396b6e122d636f736d6f73316d396c33353878756e6868776473303536387a6134396d7a68767578783975787265357475641a140a057561746f6d120b3235303030303030303030180522370a0e3631373238333030303030303030120d313235303030303030303030301a0c393830303030303030303030220831353030303030302a0b0885ece7b20610959aef3a320b0885d6b1b30610959aef3a3a120a057561746f6d1209353030303030303030420b0885ece7b20610959aef3a4a0b08858fedb20610959aef3a520a697066733a2f2f4349445a14436f6d6d756e69747920706f6f6c207370656e64623646756e642074686520746f6f6c696e6720776f726b696e672067726f75702066726f6d2074686520636f6d6d756e69747920706f6f6c6a2d636f736d6f73316d396c33353878756e6868776473303536387a6134396d7a687675787839757872653574756470017a4d7370656e6461626c652062616c616e636520307561746f6d20697320736d616c6c6572207468616e2032353030303030303030307561746f6d3a20696e73756666696369656e742066756e6473";
+pub const STAKING_PARAMS_HEX_RESPONSE: &str =
+    "0a040880df6e10b401180720904e2a057561746f6d32113530303030303030303030303030303030";
+pub const STAKING_VALIDATOR_HEX_RESPONSE: &str = "0a34636f736d6f7376616c6f7065723138686c356339786e35647a6532673530756177306c326d723032657735377a6b3061756b746e12430a1d2f636f736d6f732e63727970746f2e656432353531392e5075624b657912220a200b30557a9fc4e90e33587da2c7ec11365b80a5caef14395e83a8cdf2173c618620032a0a31303030303030303030321c313030303030303030303030303030303030303030303030303030303a280a116e657574726f6e2d76616c696461746f721a1368747470733a2f2f6e657574726f6e2e6f72674a0052450a3b0a1231303030303030303030303030303030303012123230303030303030303030303030303030301a11313030303030303030303030303030303012060885ece7b2065a013160016a0107";
+pub const DELEGATOR_DELEGATIONS_HEX_RESPONSE: &str = "0a2d636f736d6f73316d396c33353878756e6868776473303536387a6134396d7a68767578783975787265357475641234636f736d6f7376616c6f7065723138686c356339786e35647a6532673530756177306c326d723032657735377a6b3061756b746e1a1b323530303030303030303030303030303030303030303030303030";
+
+// value of the only /cosmos.bank.v1beta1.MsgSend message of the GOV_PROPOSAL_HEX_RESPONSE proposal
+pub const GOV_PROPOSAL_MESSAGE_HEX: &str = "0a2d636f736d6f73313064303779323635676d6d757674347a30773961773838306a6e73723730306a367a6e396b6e122d636f736d6f73316d396c33353878756e6868776473303536387a6134396d7a68767578783975787265357475641a140a057561746f6d120b3235303030303030303030";
+// gov/v1 Vote with weighted options and metadata (fields absent in gov/v1beta1 votes)
+pub const GOV_PROPOSAL_VOTE_HEX_RESPONSE: &str = "0801122d636f736d6f73316d396c33353878756e6868776473303536387a6134396d7a6876757878397578726535747564221708011213313030303030303030303030303030303030302a0a697066733a2f2f434944";
+
+#[test]
+fn test_collections_keys_layout() {
+    let delegator = decode_and_convert("cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud").unwrap();
+    let validator =
+        decode_and_convert("cosmosvaloper18hl5c9xn5dze2g50uaw0l2mr02ew57zk0auktn").unwrap();
+
+    // bank balances: Pair[LengthPrefixed(AccAddress), String]
+    let mut expected_balance_key = vec![BALANCES_PREFIX, delegator.len() as u8];
+    expected_balance_key.extend_from_slice(&delegator);
+    expected_balance_key.extend_from_slice(b"uatom");
+    assert_eq!(
+        create_account_denom_balance_key(&delegator, "uatom").unwrap(),
+        expected_balance_key
+    );
+
+    // staking delegations: Pair[LengthPrefixed(AccAddress), LengthPrefixed(ValAddress)]
+    let mut expected_delegation_key = vec![DELEGATION_KEY, delegator.len() as u8];
+    expected_delegation_key.extend_from_slice(&delegator);
+    expected_delegation_key.push(validator.len() as u8);
+    expected_delegation_key.extend_from_slice(&validator);
+    assert_eq!(
+        create_delegation_key(&delegator, &validator).unwrap(),
+        expected_delegation_key
+    );
+
+    // staking validators: LengthPrefixed(ValAddress)
+    let mut expected_validator_key = vec![VALIDATORS_KEY, validator.len() as u8];
+    expected_validator_key.extend_from_slice(&validator);
+    assert_eq!(
+        create_validator_key(&validator).unwrap(),
+        expected_validator_key
+    );
+
+    // gov proposals: Uint64 (big endian)
+    assert_eq!(
+        create_gov_proposal_key(258).unwrap(),
+        vec![PROPOSALS_KEY_PREFIX, 0, 0, 0, 0, 0, 0, 1, 2]
+    );
+
+    // gov votes: Pair[Uint64, LengthPrefixed(AccAddress)]
+    let mut expected_vote_key = vec![VOTES_KEY_PREFIX, 0, 0, 0, 0, 0, 0, 0, 1];
+    expected_vote_key.push(delegator.len() as u8);
+    expected_vote_key.extend_from_slice(&delegator);
+    assert_eq!(
+        create_gov_proposal_voter_votes_key(1, &delegator).unwrap(),
+        expected_vote_key
+    );
+}
+
+#[test]
+fn test_government_proposals_reconstruct() {
+    struct TestCase {
+        proposals: Vec<CosmosProposal>,
+        expected_result: NeutronResult<GovernmentProposal>,
+    }
+
+    let test_cases: Vec<TestCase> = vec![
+        TestCase {
+            proposals: vec![CosmosProposal {
+                id: 1,
+                messages: vec![Any {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                    value: vec![1, 2, 3],
+                }],
+                status: 1,
+                total_deposit: vec![Coin {
+                    amount: "100000".to_string(),
+                    denom: "stake".to_string(),
+                }],
+                ..Default::default()
+            }],
+            expected_result: Ok(GovernmentProposal {
+                proposals: vec![Proposal {
+                    proposal_id: 1,
+                    messages: vec![ProtobufAny::new(
+                        "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                        Binary::new(vec![1, 2, 3]),
+                    )],
+                    total_deposit: vec![StdCoin::new(100000u128, "stake")],
+                    status: 1,
+                    submit_time: None,
+                    deposit_end_time: None,
+                    voting_start_time: None,
+                    voting_end_time: None,
+                    final_tally_result: None,
+                    metadata: String::default(),
+                    title: String::default(),
+                    summary: String::default(),
+                    proposer: String::default(),
+                    expedited: false,
+                    failed_reason: String::default(),
+                }],
+            }),
+        },
+        TestCase {
+            proposals: vec![CosmosProposal {
+                id: 2,
+                messages: vec![],
+                status: 4,
+                final_tally_result: Some(CosmosTallyResult {
+                    yes_count: "4".to_string(),
+                    abstain_count: "1".to_string(),
+                    no_count: "2".to_string(),
+                    no_with_veto_count: "3".to_string(),
+                }),
+                submit_time: Some(Timestamp {
+                    seconds: 2222222,
+                    nanos: 123123,
+                }),
+                deposit_end_time: Some(Timestamp {
+                    seconds: 3333333,
+                    nanos: 123123,
+                }),
+                total_deposit: vec![Coin {
+                    amount: "100000".to_string(),
+                    denom: "stake".to_string(),
+                }],
+                voting_start_time: Some(Timestamp {
+                    seconds: 4444444,
+                    nanos: 123123,
+                }),
+                voting_end_time: Some(Timestamp {
+                    seconds: 555555555,
+                    nanos: 123123,
+                }),
+                metadata: "ipfs://CID".to_string(),
+                title: "Upgrade".to_string(),
+                summary: "Upgrade the chain".to_string(),
+                proposer: "cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud".to_string(),
+                expedited: true,
+                failed_reason: "proposal execution failed".to_string(),
+            }],
+            expected_result: Ok(GovernmentProposal {
+                proposals: vec![Proposal {
+                    proposal_id: 2,
+                    messages: vec![],
+                    total_deposit: vec![StdCoin::new(100000u128, "stake")],
+                    status: 4,
+                    submit_time: Some(2222222),
+                    deposit_end_time: Some(3333333),
+                    voting_start_time: Some(4444444),
+                    voting_end_time: Some(555555555),
+                    final_tally_result: Some(TallyResult {
+                        yes: Uint128::from(4u128),
+                        no: Uint128::from(2u128),
+                        abstain: Uint128::from(1u128),
+                        no_with_veto: Uint128::from(3u128),
+                    }),
+                    metadata: "ipfs://CID".to_string(),
+                    title: "Upgrade".to_string(),
+                    summary: "Upgrade the chain".to_string(),
+                    proposer: "cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud".to_string(),
+                    expedited: true,
+                    failed_reason: "proposal execution failed".to_string(),
+                }],
+            }),
+        },
+        TestCase {
+            proposals: vec![],
+            expected_result: Ok(GovernmentProposal { proposals: vec![] }),
+        },
+    ];
+
+    for ts in test_cases {
+        let mut st_values: Vec<StorageValue> = vec![];
+
+        for proposal in &ts.proposals {
+            let proposal_key = create_gov_proposal_key(proposal.id).unwrap();
+            let s = StorageValue {
+                storage_prefix: "".to_string(),
+                key: Binary::new(proposal_key),
+                value: Binary::new(proposal.encode_to_vec()),
+            };
+            st_values.push(s);
+        }
+
+        let gov_proposal = GovernmentProposal::reconstruct(&st_values);
+
+        assert_eq!(gov_proposal, ts.expected_result)
+    }
+}
+
+#[test]
+fn test_proposal_votes_reconstruct() {
+    struct TestCase {
+        proposal_votes: Vec<Vote>,
+        expected_result: NeutronResult<GovernmentProposalVotes>,
+    }
+
+    let test_cases: Vec<TestCase> = vec![
+        TestCase {
+            proposal_votes: vec![
+                Vote {
+                    proposal_id: 1,
+                    voter: "cosmos1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                    options: vec![CosmosWeightedVoteOption {
+                        weight: "1000000000000000000".to_string(),
+                        option: 1,
+                    }],
+                    metadata: "".to_string(),
+                },
+                Vote {
+                    proposal_id: 2,
+                    voter: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                    options: vec![
+                        CosmosWeightedVoteOption {
+                            weight: "600000000000000000".to_string(),
+                            option: 1,
+                        },
+                        CosmosWeightedVoteOption {
+                            weight: "400000000000000000".to_string(),
+                            option: 3,
+                        },
+                    ],
+                    metadata: "split vote".to_string(),
+                },
+            ],
+            expected_result: Ok(GovernmentProposalVotes {
+                proposal_votes: vec![
+                    ProposalVote {
+                        proposal_id: 1,
+                        voter: "cosmos1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                        options: vec![WeightedVoteOption {
+                            weight: "1000000000000000000".to_string(),
+                            option: 1,
+                        }],
+                        metadata: "".to_string(),
+                    },
+                    ProposalVote {
+                        proposal_id: 2,
+                        voter: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                        options: vec![
+                            WeightedVoteOption {
+                                weight: "600000000000000000".to_string(),
+                                option: 1,
+                            },
+                            WeightedVoteOption {
+                                weight: "400000000000000000".to_string(),
+                                option: 3,
+                            },
+                        ],
+                        metadata: "split vote".to_string(),
+                    },
+                ],
+            }),
+        },
+        TestCase {
+            proposal_votes: vec![],
+            expected_result: Ok(GovernmentProposalVotes {
+                proposal_votes: vec![],
+            }),
+        },
+    ];
+
+    for ts in test_cases {
+        let mut st_values: Vec<StorageValue> = vec![];
+
+        for vote in &ts.proposal_votes {
+            let vote_key = create_gov_proposal_votes_key(vote.proposal_id).unwrap();
+            let s = StorageValue {
+                storage_prefix: "".to_string(),
+                key: Binary::new(vote_key),
+                value: Binary::new(vote.encode_to_vec()),
+            };
+            st_values.push(s);
+        }
+
+        let gov_proposals_votes = GovernmentProposalVotes::reconstruct(&st_values);
+
+        assert_eq!(gov_proposals_votes, ts.expected_result)
+    }
+}
+
+#[test]
+fn test_balance_reconstruct_from_hex() {
+    let bytes = hex::decode(BALANCES_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let base64_input = BASE64_STANDARD.encode(bytes); // encode bytes to base64 string
+
+    let s = StorageValue {
+        storage_prefix: String::default(), // not used in reconstruct
+        key: Binary::new(create_account_denom_balance_key("addr", "uatom").unwrap()),
+        value: Binary::from_base64(base64_input.as_str()).unwrap(),
+    };
+    let bank_balances = Balances::reconstruct(&[s]).unwrap();
+    assert_eq!(
+        bank_balances,
+        Balances {
+            coins: vec![StdCoin::new(1250000u128, "uatom")]
+        }
+    );
+}
+
+#[test]
+fn test_government_proposals_reconstruct_from_hex() {
+    let bytes = hex::decode(GOV_PROPOSAL_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let base64_input = BASE64_STANDARD.encode(bytes); // encode bytes to base64 string
+
+    let s = StorageValue {
+        storage_prefix: String::default(), // not used in reconstruct
+        key: Binary::default(),            // not used in reconstruct
+        value: Binary::from_base64(base64_input.as_str()).unwrap(),
+    };
+    let proposals = GovernmentProposal::reconstruct(&[s]).unwrap();
+    assert_eq!(
+        proposals,
+        GovernmentProposal {
+            proposals: vec![Proposal {
+                proposal_id: 42u64,
+                messages: vec![ProtobufAny::new(
+                    String::from("/cosmos.bank.v1beta1.MsgSend"),
+                    Binary::new(hex::decode(GOV_PROPOSAL_MESSAGE_HEX).unwrap()),
+                )],
+                total_deposit: vec![StdCoin {
+                    denom: String::from("uatom"),
+                    amount: Uint128::from(500000000u64),
+                }],
+                status: 5i32,
+                submit_time: Some(1717171717u64),
+                deposit_end_time: Some(1718381317u64),
+                voting_start_time: Some(1717171717u64),
+                voting_end_time: Some(1717258117u64),
+                final_tally_result: Some(TallyResult {
+                    yes: Uint128::from(61728300000000u128),
+                    no: Uint128::from(980000000000u128),
+                    abstain: Uint128::from(1250000000000u128),
+                    no_with_veto: Uint128::from(15000000u128),
+                }),
+                metadata: String::from("ipfs://CID"),
+                title: String::from("Community pool spend"),
+                summary: String::from("Fund the tooling working group from the community pool"),
+                proposer: String::from("cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud"),
+                expedited: true,
+                failed_reason: String::from(
+                    "spendable balance 0uatom is smaller than 25000000000uatom: insufficient funds"
+                ),
+            }]
+        }
+    );
+}
+
+#[test]
+fn test_proposal_votes_reconstruct_from_hex() {
+    let bytes = hex::decode(GOV_PROPOSAL_VOTE_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let base64_input = BASE64_STANDARD.encode(bytes); // encode bytes to base64 string
+
+    let s = StorageValue {
+        storage_prefix: String::default(), // not used in reconstruct
+        key: Binary::default(),            // not used in reconstruct
+        value: Binary::from_base64(base64_input.as_str()).unwrap(),
+    };
+    let votes = GovernmentProposalVotes::reconstruct(&[s]).unwrap();
+    assert_eq!(
+        votes,
+        GovernmentProposalVotes {
+            proposal_votes: vec![ProposalVote {
+                proposal_id: 1,
+                voter: String::from("cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud"),
+                options: vec![WeightedVoteOption {
+                    option: 1,
+                    weight: String::from("1000000000000000000"),
+                }],
+                metadata: String::from("ipfs://CID"),
+            }]
+        }
+    );
+}
+
+#[test]
+fn test_delegations_reconstruct_from_hex() {
+    let staking_params_bytes = hex::decode(STAKING_PARAMS_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let staking_params_base64_input = BASE64_STANDARD.encode(staking_params_bytes); // encode bytes to base64 string
+    let staking_validator_bytes = hex::decode(STAKING_VALIDATOR_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let staking_validator_base64_input = BASE64_STANDARD.encode(staking_validator_bytes); // encode bytes to base64 string
+    let delegation_bytes = hex::decode(DELEGATOR_DELEGATIONS_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let delegation_base64_input = BASE64_STANDARD.encode(delegation_bytes); // encode bytes to base64 string
+
+    let st_values: Vec<StorageValue> = vec![
+        StorageValue {
+            storage_prefix: String::default(), // not used in reconstruct
+            key: Binary::default(),            // not used in reconstruct
+            value: Binary::from_base64(staking_params_base64_input.as_str()).unwrap(),
+        },
+        StorageValue {
+            storage_prefix: String::default(), // not used in reconstruct
+            key: Binary::default(),            // not used in reconstruct
+            value: Binary::from_base64(delegation_base64_input.as_str()).unwrap(),
+        },
+        StorageValue {
+            storage_prefix: String::default(), // not used in reconstruct
+            key: Binary::default(),            // not used in reconstruct
+            value: Binary::from_base64(staking_validator_base64_input.as_str()).unwrap(),
+        },
+    ];
+
+    let delegations = Delegations::reconstruct(&st_values).unwrap();
+    assert_eq!(
+        delegations,
+        Delegations {
+            delegations: vec![StdDelegation {
+                delegator: Addr::unchecked("cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud"),
+                validator: String::from("cosmosvaloper18hl5c9xn5dze2g50uaw0l2mr02ew57zk0auktn"),
+                amount: StdCoin {
+                    denom: String::from("uatom"),
+                    amount: Uint128::from(250000000u64),
+                },
+            }],
+        }
+    );
+}
+
+#[test]
+fn test_balance_reconstruct_from_empty_value() {
+    let s = StorageValue {
+        storage_prefix: String::default(), // not used in reconstruct
+        key: Binary::new(create_account_denom_balance_key("addr", "uatom").unwrap()),
+        value: Binary::default(),
+    };
+    let bank_balances = Balances::reconstruct(&[s]).unwrap();
+    assert_eq!(
+        bank_balances,
+        Balances {
+            coins: vec![StdCoin::new(0u128, "uatom")]
+        }
+    );
+}
+
+#[test]
+fn test_bank_total_supply_reconstruct_from_hex() {
+    let bytes = hex::decode(TOTAL_SUPPLY_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let base64_input = BASE64_STANDARD.encode(bytes); // encode bytes to base64 string
+
+    let s = StorageValue {
+        storage_prefix: String::default(), // not used in reconstruct
+        key: Binary::new(create_total_denom_key("uatom").unwrap()),
+        value: Binary::from_base64(base64_input.as_str()).unwrap(),
+    };
+    let total_supply = TotalSupply::reconstruct(&[s]).unwrap();
+    assert_eq!(
+        total_supply,
+        TotalSupply {
+            coins: vec![StdCoin::new(16441719889057u128, "uatom")]
+        }
+    );
+}
+
+#[test]
+fn test_staking_validators_reconstruct_from_hex() {
+    let bytes = hex::decode(STAKING_VALIDATOR_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+
+    // make sure the fixture carries the unbonding fields added to the validator in 0.47+
+    let cosmos_validator = CosmosValidator::decode(bytes.as_slice()).unwrap();
+    assert_eq!(cosmos_validator.unbonding_on_hold_ref_count, 1);
+    assert_eq!(cosmos_validator.unbonding_ids, vec![7]);
+
+    let base64_input = BASE64_STANDARD.encode(bytes); // encode bytes to base64 string
+    let s = StorageValue {
+        storage_prefix: String::default(), // not used in reconstruct
+        key: Binary::default(),            // not used in reconstruct
+        value: Binary::from_base64(base64_input.as_str()).unwrap(),
+    };
+    let staking_validator = StakingValidator::reconstruct(&[s]).unwrap();
+    assert_eq!(
+        staking_validator,
+        StakingValidator {
+            validators: vec![ContractValidator {
+                operator_address: String::from(
+                    "cosmosvaloper18hl5c9xn5dze2g50uaw0l2mr02ew57zk0auktn"
+                ),
+                consensus_pubkey: Some(vec![
+                    10, 32, 11, 48, 85, 122, 159, 196, 233, 14, 51, 88, 125, 162, 199, 236, 17, 54,
+                    91, 128, 165, 202, 239, 20, 57, 94, 131, 168, 205, 242, 23, 60, 97, 134,
+                ]),
+                jailed: false,
+                status: 3,
+                tokens: String::from("1000000000"),
+                delegator_shares: String::from("1000000000000000000000000000"),
+                moniker: Some(String::from("neutron-validator")),
+                identity: Some(String::from("")),
+                website: Some(String::from("https://neutron.org")),
+                security_contact: Some(String::from("")),
+                details: Some(String::from("")),
+                unbonding_height: 0u64,
+                unbonding_time: Some(0u64),
+                rate: Some(Decimal::from_str("0.1").unwrap()),
+                max_rate: Some(Decimal::from_str("0.2").unwrap()),
+                max_change_rate: Some(Decimal::from_str("0.01").unwrap()),
+                update_time: Some(1717171717u64),
+                min_self_delegation: Decimal::one(),
+            }]
+        }
+    );
+}
+
+#[test]
+fn test_validators_signing_infos_reconstruct_from_hex() {
+    let bytes = hex::decode(VALIDATOR_SIGNING_INFO_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let base64_input = BASE64_STANDARD.encode(bytes); // encode bytes to base64 string
+
+    let s = StorageValue {
+        storage_prefix: String::default(), // not used in reconstruct
+        key: Binary::default(),            // not used in reconstruct
+        value: Binary::from_base64(base64_input.as_str()).unwrap(),
+    };
+    let signing_info = SigningInfo::reconstruct(&[s]).unwrap();
+    assert_eq!(
+        signing_info,
+        SigningInfo {
+            signing_infos: vec![ValidatorSigningInfo {
+                address: "cosmosvalcons1ez43ye5qn3q2zwh8uvswppvducwnkq6wjqc87d".to_string(),
+                start_height: 1200,
+                index_offset: 34011,
+                jailed_until: Some(1717258117),
+                tombstoned: false,
+                missed_blocks_counter: 5,
+            }]
+        }
+    );
+}
+
+#[test]
+fn test_fee_pool_reconstruct_from_hex() {
+    let bytes = hex::decode(FEE_POOL_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let base64_input = BASE64_STANDARD.encode(bytes); // encode bytes to base64 string
+
+    let s = StorageValue {
+        storage_prefix: String::default(), // not used in reconstruct
+        key: Binary::default(),            // not used in reconstruct
+        value: Binary::from_base64(base64_input.as_str()).unwrap(),
+    };
+    let fee_pool = FeePool::reconstruct(&[s]).unwrap();
+    assert_eq!(
+        fee_pool,
+        FeePool {
+            // 1234567890.123456789012345678uatom rounded down
+            coins: vec![StdCoin::new(1234567890u128, "uatom")]
+        }
+    );
+}
+
+#[test]
+fn test_unbonding_delegations_reconstruct_from_hex() {
+    let unbonding_delegations_bytes =
+        hex::decode(DELEGATOR_UNBONDING_DELEGATIONS_HEX_RESPONSE).unwrap(); // decode hex string to bytes
+    let unbonding_delegations_base64_input = BASE64_STANDARD.encode(unbonding_delegations_bytes); // encode bytes to base64 string
+
+    let st_values: Vec<StorageValue> = vec![StorageValue {
+        storage_prefix: String::default(), // not used in reconstruct
+        key: Binary::default(),            // not used in reconstruct
+        value: Binary::from_base64(unbonding_delegations_base64_input.as_str()).unwrap(),
+    }];
+
+    let unbonding_delegations = UnbondingDelegations::reconstruct(&st_values).unwrap();
+    assert_eq!(
+        unbonding_delegations,
+        UnbondingDelegations {
+            unbonding_responses: vec![UnbondingResponse {
+                delegator_address: Addr::unchecked("cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud"),
+                validator_address: String::from(
+                    "cosmosvaloper18hl5c9xn5dze2g50uaw0l2mr02ew57zk0auktn"
+                ),
+                entries: vec![
+                    UnbondingEntry {
+                        balance: Uint128::new(50_000_000),
+                        completion_time: Some(StdTimestamp::from_nanos(1718986117570185206)),
+                        creation_height: 10400,
+                        initial_balance: Uint128::new(50_000_000),
+                    },
+                    UnbondingEntry {
+                        balance: Uint128::new(29_700_000),
+                        completion_time: Some(StdTimestamp::from_nanos(1718987012002248037)),
+                        creation_height: 10523,
+                        initial_balance: Uint128::new(30_000_000),
+                    },
+                ],
+            }]
+        }
+    );
+}
+
+#[test]
+fn test_delegations_reconstruct_overflow() {
+    struct TestCase {
+        staking_params: Params,
+        delegations: Vec<Delegation>,
+        validators: Vec<CosmosValidator>,
+        expected_result: NeutronResult<Delegations>,
+    }
+    let test_cases: Vec<TestCase> = vec![TestCase {
+        staking_params: Params {
+            bond_denom: "stake".to_string(),
+            ..Default::default()
+        },
+        delegations: vec![Delegation {
+            delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+            validator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
+            shares: "340282366920938463463".to_string(),
+        }],
+        validators: vec![CosmosValidator {
+            operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
+            tokens: "340282366920938463463".to_string(),
+            delegator_shares: "340282366920938463463".to_string(),
+            unbonding_ids: vec![1, 2],
+            ..Default::default()
+        }],
+        expected_result: Ok(Delegations {
+            delegations: vec![StdDelegation {
+                delegator: Addr::unchecked("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs"),
+                validator: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
+                amount: StdCoin::new(340282366920938463463u128, "stake"),
+            }],
+        }),
+    }];
+
+    for ts in &test_cases {
+        // prepare storage values
+        let mut st_values: Vec<StorageValue> = vec![StorageValue {
+            storage_prefix: STAKING_STORE_KEY.to_string(),
+            key: Binary::new(vec![STAKING_PARAMS_KEY]),
+            value: Binary::from(ts.staking_params.encode_to_vec()),
+        }];
+
+        for (i, d) in ts.delegations.iter().enumerate() {
+            let delegator_addr = decode_and_convert(&d.delegator_address).unwrap();
+            let val_addr = decode_and_convert(&d.validator_address).unwrap();
+
+            st_values.push(StorageValue {
+                storage_prefix: STAKING_STORE_KEY.to_string(),
+                key: Binary::new(create_delegation_key(&delegator_addr, &val_addr).unwrap()),
+                value: Binary::from(d.encode_to_vec()),
+            });
+
+            if let Some(v) = ts.validators.get(i) {
+                st_values.push(StorageValue {
+                    storage_prefix: STAKING_STORE_KEY.to_string(),
+                    key: Binary::new(create_validator_key(&val_addr).unwrap()),
+                    value: Binary::from(v.encode_to_vec()),
+                });
+            }
+        }
+
+        // test reconstruction
+        let delegations = Delegations::reconstruct(&st_values);
+
+        assert_eq!(delegations, ts.expected_result)
+    }
+}
@@ -0,0 +1,160 @@
+// import all types from v047 package
+// to make it available from v050 package (kinda proxy) since they work with Cosmos SDK 0.50 as usual
+pub use crate::interchain_queries::v047::types::*;
+
+// But at the same time we replace some structs from v047 with structs below to create structures
+// compatible with Cosmos SDK 0.50
+
+use crate::bindings::types::ProtobufAny;
+use crate::interchain_queries::types::{KVReconstruct, KVResultType};
+use crate::proto_types::cosmos::gov::v1::{Proposal as CosmosProposal, Vote};
+use crate::{bindings::types::StorageValue, errors::error::NeutronResult};
+use cosmwasm_std::{Binary, Coin, Uint128};
+use prost::Message;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// TallyResult defines a standard tally for a governance proposal.
+pub struct TallyResult {
+    pub yes: Uint128,
+    pub no: Uint128,
+    pub abstain: Uint128,
+    pub no_with_veto: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// Proposal defines the core field members of a **gov/v1** governance proposal.
+pub struct Proposal {
+    pub proposal_id: u64,
+    /// messages are the arbitrary messages to be executed if the proposal passes.
+    pub messages: Vec<ProtobufAny>,
+    pub total_deposit: Vec<Coin>,
+    pub status: i32,
+    pub submit_time: Option<u64>,
+    pub deposit_end_time: Option<u64>,
+    pub voting_start_time: Option<u64>,
+    pub voting_end_time: Option<u64>,
+    pub final_tally_result: Option<TallyResult>,
+    /// metadata is any arbitrary metadata attached to the proposal.
+    pub metadata: String,
+    pub title: String,
+    pub summary: String,
+    /// proposer is the address of the proposal submitter.
+    pub proposer: String,
+    /// expedited defines if the proposal is expedited.
+    pub expedited: bool,
+    /// failed_reason defines the reason why the proposal failed.
+    pub failed_reason: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Government Proposal Interchain Query**.
+/// Contains governance proposals from remote chain.
+pub struct GovernmentProposal {
+    pub proposals: Vec<Proposal>,
+}
+
+impl KVResultType for GovernmentProposal {
+    const RESULT_TYPE: &'static str = "v050/government_proposal";
+}
+
+impl KVReconstruct for GovernmentProposal {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<GovernmentProposal> {
+        let mut proposals = Vec::with_capacity(storage_values.len());
+
+        for kv in storage_values {
+            let proposal: CosmosProposal = CosmosProposal::decode(kv.value.as_slice())?;
+
+            let mut coins: Vec<Coin> = Vec::with_capacity(proposal.total_deposit.len());
+
+            for coin in proposal.total_deposit {
+                let amount = Uint128::from_str(coin.amount.as_str())?;
+                coins.push(Coin::new(amount.u128(), coin.denom));
+            }
+
+            let final_tally_result = &proposal.final_tally_result;
+
+            let proposal = Proposal {
+                proposal_id: proposal.id,
+                messages: proposal
+                    .messages
+                    .into_iter()
+                    .map(|msg| ProtobufAny::new(msg.type_url, Binary::new(msg.value)))
+                    .collect(),
+                total_deposit: coins,
+                status: proposal.status,
+                submit_time: proposal.submit_time.map(|v| v.seconds as u64),
+                deposit_end_time: proposal.deposit_end_time.map(|v| v.seconds as u64),
+                voting_end_time: proposal.voting_end_time.map(|v| v.seconds as u64),
+                voting_start_time: proposal.voting_start_time.map(|v| v.seconds as u64),
+                final_tally_result: final_tally_result.as_ref().map(|v| TallyResult {
+                    abstain: Uint128::from_str(v.abstain_count.as_str()).unwrap_or(Uint128::zero()),
+                    no: Uint128::from_str(v.no_count.as_str()).unwrap_or(Uint128::zero()),
+                    no_with_veto: Uint128::from_str(v.no_with_veto_count.as_str())
+                        .unwrap_or(Uint128::zero()),
+                    yes: Uint128::from_str(v.yes_count.as_str()).unwrap_or(Uint128::zero()),
+                }),
+                metadata: proposal.metadata,
+                title: proposal.title,
+                summary: proposal.summary,
+                proposer: proposal.proposer,
+                expedited: proposal.expedited,
+                failed_reason: proposal.failed_reason,
+            };
+
+            proposals.push(proposal);
+        }
+
+        Ok(GovernmentProposal { proposals })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// Proposal vote defines the core field members of a **gov/v1** governance proposal votes.
+pub struct ProposalVote {
+    pub proposal_id: u64,
+    pub voter: String,
+    pub options: Vec<WeightedVoteOption>,
+    /// metadata is any arbitrary metadata attached to the vote.
+    pub metadata: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Government Proposal Votes Interchain Query**.
+pub struct GovernmentProposalVotes {
+    pub proposal_votes: Vec<ProposalVote>,
+}
+
+impl KVResultType for GovernmentProposalVotes {
+    const RESULT_TYPE: &'static str = "v050/government_proposal_votes";
+}
+
+impl KVReconstruct for GovernmentProposalVotes {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<GovernmentProposalVotes> {
+        let mut proposal_votes = Vec::with_capacity(storage_values.len());
+
+        for kv in storage_values {
+            let voter_vote: Vote = Vote::decode(kv.value.as_slice())?;
+
+            let vote = ProposalVote {
+                proposal_id: voter_vote.proposal_id,
+                voter: voter_vote.voter,
+                options: voter_vote
+                    .options
+                    .into_iter()
+                    .map(|v| WeightedVoteOption {
+                        option: v.option,
+                        weight: v.weight,
+                    })
+                    .collect(),
+                metadata: voter_vote.metadata,
+            };
+
+            proposal_votes.push(vote);
+        }
+
+        Ok(GovernmentProposalVotes { proposal_votes })
+    }
+}
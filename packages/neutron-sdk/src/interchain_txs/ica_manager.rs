@@ -0,0 +1,628 @@
+use crate::{
+    bindings::msg::NeutronMsg,
+    errors::error::{NeutronError, NeutronResult},
+    interchain_txs::helpers::get_port_id,
+    sudo::msg::RequestPacket,
+};
+use cosmwasm_std::{Coin, Env, Order, StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes the **counterparty_version** JSON received in **SudoMsg::OpenAck**
+pub struct OpenAckVersion {
+    pub version: String,
+    pub controller_connection_id: String,
+    pub host_connection_id: String,
+    /// **address** is an address of the interchain account on remote chain
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes lifecycle state of an interchain account
+pub enum IcaState {
+    /// **requested** means the account registration is sent, but the channel is not open yet
+    Requested,
+    /// **open** means the channel is open and the account can execute transactions
+    Open,
+    /// **closed** means the channel is closed and the account is not re-registered yet
+    Closed,
+    /// **reopening** means the channel was closed and the account re-registration is sent
+    Reopening,
+}
+
+impl std::fmt::Display for IcaState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IcaState::Requested => write!(f, "requested"),
+            IcaState::Open => write!(f, "open"),
+            IcaState::Closed => write!(f, "closed"),
+            IcaState::Reopening => write!(f, "reopening"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes an interchain account tracked by the [IcaManager]
+pub struct InterchainAccount {
+    pub interchain_account_id: String,
+    /// **connection_id** is an IBC connection identifier between Neutron and remote chain
+    pub connection_id: String,
+    pub state: IcaState,
+    /// **address** is an address of the account on remote chain, known after the first **OpenAck**
+    pub address: Option<String>,
+    /// **channel_id** is an identifier of the ICA channel on Neutron side
+    pub channel_id: Option<String>,
+    /// **counterparty_channel_id** is an identifier of the ICA channel on remote chain side
+    pub counterparty_channel_id: Option<String>,
+    /// **register_fee** is a fee paid for registration, reused when the account is re-registered
+    pub register_fee: Option<Vec<Coin>>,
+}
+
+/// Tracks lifecycle of interchain accounts of a contract.
+///
+/// ICA channels opened by Neutron are ORDERED, so a timed out packet closes the channel
+/// and the account can't be used until it is registered again on the same port.
+/// The manager follows **SudoMsg::OpenAck** and **SudoMsg::Timeout** messages and re-registers
+/// such accounts automatically, keeping the address of the account, since it doesn't change
+/// after re-registration.
+///
+/// ```rust ignore
+/// const ICA_MANAGER: IcaManager = IcaManager::new("interchain_accounts");
+///
+/// // execute
+/// let msg = ICA_MANAGER.register(deps.storage, connection_id, interchain_account_id, None)?;
+///
+/// // sudo
+/// SudoMsg::OpenAck { port_id, channel_id, counterparty_channel_id, counterparty_version } => {
+///     ICA_MANAGER.handle_open_ack(deps.storage, &env, &port_id, &channel_id, &counterparty_channel_id, &counterparty_version)?;
+/// }
+/// SudoMsg::Timeout { request } => {
+///     let reopen_msg = ICA_MANAGER.handle_timeout(deps.storage, &env, &request)?;
+/// }
+/// ```
+pub struct IcaManager {
+    accounts: Map<String, InterchainAccount>,
+}
+
+impl IcaManager {
+    pub const fn new(namespace: &'static str) -> Self {
+        IcaManager {
+            accounts: Map::new(namespace),
+        }
+    }
+
+    /// Creates a message to register an interchain account and starts tracking it
+    ///
+    /// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+    /// * **interchain_account_id** is an identifier of your new interchain account. Can be any string;
+    /// * **register_fee** is a fees required to be payed to register interchain account.
+    pub fn register(
+        &self,
+        storage: &mut dyn Storage,
+        connection_id: String,
+        interchain_account_id: String,
+        register_fee: Option<Vec<Coin>>,
+    ) -> NeutronResult<NeutronMsg> {
+        if self.accounts.has(storage, interchain_account_id.clone()) {
+            return Err(NeutronError::IcaAlreadyRegistered {
+                interchain_account_id,
+            });
+        }
+
+        self.accounts.save(
+            storage,
+            interchain_account_id.clone(),
+            &InterchainAccount {
+                interchain_account_id: interchain_account_id.clone(),
+                connection_id: connection_id.clone(),
+                state: IcaState::Requested,
+                address: None,
+                channel_id: None,
+                counterparty_channel_id: None,
+                register_fee: register_fee.clone(),
+            },
+        )?;
+
+        Ok(NeutronMsg::register_interchain_account(
+            connection_id,
+            interchain_account_id,
+            register_fee,
+        ))
+    }
+
+    /// Marks the account of the **port_id** as open and saves its address and channels.
+    /// Returns **None** if the port doesn't belong to an account tracked by the manager.
+    /// A reopened account must have the same address as before, otherwise an error is returned
+    /// and the account stays closed, so the contract never switches to another remote account.
+    pub fn handle_open_ack(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        port_id: &str,
+        channel_id: &str,
+        counterparty_channel_id: &str,
+        counterparty_version: &str,
+    ) -> NeutronResult<Option<InterchainAccount>> {
+        let mut account = match self.account_by_port(storage, env, port_id)? {
+            Some(account) => account,
+            None => return Ok(None),
+        };
+
+        let version: OpenAckVersion = serde_json_wasm::from_str(counterparty_version)
+            .map_err(|_| StdError::generic_err("Can't parse counterparty_version"))?;
+
+        if let Some(address) = &account.address {
+            if *address != version.address {
+                return Err(NeutronError::IcaAddressMismatch {
+                    interchain_account_id: account.interchain_account_id,
+                    expected: address.clone(),
+                    actual: version.address,
+                });
+            }
+        }
+
+        account.state = IcaState::Open;
+        account.address = Some(version.address);
+        account.channel_id = Some(channel_id.to_string());
+        account.counterparty_channel_id = Some(counterparty_channel_id.to_string());
+        self.accounts
+            .save(storage, account.interchain_account_id.clone(), &account)?;
+
+        Ok(Some(account))
+    }
+
+    /// Handles a timed out packet. If the packet was sent by an open account, its channel is closed,
+    /// so the account is marked as closed and a message to re-register it is returned.
+    pub fn handle_timeout(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        request: &RequestPacket,
+    ) -> NeutronResult<Option<NeutronMsg>> {
+        let port_id = match &request.source_port {
+            Some(port_id) => port_id,
+            None => return Ok(None),
+        };
+
+        match self.account_by_port(storage, env, port_id)? {
+            Some(account) if account.state == IcaState::Open => {
+                self.close(storage, &account.interchain_account_id)?;
+                self.reopen(storage, &account.interchain_account_id)
+                    .map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Marks the account as closed, e.g. when the contract found out that its channel is closed
+    pub fn close(
+        &self,
+        storage: &mut dyn Storage,
+        interchain_account_id: &str,
+    ) -> NeutronResult<()> {
+        let mut account = self.account(storage, interchain_account_id)?;
+        account.state = IcaState::Closed;
+        account.channel_id = None;
+        account.counterparty_channel_id = None;
+        self.accounts
+            .save(storage, interchain_account_id.to_string(), &account)?;
+
+        Ok(())
+    }
+
+    /// Creates a message to register again the account which channel is not open.
+    ///
+    /// Besides closed accounts, it retries registrations stuck in the **requested** or **reopening**
+    /// state, e.g. when the channel handshake has never completed and no **OpenAck** arrived.
+    pub fn reopen(
+        &self,
+        storage: &mut dyn Storage,
+        interchain_account_id: &str,
+    ) -> NeutronResult<NeutronMsg> {
+        let mut account = self.account(storage, interchain_account_id)?;
+        account.state = match account.state {
+            IcaState::Open => {
+                return Err(NeutronError::IcaNotReady {
+                    interchain_account_id: interchain_account_id.to_string(),
+                    state: account.state.to_string(),
+                })
+            }
+            // the account has never been open, so it's still a first registration
+            IcaState::Requested => IcaState::Requested,
+            IcaState::Closed | IcaState::Reopening => IcaState::Reopening,
+        };
+        self.accounts
+            .save(storage, interchain_account_id.to_string(), &account)?;
+
+        Ok(NeutronMsg::register_interchain_account(
+            account.connection_id,
+            account.interchain_account_id,
+            account.register_fee,
+        ))
+    }
+
+    /// Returns the tracked account
+    pub fn account(
+        &self,
+        storage: &dyn Storage,
+        interchain_account_id: &str,
+    ) -> NeutronResult<InterchainAccount> {
+        self.accounts
+            .may_load(storage, interchain_account_id.to_string())?
+            .ok_or_else(|| NeutronError::IcaNotRegistered {
+                interchain_account_id: interchain_account_id.to_string(),
+            })
+    }
+
+    /// Returns all the tracked accounts ordered by their identifiers
+    pub fn accounts(&self, storage: &dyn Storage) -> StdResult<Vec<InterchainAccount>> {
+        self.accounts
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, account)| account))
+            .collect()
+    }
+
+    /// Returns the address of the account on remote chain.
+    /// Fails with [NeutronError::IcaNotReady] if the account channel is not open.
+    pub fn address(
+        &self,
+        storage: &dyn Storage,
+        interchain_account_id: &str,
+    ) -> NeutronResult<String> {
+        self.open_account(storage, interchain_account_id)
+            .map(|account| account.address.unwrap_or_default())
+    }
+
+    /// Returns the address of the account on remote chain and connection id of the account.
+    /// Fails with [NeutronError::IcaNotReady] if the account channel is not open.
+    pub fn address_and_connection(
+        &self,
+        storage: &dyn Storage,
+        interchain_account_id: &str,
+    ) -> NeutronResult<(String, String)> {
+        self.open_account(storage, interchain_account_id)
+            .map(|account| (account.address.unwrap_or_default(), account.connection_id))
+    }
+
+    fn open_account(
+        &self,
+        storage: &dyn Storage,
+        interchain_account_id: &str,
+    ) -> NeutronResult<InterchainAccount> {
+        let account = self.account(storage, interchain_account_id)?;
+        if account.state != IcaState::Open {
+            return Err(NeutronError::IcaNotReady {
+                interchain_account_id: interchain_account_id.to_string(),
+                state: account.state.to_string(),
+            });
+        }
+
+        Ok(account)
+    }
+
+    fn account_by_port(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        port_id: &str,
+    ) -> StdResult<Option<InterchainAccount>> {
+        let contract_port_prefix = get_port_id(env.contract.address.as_str(), "");
+
+        match port_id.strip_prefix(&contract_port_prefix) {
+            Some(interchain_account_id) => self
+                .accounts
+                .may_load(storage, interchain_account_id.to_string()),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+
+    const ICA_MANAGER: IcaManager = IcaManager::new("interchain_accounts");
+    const ICA_ID: &str = "test";
+    const OPEN_ACK_VERSION: &str = r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-1","address":"cosmos1ica","encoding":"proto3","tx_type":"sdk_multi_msg"}"#;
+
+    fn open_account(storage: &mut dyn Storage, env: &Env) {
+        ICA_MANAGER
+            .register(
+                storage,
+                "connection-0".to_string(),
+                ICA_ID.to_string(),
+                None,
+            )
+            .unwrap();
+        open_ack(storage, env);
+    }
+
+    fn open_ack(storage: &mut dyn Storage, env: &Env) {
+        ICA_MANAGER
+            .handle_open_ack(
+                storage,
+                env,
+                &get_port_id(env.contract.address.as_str(), ICA_ID),
+                "channel-1",
+                "channel-2",
+                OPEN_ACK_VERSION,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_register_and_open() {
+        let mut storage = MockStorage::default();
+        let env = mock_env();
+
+        let msg = ICA_MANAGER
+            .register(
+                &mut storage,
+                "connection-0".to_string(),
+                ICA_ID.to_string(),
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            msg,
+            NeutronMsg::register_interchain_account(
+                "connection-0".to_string(),
+                ICA_ID.to_string(),
+                None
+            )
+        );
+        assert_eq!(
+            ICA_MANAGER.address(&storage, ICA_ID).unwrap_err(),
+            NeutronError::IcaNotReady {
+                interchain_account_id: ICA_ID.to_string(),
+                state: "requested".to_string(),
+            }
+        );
+        assert_eq!(
+            ICA_MANAGER
+                .register(
+                    &mut storage,
+                    "connection-0".to_string(),
+                    ICA_ID.to_string(),
+                    None
+                )
+                .unwrap_err(),
+            NeutronError::IcaAlreadyRegistered {
+                interchain_account_id: ICA_ID.to_string()
+            }
+        );
+
+        // open ack of a port which doesn't belong to the manager is ignored
+        assert_eq!(
+            ICA_MANAGER
+                .handle_open_ack(
+                    &mut storage,
+                    &env,
+                    "icacontroller-neutron1other.test",
+                    "channel-1",
+                    "channel-2",
+                    OPEN_ACK_VERSION,
+                )
+                .unwrap(),
+            None
+        );
+
+        open_ack(&mut storage, &env);
+        let account = ICA_MANAGER.account(&storage, ICA_ID).unwrap();
+        assert_eq!(account.state, IcaState::Open);
+        assert_eq!(account.channel_id, Some("channel-1".to_string()));
+        assert_eq!(
+            account.counterparty_channel_id,
+            Some("channel-2".to_string())
+        );
+        assert_eq!(
+            ICA_MANAGER
+                .address_and_connection(&storage, ICA_ID)
+                .unwrap(),
+            ("cosmos1ica".to_string(), "connection-0".to_string())
+        );
+
+        assert_eq!(
+            ICA_MANAGER.address(&storage, "unknown").unwrap_err(),
+            NeutronError::IcaNotRegistered {
+                interchain_account_id: "unknown".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_reopen_on_timeout() {
+        let mut storage = MockStorage::default();
+        let env = mock_env();
+        open_account(&mut storage, &env);
+
+        let mut request = RequestPacket {
+            sequence: Some(1),
+            source_port: Some("transfer".to_string()),
+            source_channel: Some("channel-0".to_string()),
+            destination_port: None,
+            destination_channel: None,
+            data: None,
+            timeout_height: None,
+            timeout_timestamp: None,
+        };
+
+        // timeout of a packet sent not by an interchain account is ignored
+        assert_eq!(
+            ICA_MANAGER
+                .handle_timeout(&mut storage, &env, &request)
+                .unwrap(),
+            None
+        );
+
+        request.source_port = Some(get_port_id(env.contract.address.as_str(), ICA_ID));
+        assert_eq!(
+            ICA_MANAGER
+                .handle_timeout(&mut storage, &env, &request)
+                .unwrap(),
+            Some(NeutronMsg::register_interchain_account(
+                "connection-0".to_string(),
+                ICA_ID.to_string(),
+                None
+            ))
+        );
+
+        let account = ICA_MANAGER.account(&storage, ICA_ID).unwrap();
+        assert_eq!(account.state, IcaState::Reopening);
+        assert_eq!(account.address, Some("cosmos1ica".to_string()));
+        assert_eq!(account.channel_id, None);
+        assert_eq!(
+            ICA_MANAGER.address(&storage, ICA_ID).unwrap_err(),
+            NeutronError::IcaNotReady {
+                interchain_account_id: ICA_ID.to_string(),
+                state: "reopening".to_string(),
+            }
+        );
+
+        // the account is being reopened already
+        assert_eq!(
+            ICA_MANAGER
+                .handle_timeout(&mut storage, &env, &request)
+                .unwrap(),
+            None
+        );
+
+        // the channel is reopened for another remote account
+        assert_eq!(
+            ICA_MANAGER
+                .handle_open_ack(
+                    &mut storage,
+                    &env,
+                    &get_port_id(env.contract.address.as_str(), ICA_ID),
+                    "channel-3",
+                    "channel-4",
+                    &OPEN_ACK_VERSION.replace("cosmos1ica", "cosmos1other"),
+                )
+                .unwrap_err(),
+            NeutronError::IcaAddressMismatch {
+                interchain_account_id: ICA_ID.to_string(),
+                expected: "cosmos1ica".to_string(),
+                actual: "cosmos1other".to_string(),
+            }
+        );
+        assert_eq!(
+            ICA_MANAGER.account(&storage, ICA_ID).unwrap().state,
+            IcaState::Reopening
+        );
+
+        open_ack(&mut storage, &env);
+        assert_eq!(
+            ICA_MANAGER.address(&storage, ICA_ID).unwrap(),
+            "cosmos1ica".to_string()
+        );
+    }
+
+    #[test]
+    fn test_close_and_reopen() {
+        let mut storage = MockStorage::default();
+        let env = mock_env();
+        open_account(&mut storage, &env);
+
+        assert_eq!(
+            ICA_MANAGER.reopen(&mut storage, ICA_ID).unwrap_err(),
+            NeutronError::IcaNotReady {
+                interchain_account_id: ICA_ID.to_string(),
+                state: "open".to_string(),
+            }
+        );
+
+        ICA_MANAGER.close(&mut storage, ICA_ID).unwrap();
+        assert_eq!(
+            ICA_MANAGER.account(&storage, ICA_ID).unwrap().state,
+            IcaState::Closed
+        );
+
+        ICA_MANAGER.reopen(&mut storage, ICA_ID).unwrap();
+        assert_eq!(ICA_MANAGER.accounts(&storage).unwrap().len(), 1);
+        assert_eq!(
+            ICA_MANAGER.account(&storage, ICA_ID).unwrap().state,
+            IcaState::Reopening
+        );
+    }
+
+    #[test]
+    fn test_retry_stuck_registration() {
+        let mut storage = MockStorage::default();
+        let env = mock_env();
+        let register_fee = Some(vec![Coin::new(1000u128, "untrn")]);
+        ICA_MANAGER
+            .register(
+                &mut storage,
+                "connection-0".to_string(),
+                ICA_ID.to_string(),
+                register_fee.clone(),
+            )
+            .unwrap();
+
+        // no OpenAck has arrived, the registration is sent again with the same fee
+        let expected_msg = NeutronMsg::register_interchain_account(
+            "connection-0".to_string(),
+            ICA_ID.to_string(),
+            register_fee,
+        );
+        assert_eq!(
+            ICA_MANAGER.reopen(&mut storage, ICA_ID).unwrap(),
+            expected_msg
+        );
+        assert_eq!(
+            ICA_MANAGER.account(&storage, ICA_ID).unwrap().state,
+            IcaState::Requested
+        );
+
+        open_ack(&mut storage, &env);
+        ICA_MANAGER.close(&mut storage, ICA_ID).unwrap();
+        ICA_MANAGER.reopen(&mut storage, ICA_ID).unwrap();
+
+        // the re-registration is stuck as well
+        assert_eq!(
+            ICA_MANAGER.reopen(&mut storage, ICA_ID).unwrap(),
+            expected_msg
+        );
+        assert_eq!(
+            ICA_MANAGER.account(&storage, ICA_ID).unwrap().state,
+            IcaState::Reopening
+        );
+
+        // the channel is reopened for another remote account
+        assert_eq!(
+            ICA_MANAGER
+                .handle_open_ack(
+                    &mut storage,
+                    &env,
+                    &get_port_id(env.contract.address.as_str(), ICA_ID),
+                    "channel-3",
+                    "channel-4",
+                    &OPEN_ACK_VERSION.replace("cosmos1ica", "cosmos1other"),
+                )
+                .unwrap_err(),
+            NeutronError::IcaAddressMismatch {
+                interchain_account_id: ICA_ID.to_string(),
+                expected: "cosmos1ica".to_string(),
+                actual: "cosmos1other".to_string(),
+            }
+        );
+        assert_eq!(
+            ICA_MANAGER.account(&storage, ICA_ID).unwrap().state,
+            IcaState::Reopening
+        );
+
+        open_ack(&mut storage, &env);
+        assert_eq!(
+            ICA_MANAGER.address(&storage, ICA_ID).unwrap(),
+            "cosmos1ica".to_string()
+        );
+    }
+}
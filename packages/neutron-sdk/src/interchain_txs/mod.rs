@@ -1,4 +1,7 @@
 pub mod helpers;
+pub mod ica_manager;
+pub mod responses;
+pub mod tx_builder;
 
 pub mod v045;
 pub mod v047;
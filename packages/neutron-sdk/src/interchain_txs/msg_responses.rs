@@ -0,0 +1,176 @@
+// @generated by proto-build from the `Msg*` and `Msg*Response` pairs of `proto_types`.
+// Do not edit it manually, run `make build-proto` instead.
+
+typed_msg_responses! {
+    CosmosAuthV1beta1UpdateParams(crate::proto_types::cosmos::auth::v1beta1::MsgUpdateParams => crate::proto_types::cosmos::auth::v1beta1::MsgUpdateParamsResponse),
+    CosmosAuthzV1beta1Exec(crate::proto_types::cosmos::authz::v1beta1::MsgExec => crate::proto_types::cosmos::authz::v1beta1::MsgExecResponse),
+    CosmosAuthzV1beta1Grant(crate::proto_types::cosmos::authz::v1beta1::MsgGrant => crate::proto_types::cosmos::authz::v1beta1::MsgGrantResponse),
+    CosmosAuthzV1beta1Revoke(crate::proto_types::cosmos::authz::v1beta1::MsgRevoke => crate::proto_types::cosmos::authz::v1beta1::MsgRevokeResponse),
+    CosmosBankV1beta1MultiSend(crate::proto_types::cosmos::bank::v1beta1::MsgMultiSend => crate::proto_types::cosmos::bank::v1beta1::MsgMultiSendResponse),
+    CosmosBankV1beta1Send(crate::proto_types::cosmos::bank::v1beta1::MsgSend => crate::proto_types::cosmos::bank::v1beta1::MsgSendResponse),
+    CosmosBankV1beta1SetSendEnabled(crate::proto_types::cosmos::bank::v1beta1::MsgSetSendEnabled => crate::proto_types::cosmos::bank::v1beta1::MsgSetSendEnabledResponse),
+    CosmosBankV1beta1UpdateParams(crate::proto_types::cosmos::bank::v1beta1::MsgUpdateParams => crate::proto_types::cosmos::bank::v1beta1::MsgUpdateParamsResponse),
+    CosmosCircuitV1AuthorizeCircuitBreaker(crate::proto_types::cosmos::circuit::v1::MsgAuthorizeCircuitBreaker => crate::proto_types::cosmos::circuit::v1::MsgAuthorizeCircuitBreakerResponse),
+    CosmosCircuitV1ResetCircuitBreaker(crate::proto_types::cosmos::circuit::v1::MsgResetCircuitBreaker => crate::proto_types::cosmos::circuit::v1::MsgResetCircuitBreakerResponse),
+    CosmosCircuitV1TripCircuitBreaker(crate::proto_types::cosmos::circuit::v1::MsgTripCircuitBreaker => crate::proto_types::cosmos::circuit::v1::MsgTripCircuitBreakerResponse),
+    CosmosConsensusV1UpdateParams(crate::proto_types::cosmos::consensus::v1::MsgUpdateParams => crate::proto_types::cosmos::consensus::v1::MsgUpdateParamsResponse),
+    CosmosCrisisV1beta1UpdateParams(crate::proto_types::cosmos::crisis::v1beta1::MsgUpdateParams => crate::proto_types::cosmos::crisis::v1beta1::MsgUpdateParamsResponse),
+    CosmosCrisisV1beta1VerifyInvariant(crate::proto_types::cosmos::crisis::v1beta1::MsgVerifyInvariant => crate::proto_types::cosmos::crisis::v1beta1::MsgVerifyInvariantResponse),
+    CosmosDistributionV1beta1CommunityPoolSpend(crate::proto_types::cosmos::distribution::v1beta1::MsgCommunityPoolSpend => crate::proto_types::cosmos::distribution::v1beta1::MsgCommunityPoolSpendResponse),
+    CosmosDistributionV1beta1DepositValidatorRewardsPool(crate::proto_types::cosmos::distribution::v1beta1::MsgDepositValidatorRewardsPool => crate::proto_types::cosmos::distribution::v1beta1::MsgDepositValidatorRewardsPoolResponse),
+    CosmosDistributionV1beta1FundCommunityPool(crate::proto_types::cosmos::distribution::v1beta1::MsgFundCommunityPool => crate::proto_types::cosmos::distribution::v1beta1::MsgFundCommunityPoolResponse),
+    CosmosDistributionV1beta1SetWithdrawAddress(crate::proto_types::cosmos::distribution::v1beta1::MsgSetWithdrawAddress => crate::proto_types::cosmos::distribution::v1beta1::MsgSetWithdrawAddressResponse),
+    CosmosDistributionV1beta1UpdateParams(crate::proto_types::cosmos::distribution::v1beta1::MsgUpdateParams => crate::proto_types::cosmos::distribution::v1beta1::MsgUpdateParamsResponse),
+    CosmosDistributionV1beta1WithdrawDelegatorReward(crate::proto_types::cosmos::distribution::v1beta1::MsgWithdrawDelegatorReward => crate::proto_types::cosmos::distribution::v1beta1::MsgWithdrawDelegatorRewardResponse),
+    CosmosDistributionV1beta1WithdrawValidatorCommission(crate::proto_types::cosmos::distribution::v1beta1::MsgWithdrawValidatorCommission => crate::proto_types::cosmos::distribution::v1beta1::MsgWithdrawValidatorCommissionResponse),
+    CosmosEvidenceV1beta1SubmitEvidence(crate::proto_types::cosmos::evidence::v1beta1::MsgSubmitEvidence => crate::proto_types::cosmos::evidence::v1beta1::MsgSubmitEvidenceResponse),
+    CosmosFeegrantV1beta1GrantAllowance(crate::proto_types::cosmos::feegrant::v1beta1::MsgGrantAllowance => crate::proto_types::cosmos::feegrant::v1beta1::MsgGrantAllowanceResponse),
+    CosmosFeegrantV1beta1PruneAllowances(crate::proto_types::cosmos::feegrant::v1beta1::MsgPruneAllowances => crate::proto_types::cosmos::feegrant::v1beta1::MsgPruneAllowancesResponse),
+    CosmosFeegrantV1beta1RevokeAllowance(crate::proto_types::cosmos::feegrant::v1beta1::MsgRevokeAllowance => crate::proto_types::cosmos::feegrant::v1beta1::MsgRevokeAllowanceResponse),
+    CosmosGovV1CancelProposal(crate::proto_types::cosmos::gov::v1::MsgCancelProposal => crate::proto_types::cosmos::gov::v1::MsgCancelProposalResponse),
+    CosmosGovV1Deposit(crate::proto_types::cosmos::gov::v1::MsgDeposit => crate::proto_types::cosmos::gov::v1::MsgDepositResponse),
+    CosmosGovV1ExecLegacyContent(crate::proto_types::cosmos::gov::v1::MsgExecLegacyContent => crate::proto_types::cosmos::gov::v1::MsgExecLegacyContentResponse),
+    CosmosGovV1SubmitProposal(crate::proto_types::cosmos::gov::v1::MsgSubmitProposal => crate::proto_types::cosmos::gov::v1::MsgSubmitProposalResponse),
+    CosmosGovV1UpdateParams(crate::proto_types::cosmos::gov::v1::MsgUpdateParams => crate::proto_types::cosmos::gov::v1::MsgUpdateParamsResponse),
+    CosmosGovV1Vote(crate::proto_types::cosmos::gov::v1::MsgVote => crate::proto_types::cosmos::gov::v1::MsgVoteResponse),
+    CosmosGovV1VoteWeighted(crate::proto_types::cosmos::gov::v1::MsgVoteWeighted => crate::proto_types::cosmos::gov::v1::MsgVoteWeightedResponse),
+    CosmosGovV1beta1Deposit(crate::proto_types::cosmos::gov::v1beta1::MsgDeposit => crate::proto_types::cosmos::gov::v1beta1::MsgDepositResponse),
+    CosmosGovV1beta1SubmitProposal(crate::proto_types::cosmos::gov::v1beta1::MsgSubmitProposal => crate::proto_types::cosmos::gov::v1beta1::MsgSubmitProposalResponse),
+    CosmosGovV1beta1Vote(crate::proto_types::cosmos::gov::v1beta1::MsgVote => crate::proto_types::cosmos::gov::v1beta1::MsgVoteResponse),
+    CosmosGovV1beta1VoteWeighted(crate::proto_types::cosmos::gov::v1beta1::MsgVoteWeighted => crate::proto_types::cosmos::gov::v1beta1::MsgVoteWeightedResponse),
+    CosmosGroupV1CreateGroup(crate::proto_types::cosmos::group::v1::MsgCreateGroup => crate::proto_types::cosmos::group::v1::MsgCreateGroupResponse),
+    CosmosGroupV1CreateGroupPolicy(crate::proto_types::cosmos::group::v1::MsgCreateGroupPolicy => crate::proto_types::cosmos::group::v1::MsgCreateGroupPolicyResponse),
+    CosmosGroupV1CreateGroupWithPolicy(crate::proto_types::cosmos::group::v1::MsgCreateGroupWithPolicy => crate::proto_types::cosmos::group::v1::MsgCreateGroupWithPolicyResponse),
+    CosmosGroupV1Exec(crate::proto_types::cosmos::group::v1::MsgExec => crate::proto_types::cosmos::group::v1::MsgExecResponse),
+    CosmosGroupV1LeaveGroup(crate::proto_types::cosmos::group::v1::MsgLeaveGroup => crate::proto_types::cosmos::group::v1::MsgLeaveGroupResponse),
+    CosmosGroupV1SubmitProposal(crate::proto_types::cosmos::group::v1::MsgSubmitProposal => crate::proto_types::cosmos::group::v1::MsgSubmitProposalResponse),
+    CosmosGroupV1UpdateGroupAdmin(crate::proto_types::cosmos::group::v1::MsgUpdateGroupAdmin => crate::proto_types::cosmos::group::v1::MsgUpdateGroupAdminResponse),
+    CosmosGroupV1UpdateGroupMembers(crate::proto_types::cosmos::group::v1::MsgUpdateGroupMembers => crate::proto_types::cosmos::group::v1::MsgUpdateGroupMembersResponse),
+    CosmosGroupV1UpdateGroupMetadata(crate::proto_types::cosmos::group::v1::MsgUpdateGroupMetadata => crate::proto_types::cosmos::group::v1::MsgUpdateGroupMetadataResponse),
+    CosmosGroupV1UpdateGroupPolicyAdmin(crate::proto_types::cosmos::group::v1::MsgUpdateGroupPolicyAdmin => crate::proto_types::cosmos::group::v1::MsgUpdateGroupPolicyAdminResponse),
+    CosmosGroupV1UpdateGroupPolicyDecisionPolicy(crate::proto_types::cosmos::group::v1::MsgUpdateGroupPolicyDecisionPolicy => crate::proto_types::cosmos::group::v1::MsgUpdateGroupPolicyDecisionPolicyResponse),
+    CosmosGroupV1UpdateGroupPolicyMetadata(crate::proto_types::cosmos::group::v1::MsgUpdateGroupPolicyMetadata => crate::proto_types::cosmos::group::v1::MsgUpdateGroupPolicyMetadataResponse),
+    CosmosGroupV1Vote(crate::proto_types::cosmos::group::v1::MsgVote => crate::proto_types::cosmos::group::v1::MsgVoteResponse),
+    CosmosGroupV1WithdrawProposal(crate::proto_types::cosmos::group::v1::MsgWithdrawProposal => crate::proto_types::cosmos::group::v1::MsgWithdrawProposalResponse),
+    CosmosMintV1beta1UpdateParams(crate::proto_types::cosmos::mint::v1beta1::MsgUpdateParams => crate::proto_types::cosmos::mint::v1beta1::MsgUpdateParamsResponse),
+    CosmosNftV1beta1Send(crate::proto_types::cosmos::nft::v1beta1::MsgSend => crate::proto_types::cosmos::nft::v1beta1::MsgSendResponse),
+    CosmosSlashingV1beta1Unjail(crate::proto_types::cosmos::slashing::v1beta1::MsgUnjail => crate::proto_types::cosmos::slashing::v1beta1::MsgUnjailResponse),
+    CosmosSlashingV1beta1UpdateParams(crate::proto_types::cosmos::slashing::v1beta1::MsgUpdateParams => crate::proto_types::cosmos::slashing::v1beta1::MsgUpdateParamsResponse),
+    CosmosStakingV1beta1BeginRedelegate(crate::proto_types::cosmos::staking::v1beta1::MsgBeginRedelegate => crate::proto_types::cosmos::staking::v1beta1::MsgBeginRedelegateResponse),
+    CosmosStakingV1beta1CancelUnbondingDelegation(crate::proto_types::cosmos::staking::v1beta1::MsgCancelUnbondingDelegation => crate::proto_types::cosmos::staking::v1beta1::MsgCancelUnbondingDelegationResponse),
+    CosmosStakingV1beta1CreateValidator(crate::proto_types::cosmos::staking::v1beta1::MsgCreateValidator => crate::proto_types::cosmos::staking::v1beta1::MsgCreateValidatorResponse),
+    CosmosStakingV1beta1Delegate(crate::proto_types::cosmos::staking::v1beta1::MsgDelegate => crate::proto_types::cosmos::staking::v1beta1::MsgDelegateResponse),
+    CosmosStakingV1beta1EditValidator(crate::proto_types::cosmos::staking::v1beta1::MsgEditValidator => crate::proto_types::cosmos::staking::v1beta1::MsgEditValidatorResponse),
+    CosmosStakingV1beta1Undelegate(crate::proto_types::cosmos::staking::v1beta1::MsgUndelegate => crate::proto_types::cosmos::staking::v1beta1::MsgUndelegateResponse),
+    CosmosStakingV1beta1UpdateParams(crate::proto_types::cosmos::staking::v1beta1::MsgUpdateParams => crate::proto_types::cosmos::staking::v1beta1::MsgUpdateParamsResponse),
+    CosmosUpgradeV1beta1CancelUpgrade(crate::proto_types::cosmos::upgrade::v1beta1::MsgCancelUpgrade => crate::proto_types::cosmos::upgrade::v1beta1::MsgCancelUpgradeResponse),
+    CosmosUpgradeV1beta1SoftwareUpgrade(crate::proto_types::cosmos::upgrade::v1beta1::MsgSoftwareUpgrade => crate::proto_types::cosmos::upgrade::v1beta1::MsgSoftwareUpgradeResponse),
+    CosmosVestingV1beta1CreatePeriodicVestingAccount(crate::proto_types::cosmos::vesting::v1beta1::MsgCreatePeriodicVestingAccount => crate::proto_types::cosmos::vesting::v1beta1::MsgCreatePeriodicVestingAccountResponse),
+    CosmosVestingV1beta1CreatePermanentLockedAccount(crate::proto_types::cosmos::vesting::v1beta1::MsgCreatePermanentLockedAccount => crate::proto_types::cosmos::vesting::v1beta1::MsgCreatePermanentLockedAccountResponse),
+    CosmosVestingV1beta1CreateVestingAccount(crate::proto_types::cosmos::vesting::v1beta1::MsgCreateVestingAccount => crate::proto_types::cosmos::vesting::v1beta1::MsgCreateVestingAccountResponse),
+    CosmwasmWasmV1AddCodeUploadParamsAddresses(crate::proto_types::cosmwasm::wasm::v1::MsgAddCodeUploadParamsAddresses => crate::proto_types::cosmwasm::wasm::v1::MsgAddCodeUploadParamsAddressesResponse),
+    CosmwasmWasmV1ClearAdmin(crate::proto_types::cosmwasm::wasm::v1::MsgClearAdmin => crate::proto_types::cosmwasm::wasm::v1::MsgClearAdminResponse),
+    CosmwasmWasmV1ExecuteContract(crate::proto_types::cosmwasm::wasm::v1::MsgExecuteContract => crate::proto_types::cosmwasm::wasm::v1::MsgExecuteContractResponse),
+    CosmwasmWasmV1IbcSend(crate::proto_types::cosmwasm::wasm::v1::MsgIbcSend => crate::proto_types::cosmwasm::wasm::v1::MsgIbcSendResponse),
+    CosmwasmWasmV1InstantiateContract(crate::proto_types::cosmwasm::wasm::v1::MsgInstantiateContract => crate::proto_types::cosmwasm::wasm::v1::MsgInstantiateContractResponse),
+    CosmwasmWasmV1InstantiateContract2(crate::proto_types::cosmwasm::wasm::v1::MsgInstantiateContract2 => crate::proto_types::cosmwasm::wasm::v1::MsgInstantiateContract2Response),
+    CosmwasmWasmV1MigrateContract(crate::proto_types::cosmwasm::wasm::v1::MsgMigrateContract => crate::proto_types::cosmwasm::wasm::v1::MsgMigrateContractResponse),
+    CosmwasmWasmV1PinCodes(crate::proto_types::cosmwasm::wasm::v1::MsgPinCodes => crate::proto_types::cosmwasm::wasm::v1::MsgPinCodesResponse),
+    CosmwasmWasmV1RemoveCodeUploadParamsAddresses(crate::proto_types::cosmwasm::wasm::v1::MsgRemoveCodeUploadParamsAddresses => crate::proto_types::cosmwasm::wasm::v1::MsgRemoveCodeUploadParamsAddressesResponse),
+    CosmwasmWasmV1StoreAndInstantiateContract(crate::proto_types::cosmwasm::wasm::v1::MsgStoreAndInstantiateContract => crate::proto_types::cosmwasm::wasm::v1::MsgStoreAndInstantiateContractResponse),
+    CosmwasmWasmV1StoreAndMigrateContract(crate::proto_types::cosmwasm::wasm::v1::MsgStoreAndMigrateContract => crate::proto_types::cosmwasm::wasm::v1::MsgStoreAndMigrateContractResponse),
+    CosmwasmWasmV1StoreCode(crate::proto_types::cosmwasm::wasm::v1::MsgStoreCode => crate::proto_types::cosmwasm::wasm::v1::MsgStoreCodeResponse),
+    CosmwasmWasmV1SudoContract(crate::proto_types::cosmwasm::wasm::v1::MsgSudoContract => crate::proto_types::cosmwasm::wasm::v1::MsgSudoContractResponse),
+    CosmwasmWasmV1UnpinCodes(crate::proto_types::cosmwasm::wasm::v1::MsgUnpinCodes => crate::proto_types::cosmwasm::wasm::v1::MsgUnpinCodesResponse),
+    CosmwasmWasmV1UpdateAdmin(crate::proto_types::cosmwasm::wasm::v1::MsgUpdateAdmin => crate::proto_types::cosmwasm::wasm::v1::MsgUpdateAdminResponse),
+    CosmwasmWasmV1UpdateContractLabel(crate::proto_types::cosmwasm::wasm::v1::MsgUpdateContractLabel => crate::proto_types::cosmwasm::wasm::v1::MsgUpdateContractLabelResponse),
+    CosmwasmWasmV1UpdateInstantiateConfig(crate::proto_types::cosmwasm::wasm::v1::MsgUpdateInstantiateConfig => crate::proto_types::cosmwasm::wasm::v1::MsgUpdateInstantiateConfigResponse),
+    CosmwasmWasmV1UpdateParams(crate::proto_types::cosmwasm::wasm::v1::MsgUpdateParams => crate::proto_types::cosmwasm::wasm::v1::MsgUpdateParamsResponse),
+    FeemarketFeemarketV1Params(crate::proto_types::feemarket::feemarket::v1::MsgParams => crate::proto_types::feemarket::feemarket::v1::MsgParamsResponse),
+    GaiaGlobalfeeV1beta1UpdateParams(crate::proto_types::gaia::globalfee::v1beta1::MsgUpdateParams => crate::proto_types::gaia::globalfee::v1beta1::MsgUpdateParamsResponse),
+    IbcApplicationsFeeV1PayPacketFee(crate::proto_types::ibc::applications::fee::v1::MsgPayPacketFee => crate::proto_types::ibc::applications::fee::v1::MsgPayPacketFeeResponse),
+    IbcApplicationsFeeV1PayPacketFeeAsync(crate::proto_types::ibc::applications::fee::v1::MsgPayPacketFeeAsync => crate::proto_types::ibc::applications::fee::v1::MsgPayPacketFeeAsyncResponse),
+    IbcApplicationsFeeV1RegisterCounterpartyPayee(crate::proto_types::ibc::applications::fee::v1::MsgRegisterCounterpartyPayee => crate::proto_types::ibc::applications::fee::v1::MsgRegisterCounterpartyPayeeResponse),
+    IbcApplicationsFeeV1RegisterPayee(crate::proto_types::ibc::applications::fee::v1::MsgRegisterPayee => crate::proto_types::ibc::applications::fee::v1::MsgRegisterPayeeResponse),
+    IbcApplicationsInterchainAccountsControllerV1RegisterInterchainAccount(crate::proto_types::ibc::applications::interchain_accounts::controller::v1::MsgRegisterInterchainAccount => crate::proto_types::ibc::applications::interchain_accounts::controller::v1::MsgRegisterInterchainAccountResponse),
+    IbcApplicationsInterchainAccountsControllerV1SendTx(crate::proto_types::ibc::applications::interchain_accounts::controller::v1::MsgSendTx => crate::proto_types::ibc::applications::interchain_accounts::controller::v1::MsgSendTxResponse),
+    IbcApplicationsInterchainAccountsControllerV1UpdateParams(crate::proto_types::ibc::applications::interchain_accounts::controller::v1::MsgUpdateParams => crate::proto_types::ibc::applications::interchain_accounts::controller::v1::MsgUpdateParamsResponse),
+    IbcApplicationsInterchainAccountsHostV1UpdateParams(crate::proto_types::ibc::applications::interchain_accounts::host::v1::MsgUpdateParams => crate::proto_types::ibc::applications::interchain_accounts::host::v1::MsgUpdateParamsResponse),
+    IbcApplicationsTransferV1Transfer(crate::proto_types::ibc::applications::transfer::v1::MsgTransfer => crate::proto_types::ibc::applications::transfer::v1::MsgTransferResponse),
+    IbcApplicationsTransferV1UpdateParams(crate::proto_types::ibc::applications::transfer::v1::MsgUpdateParams => crate::proto_types::ibc::applications::transfer::v1::MsgUpdateParamsResponse),
+    IbcCoreChannelV1Acknowledgement(crate::proto_types::ibc::core::channel::v1::MsgAcknowledgement => crate::proto_types::ibc::core::channel::v1::MsgAcknowledgementResponse),
+    IbcCoreChannelV1ChannelCloseConfirm(crate::proto_types::ibc::core::channel::v1::MsgChannelCloseConfirm => crate::proto_types::ibc::core::channel::v1::MsgChannelCloseConfirmResponse),
+    IbcCoreChannelV1ChannelCloseInit(crate::proto_types::ibc::core::channel::v1::MsgChannelCloseInit => crate::proto_types::ibc::core::channel::v1::MsgChannelCloseInitResponse),
+    IbcCoreChannelV1ChannelOpenAck(crate::proto_types::ibc::core::channel::v1::MsgChannelOpenAck => crate::proto_types::ibc::core::channel::v1::MsgChannelOpenAckResponse),
+    IbcCoreChannelV1ChannelOpenConfirm(crate::proto_types::ibc::core::channel::v1::MsgChannelOpenConfirm => crate::proto_types::ibc::core::channel::v1::MsgChannelOpenConfirmResponse),
+    IbcCoreChannelV1ChannelOpenInit(crate::proto_types::ibc::core::channel::v1::MsgChannelOpenInit => crate::proto_types::ibc::core::channel::v1::MsgChannelOpenInitResponse),
+    IbcCoreChannelV1ChannelOpenTry(crate::proto_types::ibc::core::channel::v1::MsgChannelOpenTry => crate::proto_types::ibc::core::channel::v1::MsgChannelOpenTryResponse),
+    IbcCoreChannelV1ChannelUpgradeAck(crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeAck => crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeAckResponse),
+    IbcCoreChannelV1ChannelUpgradeCancel(crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeCancel => crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeCancelResponse),
+    IbcCoreChannelV1ChannelUpgradeConfirm(crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeConfirm => crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeConfirmResponse),
+    IbcCoreChannelV1ChannelUpgradeInit(crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeInit => crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeInitResponse),
+    IbcCoreChannelV1ChannelUpgradeOpen(crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeOpen => crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeOpenResponse),
+    IbcCoreChannelV1ChannelUpgradeTimeout(crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeTimeout => crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeTimeoutResponse),
+    IbcCoreChannelV1ChannelUpgradeTry(crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeTry => crate::proto_types::ibc::core::channel::v1::MsgChannelUpgradeTryResponse),
+    IbcCoreChannelV1PruneAcknowledgements(crate::proto_types::ibc::core::channel::v1::MsgPruneAcknowledgements => crate::proto_types::ibc::core::channel::v1::MsgPruneAcknowledgementsResponse),
+    IbcCoreChannelV1RecvPacket(crate::proto_types::ibc::core::channel::v1::MsgRecvPacket => crate::proto_types::ibc::core::channel::v1::MsgRecvPacketResponse),
+    IbcCoreChannelV1Timeout(crate::proto_types::ibc::core::channel::v1::MsgTimeout => crate::proto_types::ibc::core::channel::v1::MsgTimeoutResponse),
+    IbcCoreChannelV1TimeoutOnClose(crate::proto_types::ibc::core::channel::v1::MsgTimeoutOnClose => crate::proto_types::ibc::core::channel::v1::MsgTimeoutOnCloseResponse),
+    IbcCoreChannelV1UpdateParams(crate::proto_types::ibc::core::channel::v1::MsgUpdateParams => crate::proto_types::ibc::core::channel::v1::MsgUpdateParamsResponse),
+    IbcCoreClientV1CreateClient(crate::proto_types::ibc::core::client::v1::MsgCreateClient => crate::proto_types::ibc::core::client::v1::MsgCreateClientResponse),
+    IbcCoreClientV1IbcSoftwareUpgrade(crate::proto_types::ibc::core::client::v1::MsgIbcSoftwareUpgrade => crate::proto_types::ibc::core::client::v1::MsgIbcSoftwareUpgradeResponse),
+    IbcCoreClientV1RecoverClient(crate::proto_types::ibc::core::client::v1::MsgRecoverClient => crate::proto_types::ibc::core::client::v1::MsgRecoverClientResponse),
+    IbcCoreClientV1UpdateClient(crate::proto_types::ibc::core::client::v1::MsgUpdateClient => crate::proto_types::ibc::core::client::v1::MsgUpdateClientResponse),
+    IbcCoreClientV1UpdateParams(crate::proto_types::ibc::core::client::v1::MsgUpdateParams => crate::proto_types::ibc::core::client::v1::MsgUpdateParamsResponse),
+    IbcCoreClientV1UpgradeClient(crate::proto_types::ibc::core::client::v1::MsgUpgradeClient => crate::proto_types::ibc::core::client::v1::MsgUpgradeClientResponse),
+    IbcCoreConnectionV1ConnectionOpenAck(crate::proto_types::ibc::core::connection::v1::MsgConnectionOpenAck => crate::proto_types::ibc::core::connection::v1::MsgConnectionOpenAckResponse),
+    IbcCoreConnectionV1ConnectionOpenConfirm(crate::proto_types::ibc::core::connection::v1::MsgConnectionOpenConfirm => crate::proto_types::ibc::core::connection::v1::MsgConnectionOpenConfirmResponse),
+    IbcCoreConnectionV1ConnectionOpenInit(crate::proto_types::ibc::core::connection::v1::MsgConnectionOpenInit => crate::proto_types::ibc::core::connection::v1::MsgConnectionOpenInitResponse),
+    IbcCoreConnectionV1ConnectionOpenTry(crate::proto_types::ibc::core::connection::v1::MsgConnectionOpenTry => crate::proto_types::ibc::core::connection::v1::MsgConnectionOpenTryResponse),
+    IbcCoreConnectionV1UpdateParams(crate::proto_types::ibc::core::connection::v1::MsgUpdateParams => crate::proto_types::ibc::core::connection::v1::MsgUpdateParamsResponse),
+    IbcLightclientsWasmV1MigrateContract(crate::proto_types::ibc::lightclients::wasm::v1::MsgMigrateContract => crate::proto_types::ibc::lightclients::wasm::v1::MsgMigrateContractResponse),
+    IbcLightclientsWasmV1RemoveChecksum(crate::proto_types::ibc::lightclients::wasm::v1::MsgRemoveChecksum => crate::proto_types::ibc::lightclients::wasm::v1::MsgRemoveChecksumResponse),
+    IbcLightclientsWasmV1StoreCode(crate::proto_types::ibc::lightclients::wasm::v1::MsgStoreCode => crate::proto_types::ibc::lightclients::wasm::v1::MsgStoreCodeResponse),
+    NeutronContractmanagerUpdateParams(crate::proto_types::neutron::contractmanager::MsgUpdateParams => crate::proto_types::neutron::contractmanager::MsgUpdateParamsResponse),
+    NeutronCronUpdateParams(crate::proto_types::neutron::cron::MsgUpdateParams => crate::proto_types::neutron::cron::MsgUpdateParamsResponse),
+    NeutronDexCancelLimitOrder(crate::proto_types::neutron::dex::MsgCancelLimitOrder => crate::proto_types::neutron::dex::MsgCancelLimitOrderResponse),
+    NeutronDexDeposit(crate::proto_types::neutron::dex::MsgDeposit => crate::proto_types::neutron::dex::MsgDepositResponse),
+    NeutronDexMultiHopSwap(crate::proto_types::neutron::dex::MsgMultiHopSwap => crate::proto_types::neutron::dex::MsgMultiHopSwapResponse),
+    NeutronDexPlaceLimitOrder(crate::proto_types::neutron::dex::MsgPlaceLimitOrder => crate::proto_types::neutron::dex::MsgPlaceLimitOrderResponse),
+    NeutronDexUpdateParams(crate::proto_types::neutron::dex::MsgUpdateParams => crate::proto_types::neutron::dex::MsgUpdateParamsResponse),
+    NeutronDexWithdrawFilledLimitOrder(crate::proto_types::neutron::dex::MsgWithdrawFilledLimitOrder => crate::proto_types::neutron::dex::MsgWithdrawFilledLimitOrderResponse),
+    NeutronDexWithdrawal(crate::proto_types::neutron::dex::MsgWithdrawal => crate::proto_types::neutron::dex::MsgWithdrawalResponse),
+    NeutronDynamicfeesV1UpdateParams(crate::proto_types::neutron::dynamicfees::v1::MsgUpdateParams => crate::proto_types::neutron::dynamicfees::v1::MsgUpdateParamsResponse),
+    NeutronFeeburnerUpdateParams(crate::proto_types::neutron::feeburner::MsgUpdateParams => crate::proto_types::neutron::feeburner::MsgUpdateParamsResponse),
+    NeutronFeerefunderUpdateParams(crate::proto_types::neutron::feerefunder::MsgUpdateParams => crate::proto_types::neutron::feerefunder::MsgUpdateParamsResponse),
+    NeutronInterchainqueriesRegisterInterchainQuery(crate::proto_types::neutron::interchainqueries::MsgRegisterInterchainQuery => crate::proto_types::neutron::interchainqueries::MsgRegisterInterchainQueryResponse),
+    NeutronInterchainqueriesRemoveInterchainQuery(crate::proto_types::neutron::interchainqueries::MsgRemoveInterchainQueryRequest => crate::proto_types::neutron::interchainqueries::MsgRemoveInterchainQueryResponse),
+    NeutronInterchainqueriesSubmitQueryResult(crate::proto_types::neutron::interchainqueries::MsgSubmitQueryResult => crate::proto_types::neutron::interchainqueries::MsgSubmitQueryResultResponse),
+    NeutronInterchainqueriesUpdateInterchainQuery(crate::proto_types::neutron::interchainqueries::MsgUpdateInterchainQueryRequest => crate::proto_types::neutron::interchainqueries::MsgUpdateInterchainQueryResponse),
+    NeutronInterchainqueriesUpdateParams(crate::proto_types::neutron::interchainqueries::MsgUpdateParams => crate::proto_types::neutron::interchainqueries::MsgUpdateParamsResponse),
+    NeutronInterchaintxsV1RegisterInterchainAccount(crate::proto_types::neutron::interchaintxs::v1::MsgRegisterInterchainAccount => crate::proto_types::neutron::interchaintxs::v1::MsgRegisterInterchainAccountResponse),
+    NeutronInterchaintxsV1SubmitTx(crate::proto_types::neutron::interchaintxs::v1::MsgSubmitTx => crate::proto_types::neutron::interchaintxs::v1::MsgSubmitTxResponse),
+    NeutronInterchaintxsV1UpdateParams(crate::proto_types::neutron::interchaintxs::v1::MsgUpdateParams => crate::proto_types::neutron::interchaintxs::v1::MsgUpdateParamsResponse),
+    NeutronTransferTransfer(crate::proto_types::neutron::transfer::MsgTransfer => crate::proto_types::neutron::transfer::MsgTransferResponse),
+    NeutronTransferUpdateParams(crate::proto_types::neutron::transfer::MsgUpdateParams => crate::proto_types::neutron::transfer::MsgUpdateParamsResponse),
+    OsmosisTokenfactoryV1beta1Burn(crate::proto_types::osmosis::tokenfactory::v1beta1::MsgBurn => crate::proto_types::osmosis::tokenfactory::v1beta1::MsgBurnResponse),
+    OsmosisTokenfactoryV1beta1ChangeAdmin(crate::proto_types::osmosis::tokenfactory::v1beta1::MsgChangeAdmin => crate::proto_types::osmosis::tokenfactory::v1beta1::MsgChangeAdminResponse),
+    OsmosisTokenfactoryV1beta1CreateDenom(crate::proto_types::osmosis::tokenfactory::v1beta1::MsgCreateDenom => crate::proto_types::osmosis::tokenfactory::v1beta1::MsgCreateDenomResponse),
+    OsmosisTokenfactoryV1beta1ForceTransfer(crate::proto_types::osmosis::tokenfactory::v1beta1::MsgForceTransfer => crate::proto_types::osmosis::tokenfactory::v1beta1::MsgForceTransferResponse),
+    OsmosisTokenfactoryV1beta1Mint(crate::proto_types::osmosis::tokenfactory::v1beta1::MsgMint => crate::proto_types::osmosis::tokenfactory::v1beta1::MsgMintResponse),
+    OsmosisTokenfactoryV1beta1SetBeforeSendHook(crate::proto_types::osmosis::tokenfactory::v1beta1::MsgSetBeforeSendHook => crate::proto_types::osmosis::tokenfactory::v1beta1::MsgSetBeforeSendHookResponse),
+    OsmosisTokenfactoryV1beta1SetDenomMetadata(crate::proto_types::osmosis::tokenfactory::v1beta1::MsgSetDenomMetadata => crate::proto_types::osmosis::tokenfactory::v1beta1::MsgSetDenomMetadataResponse),
+    OsmosisTokenfactoryV1beta1UpdateParams(crate::proto_types::osmosis::tokenfactory::v1beta1::MsgUpdateParams => crate::proto_types::osmosis::tokenfactory::v1beta1::MsgUpdateParamsResponse),
+    SlinkyAlertsV1Alert(crate::proto_types::slinky::alerts::v1::MsgAlert => crate::proto_types::slinky::alerts::v1::MsgAlertResponse),
+    SlinkyAlertsV1Conclusion(crate::proto_types::slinky::alerts::v1::MsgConclusion => crate::proto_types::slinky::alerts::v1::MsgConclusionResponse),
+    SlinkyAlertsV1UpdateParams(crate::proto_types::slinky::alerts::v1::MsgUpdateParams => crate::proto_types::slinky::alerts::v1::MsgUpdateParamsResponse),
+    SlinkyMarketmapV1CreateMarkets(crate::proto_types::slinky::marketmap::v1::MsgCreateMarkets => crate::proto_types::slinky::marketmap::v1::MsgCreateMarketsResponse),
+    SlinkyMarketmapV1Params(crate::proto_types::slinky::marketmap::v1::MsgParams => crate::proto_types::slinky::marketmap::v1::MsgParamsResponse),
+    SlinkyMarketmapV1RemoveMarketAuthorities(crate::proto_types::slinky::marketmap::v1::MsgRemoveMarketAuthorities => crate::proto_types::slinky::marketmap::v1::MsgRemoveMarketAuthoritiesResponse),
+    SlinkyMarketmapV1UpdateMarkets(crate::proto_types::slinky::marketmap::v1::MsgUpdateMarkets => crate::proto_types::slinky::marketmap::v1::MsgUpdateMarketsResponse),
+    SlinkyOracleV1AddCurrencyPairs(crate::proto_types::slinky::oracle::v1::MsgAddCurrencyPairs => crate::proto_types::slinky::oracle::v1::MsgAddCurrencyPairsResponse),
+    SlinkyOracleV1RemoveCurrencyPairs(crate::proto_types::slinky::oracle::v1::MsgRemoveCurrencyPairs => crate::proto_types::slinky::oracle::v1::MsgRemoveCurrencyPairsResponse),
+    SlinkySlaV1AddSlAs(crate::proto_types::slinky::sla::v1::MsgAddSlAs => crate::proto_types::slinky::sla::v1::MsgAddSlAsResponse),
+    SlinkySlaV1Params(crate::proto_types::slinky::sla::v1::MsgParams => crate::proto_types::slinky::sla::v1::MsgParamsResponse),
+    SlinkySlaV1RemoveSlAs(crate::proto_types::slinky::sla::v1::MsgRemoveSlAs => crate::proto_types::slinky::sla::v1::MsgRemoveSlAsResponse),
+}
@@ -0,0 +1,278 @@
+use crate::{
+    bindings::types::ProtobufAny, errors::error::NeutronResult,
+    proto_types::cosmos::base::abci::v1beta1::TxMsgData,
+};
+use cosmwasm_std::Binary;
+use prost::Message;
+
+/// Declares [TypedMsgResponse] with a variant per known message, the registry of
+/// message type URL -> response type URL, and decoding of responses by either of the URLs.
+macro_rules! typed_msg_responses {
+    ($($variant:ident($msg:ty => $response:ty),)*) => {
+        /// Decoded response of a message executed by an interchain account on remote chain.
+        ///
+        /// There is a variant for every message of [crate::proto_types] which has a response type,
+        /// named after the proto package and the message, e.g. **CosmosStakingV1beta1Delegate**
+        /// holds the response of `/cosmos.staking.v1beta1.MsgDelegate`.
+        /// Responses of messages of other chains' modules are returned as [TypedMsgResponse::Unknown]
+        /// with the raw bytes.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum TypedMsgResponse {
+            $($variant($response),)*
+            /// **Unknown** is a response of a message which isn't known to the registry, left as is
+            Unknown(ProtobufAny),
+        }
+
+        /// Returns type URL of the response of the message with **msg_type_url**,
+        /// or **None** if the message isn't known to the registry
+        pub fn response_type_url(msg_type_url: &str) -> Option<&'static str> {
+            match msg_type_url {
+                $(<$msg>::TYPE_URL => Some(<$response>::TYPE_URL),)*
+                _ => None,
+            }
+        }
+
+        impl TypedMsgResponse {
+            /// Decodes the response **value**. The **type_url** is either type URL of the response
+            /// (SDK 0.46+ acknowledgements) or type URL of the executed message (SDK 0.45 acknowledgements).
+            pub fn decode(type_url: &str, value: &[u8]) -> NeutronResult<Self> {
+                Ok(match type_url {
+                    $(<$msg>::TYPE_URL | <$response>::TYPE_URL => {
+                        TypedMsgResponse::$variant(<$response>::decode(value)?)
+                    })*
+                    _ => TypedMsgResponse::Unknown(ProtobufAny::new(
+                        type_url.to_string(),
+                        Binary::from(value),
+                    )),
+                })
+            }
+
+            /// Returns type URL of the response
+            pub fn type_url(&self) -> String {
+                match self {
+                    $(TypedMsgResponse::$variant(_) => <$response>::TYPE_URL.to_string(),)*
+                    TypedMsgResponse::Unknown(any) => any.type_url.clone(),
+                }
+            }
+        }
+    };
+}
+
+// the registry is generated by proto-build from the messages of crate::proto_types
+include!("msg_responses.rs");
+
+/// Decodes acknowledgement of an interchain transaction into a list of typed responses,
+/// one per executed message, in the order the messages were submitted.
+///
+/// Works for acknowledgements from both SDK 0.45 (`TxMsgData.data` with message type URLs)
+/// and SDK 0.46+ (`TxMsgData.msg_responses` with response type URLs) remote chains.
+pub fn decode_typed_acknowledgement_response(data: Binary) -> NeutronResult<Vec<TypedMsgResponse>> {
+    let msg_data = TxMsgData::decode(data.as_slice())?;
+
+    if !msg_data.msg_responses.is_empty() {
+        return msg_data
+            .msg_responses
+            .iter()
+            .map(|any| TypedMsgResponse::decode(&any.type_url, &any.value))
+            .collect();
+    }
+
+    #[allow(deprecated)]
+    msg_data
+        .data
+        .iter()
+        .map(|item| TypedMsgResponse::decode(&item.msg_type, &item.data))
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+    use crate::proto_types::cosmos::base::{abci::v1beta1::MsgData, v1beta1::Coin};
+    use crate::proto_types::cosmos::{
+        authz::v1beta1 as authz, distribution::v1beta1 as distribution, staking::v1beta1 as staking,
+    };
+    use crate::proto_types::ibc::applications::transfer::v1 as transfer;
+    use crate::proto_types::neutron::interchainqueries;
+    use crate::proto_types::osmosis::tokenfactory::v1beta1 as tokenfactory;
+    use crate::shim::{Any, Timestamp};
+
+    fn undelegate_response() -> staking::MsgUndelegateResponse {
+        staking::MsgUndelegateResponse {
+            completion_time: Some(Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 0,
+            }),
+            amount: Some(Coin {
+                denom: "uatom".to_string(),
+                amount: "1000".to_string(),
+            }),
+        }
+    }
+
+    fn withdraw_response() -> distribution::MsgWithdrawDelegatorRewardResponse {
+        distribution::MsgWithdrawDelegatorRewardResponse {
+            amount: vec![Coin {
+                denom: "uatom".to_string(),
+                amount: "15".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_response_type_url() {
+        assert_eq!(
+            response_type_url("/cosmos.staking.v1beta1.MsgDelegate"),
+            Some("/cosmos.staking.v1beta1.MsgDelegateResponse")
+        );
+        assert_eq!(
+            response_type_url("/ibc.applications.transfer.v1.MsgTransfer"),
+            Some("/ibc.applications.transfer.v1.MsgTransferResponse")
+        );
+        assert_eq!(
+            response_type_url("/cosmos.authz.v1beta1.MsgExec"),
+            Some("/cosmos.authz.v1beta1.MsgExecResponse")
+        );
+        assert_eq!(
+            response_type_url("/cosmos.feegrant.v1beta1.MsgGrantAllowance"),
+            Some("/cosmos.feegrant.v1beta1.MsgGrantAllowanceResponse")
+        );
+        assert_eq!(
+            response_type_url("/neutron.dex.MsgPlaceLimitOrder"),
+            Some("/neutron.dex.MsgPlaceLimitOrderResponse")
+        );
+        assert_eq!(
+            response_type_url("/neutron.interchainqueries.MsgRemoveInterchainQueryRequest"),
+            Some("/neutron.interchainqueries.MsgRemoveInterchainQueryResponse")
+        );
+        assert_eq!(response_type_url("/unknown.MsgUnknown"), None);
+    }
+
+    #[test]
+    fn test_decode_sdk47_acknowledgement() {
+        let ack = TxMsgData {
+            data: vec![],
+            msg_responses: vec![
+                Any {
+                    type_url: staking::MsgDelegateResponse::TYPE_URL.to_string(),
+                    value: staking::MsgDelegateResponse {}.encode_to_vec(),
+                },
+                Any {
+                    type_url: staking::MsgUndelegateResponse::TYPE_URL.to_string(),
+                    value: undelegate_response().encode_to_vec(),
+                },
+                Any {
+                    type_url: "/unknown.MsgUnknownResponse".to_string(),
+                    value: vec![1, 2, 3],
+                },
+            ],
+        };
+
+        let responses =
+            decode_typed_acknowledgement_response(Binary::new(ack.encode_to_vec())).unwrap();
+
+        assert_eq!(
+            responses,
+            vec![
+                TypedMsgResponse::CosmosStakingV1beta1Delegate(staking::MsgDelegateResponse {}),
+                TypedMsgResponse::CosmosStakingV1beta1Undelegate(undelegate_response()),
+                TypedMsgResponse::Unknown(ProtobufAny::new(
+                    "/unknown.MsgUnknownResponse".to_string(),
+                    Binary::new(vec![1, 2, 3])
+                )),
+            ]
+        );
+        assert_eq!(
+            responses[1].type_url(),
+            "/cosmos.staking.v1beta1.MsgUndelegateResponse"
+        );
+    }
+
+    #[test]
+    fn test_decode_sdk45_acknowledgement() {
+        let ack = TxMsgData {
+            data: vec![
+                MsgData {
+                    msg_type: distribution::MsgWithdrawDelegatorReward::TYPE_URL.to_string(),
+                    data: withdraw_response().encode_to_vec(),
+                },
+                MsgData {
+                    msg_type: transfer::MsgTransfer::TYPE_URL.to_string(),
+                    data: transfer::MsgTransferResponse { sequence: 4 }.encode_to_vec(),
+                },
+            ],
+            msg_responses: vec![],
+        };
+
+        assert_eq!(
+            decode_typed_acknowledgement_response(Binary::new(ack.encode_to_vec())).unwrap(),
+            vec![
+                TypedMsgResponse::CosmosDistributionV1beta1WithdrawDelegatorReward(
+                    withdraw_response()
+                ),
+                TypedMsgResponse::IbcApplicationsTransferV1Transfer(
+                    transfer::MsgTransferResponse { sequence: 4 }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_generated_registry() {
+        let ack = TxMsgData {
+            data: vec![],
+            msg_responses: vec![
+                Any {
+                    type_url: authz::MsgExecResponse::TYPE_URL.to_string(),
+                    value: authz::MsgExecResponse {
+                        results: vec![vec![1, 2]],
+                    }
+                    .encode_to_vec(),
+                },
+                Any {
+                    type_url: tokenfactory::MsgCreateDenomResponse::TYPE_URL.to_string(),
+                    value: tokenfactory::MsgCreateDenomResponse {
+                        new_token_denom: "factory/neutron1contract/token".to_string(),
+                    }
+                    .encode_to_vec(),
+                },
+                Any {
+                    type_url: interchainqueries::MsgRemoveInterchainQueryResponse::TYPE_URL
+                        .to_string(),
+                    value: vec![],
+                },
+            ],
+        };
+
+        assert_eq!(
+            decode_typed_acknowledgement_response(Binary::new(ack.encode_to_vec())).unwrap(),
+            vec![
+                TypedMsgResponse::CosmosAuthzV1beta1Exec(authz::MsgExecResponse {
+                    results: vec![vec![1, 2]],
+                }),
+                TypedMsgResponse::OsmosisTokenfactoryV1beta1CreateDenom(
+                    tokenfactory::MsgCreateDenomResponse {
+                        new_token_denom: "factory/neutron1contract/token".to_string(),
+                    }
+                ),
+                TypedMsgResponse::NeutronInterchainqueriesRemoveInterchainQuery(
+                    interchainqueries::MsgRemoveInterchainQueryResponse {}
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_acknowledgement() {
+        let ack = TxMsgData {
+            data: vec![],
+            msg_responses: vec![Any {
+                type_url: staking::MsgUndelegateResponse::TYPE_URL.to_string(),
+                value: vec![0xff, 0xff],
+            }],
+        };
+
+        assert!(decode_typed_acknowledgement_response(Binary::new(ack.encode_to_vec())).is_err());
+    }
+}
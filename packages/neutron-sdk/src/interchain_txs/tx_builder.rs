@@ -0,0 +1,395 @@
+use crate::{
+    bindings::{
+        msg::{IbcFee, NeutronMsg},
+        query::NeutronQuery,
+        types::ProtobufAny,
+    },
+    errors::error::{NeutronError, NeutronResult},
+    interchain_txs::responses::{decode_typed_acknowledgement_response, TypedMsgResponse},
+};
+use cosmwasm_std::{Binary, DepsMut, Empty, Env, Response};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Default value of **msg_submit_tx_max_messages** param of the **interchaintxs** module
+pub const DEFAULT_MAX_MSGS: usize = 16;
+
+/// Default timeout in seconds of an interchain transaction, two weeks
+pub const DEFAULT_TIMEOUT_SECONDS: u64 = 60 * 60 * 24 * 7 * 2;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Callback tags of messages of a submitted interchain transaction, in the order of the messages.
+/// Save it as (a part of) a sudo payload to deliver the responses to [IcaTxHandlers] when the ack arrives.
+pub struct IcaTxCallbacks {
+    pub tags: Vec<String>,
+}
+
+/// Builds a **SubmitTx** message from typed proto messages, attaching a callback tag to every message.
+///
+/// ```rust ignore
+/// let (msg, callbacks) = IcaTxBuilder::new(connection_id, interchain_account_id, fee)
+///     .add_msg(delegate.to_any(), "delegate")
+///     .add_msg(withdraw_rewards.to_any(), "withdraw_rewards")
+///     .build()?;
+/// let submsg = SUDO_PAYLOADS.with_sudo_callback(deps.storage, msg, &callbacks)?;
+/// ```
+pub struct IcaTxBuilder {
+    connection_id: String,
+    interchain_account_id: String,
+    fee: IbcFee,
+    msgs: Vec<ProtobufAny>,
+    tags: Vec<String>,
+    memo: String,
+    timeout: u64,
+    max_msgs: usize,
+}
+
+impl IcaTxBuilder {
+    /// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+    /// * **interchain_account_id** is an identifier of your interchain account from which you want to execute msgs;
+    /// * **fee** is a fee that is used for different kinds of callbacks.
+    pub fn new(connection_id: String, interchain_account_id: String, fee: IbcFee) -> Self {
+        IcaTxBuilder {
+            connection_id,
+            interchain_account_id,
+            fee,
+            msgs: vec![],
+            tags: vec![],
+            memo: String::new(),
+            timeout: DEFAULT_TIMEOUT_SECONDS,
+            max_msgs: DEFAULT_MAX_MSGS,
+        }
+    }
+
+    /// Adds a message to execute on remote chain. Its response is delivered to the handler of the **callback** tag.
+    /// Any message from `proto_types` can be added with its `to_any()`.
+    pub fn add_msg(mut self, msg: impl Into<ProtobufAny>, callback: impl Into<String>) -> Self {
+        self.msgs.push(msg.into());
+        self.tags.push(callback.into());
+        self
+    }
+
+    /// Sets a memo of the interchain transaction
+    pub fn memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+
+    /// Sets a timeout in seconds after which the packet times out
+    pub fn timeout(mut self, timeout: u64) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets maximum number of messages in a transaction, i.e. **msg_submit_tx_max_messages** param
+    /// of the **interchaintxs** module
+    pub fn max_msgs(mut self, max_msgs: usize) -> Self {
+        self.max_msgs = max_msgs;
+        self
+    }
+
+    /// Creates a **SubmitTx** message and callback tags of its messages
+    pub fn build(self) -> NeutronResult<(NeutronMsg, IcaTxCallbacks)> {
+        if self.msgs.is_empty() {
+            return Err(NeutronError::EmptyInterchainTx {});
+        }
+        if self.msgs.len() > self.max_msgs {
+            return Err(NeutronError::TooManyInterchainTxMessages {
+                max: self.max_msgs,
+                actual: self.msgs.len(),
+            });
+        }
+
+        Ok((
+            NeutronMsg::submit_tx(
+                self.connection_id,
+                self.interchain_account_id,
+                self.msgs,
+                self.memo,
+                self.timeout,
+                self.fee,
+            ),
+            IcaTxCallbacks { tags: self.tags },
+        ))
+    }
+}
+
+type MsgResponseHandler<'a, C> =
+    Box<dyn Fn(DepsMut<NeutronQuery>, Env, TypedMsgResponse) -> NeutronResult<Response<C>> + 'a>;
+
+/// Set of per-tag handlers of responses of interchain transaction messages
+pub struct IcaTxHandlers<'a, C = Empty> {
+    handlers: Vec<(String, MsgResponseHandler<'a, C>)>,
+}
+
+impl<'a, C> Default for IcaTxHandlers<'a, C> {
+    fn default() -> Self {
+        IcaTxHandlers { handlers: vec![] }
+    }
+}
+
+impl<'a, C> IcaTxHandlers<'a, C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a **handler** of responses of messages added with the **callback** tag
+    pub fn on(
+        mut self,
+        callback: impl Into<String>,
+        handler: impl Fn(DepsMut<NeutronQuery>, Env, TypedMsgResponse) -> NeutronResult<Response<C>>
+            + 'a,
+    ) -> Self {
+        self.handlers.push((callback.into(), Box::new(handler)));
+        self
+    }
+
+    /// Decodes the acknowledgement **data** of a transaction and delivers every message response
+    /// to the handler of its callback tag. Returns the responses of the handlers merged into one.
+    ///
+    /// The data of the merged response is the data set by a handler. Only one handler may set it,
+    /// [NeutronError::ConflictingInterchainTxResponseData] is returned otherwise.
+    pub fn handle_ack(
+        &self,
+        mut deps: DepsMut<NeutronQuery>,
+        env: Env,
+        callbacks: &IcaTxCallbacks,
+        data: Binary,
+    ) -> NeutronResult<Response<C>> {
+        let responses = decode_typed_acknowledgement_response(data)?;
+        if responses.len() != callbacks.tags.len() {
+            return Err(NeutronError::InvalidInterchainTxAck {
+                expected: callbacks.tags.len(),
+                actual: responses.len(),
+            });
+        }
+
+        let mut merged = Response::new();
+        let mut data_callback: Option<&String> = None;
+        for (tag, response) in callbacks.tags.iter().zip(responses) {
+            let handler = self
+                .handlers
+                .iter()
+                .find(|(callback, _)| callback == tag)
+                .map(|(_, handler)| handler)
+                .ok_or_else(|| NeutronError::InterchainTxCallbackNotFound {
+                    callback: tag.clone(),
+                })?;
+
+            let response = handler(deps.branch(), env.clone(), response)?;
+            if let Some(data) = response.data {
+                if let Some(first) = data_callback {
+                    return Err(NeutronError::ConflictingInterchainTxResponseData {
+                        first: first.clone(),
+                        second: tag.clone(),
+                    });
+                }
+                data_callback = Some(tag);
+                merged = merged.set_data(data);
+            }
+            merged = merged
+                .add_submessages(response.messages)
+                .add_attributes(response.attributes)
+                .add_events(response.events);
+        }
+
+        Ok(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto_types::cosmos::{
+        base::abci::v1beta1::TxMsgData,
+        base::v1beta1::Coin,
+        distribution::v1beta1::{MsgWithdrawDelegatorReward, MsgWithdrawDelegatorRewardResponse},
+        staking::v1beta1::{MsgDelegate, MsgDelegateResponse},
+    };
+    use crate::shim::Any;
+    use crate::testing::{mock_neutron_deps, NeutronDeps};
+    use cosmwasm_std::testing::{mock_env, MockQuerier};
+    use cosmwasm_std::Attribute;
+    use prost::Message;
+
+    fn fee() -> IbcFee {
+        IbcFee {
+            recv_fee: vec![],
+            ack_fee: vec![cosmwasm_std::coin(1000, "untrn")],
+            timeout_fee: vec![cosmwasm_std::coin(1000, "untrn")],
+        }
+    }
+
+    fn delegate() -> MsgDelegate {
+        MsgDelegate {
+            delegator_address: "cosmos1ica".to_string(),
+            validator_address: "cosmosvaloper1val".to_string(),
+            amount: Some(Coin {
+                denom: "uatom".to_string(),
+                amount: "100".to_string(),
+            }),
+        }
+    }
+
+    fn withdraw() -> MsgWithdrawDelegatorReward {
+        MsgWithdrawDelegatorReward {
+            delegator_address: "cosmos1ica".to_string(),
+            validator_address: "cosmosvaloper1val".to_string(),
+        }
+    }
+
+    #[allow(deprecated)]
+    fn ack(responses: Vec<Any>) -> Binary {
+        Binary::new(
+            TxMsgData {
+                data: vec![],
+                msg_responses: responses,
+            }
+            .encode_to_vec(),
+        )
+    }
+
+    fn neutron_deps() -> NeutronDeps {
+        mock_neutron_deps(MockQuerier::new(&[]))
+    }
+
+    #[test]
+    fn test_build() {
+        let (msg, callbacks) =
+            IcaTxBuilder::new("connection-0".to_string(), "test".to_string(), fee())
+                .add_msg(delegate().to_any(), "delegate")
+                .add_msg(withdraw().to_any(), "withdraw")
+                .memo("memo")
+                .timeout(100)
+                .build()
+                .unwrap();
+
+        assert_eq!(
+            msg,
+            NeutronMsg::submit_tx(
+                "connection-0".to_string(),
+                "test".to_string(),
+                vec![delegate().to_any().into(), withdraw().to_any().into()],
+                "memo".to_string(),
+                100,
+                fee(),
+            )
+        );
+        assert_eq!(
+            callbacks.tags,
+            vec!["delegate".to_string(), "withdraw".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_build_errors() {
+        assert_eq!(
+            IcaTxBuilder::new("connection-0".to_string(), "test".to_string(), fee())
+                .build()
+                .unwrap_err(),
+            NeutronError::EmptyInterchainTx {}
+        );
+
+        assert_eq!(
+            IcaTxBuilder::new("connection-0".to_string(), "test".to_string(), fee())
+                .max_msgs(1)
+                .add_msg(delegate().to_any(), "delegate")
+                .add_msg(withdraw().to_any(), "withdraw")
+                .build()
+                .unwrap_err(),
+            NeutronError::TooManyInterchainTxMessages { max: 1, actual: 2 }
+        );
+    }
+
+    #[test]
+    fn test_handle_ack() {
+        let mut deps = neutron_deps();
+        let callbacks = IcaTxCallbacks {
+            tags: vec!["delegate".to_string(), "withdraw".to_string()],
+        };
+
+        let withdraw_response = MsgWithdrawDelegatorRewardResponse {
+            amount: vec![Coin {
+                denom: "uatom".to_string(),
+                amount: "15".to_string(),
+            }],
+        };
+        let data = ack(vec![
+            MsgDelegateResponse {}.to_any(),
+            withdraw_response.to_any(),
+        ]);
+
+        let handlers = IcaTxHandlers::<Empty>::new()
+            .on("delegate", |_, _, response| {
+                assert_eq!(
+                    response,
+                    TypedMsgResponse::CosmosStakingV1beta1Delegate(MsgDelegateResponse {})
+                );
+                Ok(Response::new()
+                    .add_attribute("delegated", "true")
+                    .set_data(Binary::from(b"delegated")))
+            })
+            .on("withdraw", |_, _, response| match response {
+                TypedMsgResponse::CosmosDistributionV1beta1WithdrawDelegatorReward(r) => {
+                    Ok(Response::new().add_attribute("rewards", r.amount[0].amount.clone()))
+                }
+                _ => panic!("unexpected response"),
+            });
+
+        let response = handlers
+            .handle_ack(deps.as_mut(), mock_env(), &callbacks, data.clone())
+            .unwrap();
+        assert_eq!(
+            response.attributes,
+            vec![
+                Attribute::new("delegated", "true"),
+                Attribute::new("rewards", "15")
+            ]
+        );
+        assert_eq!(response.data, Some(Binary::from(b"delegated")));
+
+        assert_eq!(
+            IcaTxHandlers::<Empty>::new()
+                .on("delegate", |_, _, _| Ok(
+                    Response::new().set_data(Binary::from(b"delegate"))
+                ))
+                .on("withdraw", |_, _, _| Ok(
+                    Response::new().set_data(Binary::from(b"withdraw"))
+                ))
+                .handle_ack(deps.as_mut(), mock_env(), &callbacks, data.clone())
+                .unwrap_err(),
+            NeutronError::ConflictingInterchainTxResponseData {
+                first: "delegate".to_string(),
+                second: "withdraw".to_string()
+            }
+        );
+
+        assert_eq!(
+            IcaTxHandlers::<Empty>::new()
+                .on("delegate", |_, _, _| Ok(Response::new()))
+                .handle_ack(deps.as_mut(), mock_env(), &callbacks, data.clone())
+                .unwrap_err(),
+            NeutronError::InterchainTxCallbackNotFound {
+                callback: "withdraw".to_string()
+            }
+        );
+
+        assert_eq!(
+            handlers
+                .handle_ack(
+                    deps.as_mut(),
+                    mock_env(),
+                    &IcaTxCallbacks {
+                        tags: vec!["delegate".to_string()]
+                    },
+                    data
+                )
+                .unwrap_err(),
+            NeutronError::InvalidInterchainTxAck {
+                expected: 1,
+                actual: 2
+            }
+        );
+    }
+}
@@ -7,7 +7,10 @@
 pub const NEUTROND_VERSION: &str = include_str!("proto_types/NEUTRON_COMMIT");
 
 pub mod bindings;
+pub mod contractmanager;
+pub mod cron;
 mod errors;
+pub mod ibc;
 pub mod interchain_queries;
 pub mod interchain_txs;
 #[allow(deprecated, clippy::module_inception)]
@@ -16,6 +19,9 @@ pub mod query;
 mod serde;
 pub mod shim;
 pub mod sudo;
+#[cfg(test)]
+mod testing;
+pub mod tokenfactory;
 
 pub use errors::error::{NeutronError, NeutronResult};
 pub use shim::{cosmwasm_to_proto_coins, try_proto_to_cosmwasm_coins};
@@ -1,3 +1,4 @@
 pub mod min_ibc_fee;
+pub mod pagination;
 pub mod token_factory;
 pub mod total_burned_neutrons;
@@ -0,0 +1,504 @@
+use crate::{
+    bindings::{
+        dex::query::{
+            AllInactiveLimitOrderTrancheResponse, AllLimitOrderTrancheResponse,
+            AllLimitOrderTrancheUserResponse, AllPoolMetadataResponse, AllPoolReservesResponse,
+            AllTickLiquidityResponse, AllUserDepositsResponse, AllUserLimitOrdersResponse,
+        },
+        query::{PageRequest, QueryRegisteredQueriesResponse},
+    },
+    proto_types::{
+        cosmos::{
+            bank::v1beta1 as bank, base::query::v1beta1 as proto_query, staking::v1beta1 as staking,
+        },
+        neutron::{contractmanager, cron, interchainqueries as icq},
+    },
+};
+use cosmwasm_std::{Binary, CustomQuery, QuerierWrapper, QueryRequest, StdResult};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// Default number of items requested per page
+pub const DEFAULT_PAGE_SIZE: u64 = 100;
+
+/// Position to continue a paginated query from
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PageCursor {
+    /// **next_key** of the last received page
+    Key(Binary),
+    /// Number of items already received, for queries which don't return **next_key**
+    Offset(u64),
+}
+
+/// Pagination request of a query, either [PageRequest] of the custom queries
+/// or **PageRequest** of the gRPC queries in `proto_types`
+pub trait PaginationRequest {
+    fn from_cursor(cursor: Option<&PageCursor>, limit: u64) -> Self;
+}
+
+impl PaginationRequest for PageRequest {
+    fn from_cursor(cursor: Option<&PageCursor>, limit: u64) -> Self {
+        let (key, offset) = match cursor {
+            Some(PageCursor::Key(key)) => (key.clone(), 0),
+            Some(PageCursor::Offset(offset)) => (Binary::default(), *offset),
+            None => (Binary::default(), 0),
+        };
+        PageRequest {
+            key,
+            offset,
+            limit,
+            count_total: false,
+            reverse: false,
+        }
+    }
+}
+
+impl PaginationRequest for proto_query::PageRequest {
+    fn from_cursor(cursor: Option<&PageCursor>, limit: u64) -> Self {
+        let (key, offset) = match cursor {
+            Some(PageCursor::Key(key)) => (key.to_vec(), 0),
+            Some(PageCursor::Offset(offset)) => (vec![], *offset),
+            None => (vec![], 0),
+        };
+        proto_query::PageRequest {
+            key,
+            offset,
+            limit,
+            count_total: false,
+            reverse: false,
+        }
+    }
+}
+
+impl PaginationRequest for Option<PageRequest> {
+    fn from_cursor(cursor: Option<&PageCursor>, limit: u64) -> Self {
+        Some(PageRequest::from_cursor(cursor, limit))
+    }
+}
+
+impl PaginationRequest for Option<proto_query::PageRequest> {
+    fn from_cursor(cursor: Option<&PageCursor>, limit: u64) -> Self {
+        Some(proto_query::PageRequest::from_cursor(cursor, limit))
+    }
+}
+
+/// Response of a paginated query
+pub trait Paged {
+    type Item;
+
+    /// Whether the response returns **next_key**. Responses without it are paginated by offset.
+    const HAS_NEXT_KEY: bool = true;
+
+    /// Returns items of the page and **next_key** of the next page
+    fn into_page(self) -> (Vec<Self::Item>, Option<Binary>);
+}
+
+macro_rules! impl_paged {
+    ($($response:ty => $field:ident: $item:ty,)*) => {
+        $(
+            impl Paged for $response {
+                type Item = $item;
+
+                fn into_page(self) -> (Vec<Self::Item>, Option<Binary>) {
+                    let next_key = self.pagination.and_then(|p| p.next_key).map(Binary::from);
+                    (self.$field, next_key)
+                }
+            }
+        )*
+    };
+}
+
+impl_paged! {
+    AllLimitOrderTrancheUserResponse => limit_order_tranche_user: crate::bindings::dex::types::LimitOrderTrancheUser,
+    AllUserLimitOrdersResponse => limit_orders: crate::bindings::dex::types::LimitOrderTrancheUser,
+    AllLimitOrderTrancheResponse => limit_order_tranche: crate::bindings::dex::types::LimitOrderTranche,
+    AllUserDepositsResponse => deposits: crate::bindings::dex::types::DepositRecord,
+    AllTickLiquidityResponse => tick_liquidity: crate::bindings::dex::types::TickLiquidity,
+    AllInactiveLimitOrderTrancheResponse => inactive_limit_order_tranche: crate::bindings::dex::types::LimitOrderTranche,
+    AllPoolReservesResponse => pool_reserves: crate::bindings::dex::types::PoolReserves,
+    AllPoolMetadataResponse => pool_metadata: crate::bindings::dex::types::PoolMetadata,
+    bank::QueryAllBalancesResponse => balances: crate::proto_types::cosmos::base::v1beta1::Coin,
+    bank::QueryTotalSupplyResponse => supply: crate::proto_types::cosmos::base::v1beta1::Coin,
+    bank::QueryDenomsMetadataResponse => metadatas: bank::Metadata,
+    staking::QueryValidatorsResponse => validators: staking::Validator,
+    staking::QueryValidatorDelegationsResponse => delegation_responses: staking::DelegationResponse,
+    staking::QueryDelegatorDelegationsResponse => delegation_responses: staking::DelegationResponse,
+    staking::QueryDelegatorUnbondingDelegationsResponse => unbonding_responses: staking::UnbondingDelegation,
+    icq::QueryRegisteredQueriesResponse => registered_queries: icq::RegisteredQuery,
+    cron::QuerySchedulesResponse => schedules: cron::Schedule,
+    contractmanager::QueryFailuresResponse => failures: contractmanager::Failure,
+}
+
+impl Paged for QueryRegisteredQueriesResponse {
+    type Item = crate::bindings::types::RegisteredQuery;
+
+    // the custom query doesn't return pagination of the response
+    const HAS_NEXT_KEY: bool = false;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<Binary>) {
+        (self.registered_queries, None)
+    }
+}
+
+/// Items received by [Paginator::collect_pages] and the cursor to continue from,
+/// **None** if all items were received
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PaginatedItems<T> {
+    pub items: Vec<T>,
+    pub next: Option<PageCursor>,
+}
+
+/// Settings of a paginated query: page size, total items cap and the position to start from.
+///
+/// ```rust ignore
+/// // custom queries
+/// let liquidity: Vec<TickLiquidity> = Paginator::new(50).max_items(500).query::<_, AllTickLiquidityResponse>(
+///     &deps.querier,
+///     |pagination| DexQuery::TickLiquidityAll { pair_id: pair_id.clone(), token_in: token_in.clone(), pagination: Some(pagination) }.into(),
+/// ).collect::<StdResult<_>>()?;
+///
+/// // gRPC queries
+/// let bank = BankQuerier::new(&deps.querier);
+/// let balances = Paginator::default().collect(|pagination| bank.all_balances(address.clone(), pagination, false))?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Paginator {
+    page_size: u64,
+    max_items: Option<u64>,
+    start: Option<PageCursor>,
+}
+
+impl Default for Paginator {
+    fn default() -> Self {
+        Paginator::new(DEFAULT_PAGE_SIZE)
+    }
+}
+
+impl Paginator {
+    /// Creates a paginator which requests **page_size** items per page
+    pub fn new(page_size: u64) -> Self {
+        Paginator {
+            page_size: page_size.max(1),
+            max_items: None,
+            start: None,
+        }
+    }
+
+    /// Stops after **max_items** items are received
+    pub fn max_items(mut self, max_items: u64) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Starts from the **cursor** returned by a previous [Paginator::collect_pages]
+    pub fn start_from(mut self, cursor: Option<PageCursor>) -> Self {
+        self.start = cursor;
+        self
+    }
+
+    /// Returns an iterator over items of all pages. Pages are requested with **fetch** lazily.
+    pub fn iter<Req, R, F>(self, fetch: F) -> PageIter<Req, R, F>
+    where
+        Req: PaginationRequest,
+        R: Paged,
+        F: FnMut(Req) -> StdResult<R>,
+    {
+        PageIter {
+            fetch,
+            page_size: self.page_size,
+            remaining: self.max_items,
+            cursor: self.start,
+            buffer: VecDeque::new(),
+            done: false,
+            _request: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over items of a custom query created by **make_request** for every page
+    pub fn query<'a, C, R>(
+        self,
+        querier: &'a QuerierWrapper<'a, C>,
+        make_request: impl Fn(PageRequest) -> QueryRequest<C> + 'a,
+    ) -> PageIter<PageRequest, R, impl FnMut(PageRequest) -> StdResult<R> + 'a>
+    where
+        C: CustomQuery + 'a,
+        R: Paged + DeserializeOwned,
+    {
+        self.iter(move |pagination| querier.query(&make_request(pagination)))
+    }
+
+    /// Collects items of all pages
+    pub fn collect<Req, R, F>(self, fetch: F) -> StdResult<Vec<R::Item>>
+    where
+        Req: PaginationRequest,
+        R: Paged,
+        F: FnMut(Req) -> StdResult<R>,
+    {
+        self.iter(fetch).collect()
+    }
+
+    /// Collects items of at most **max_pages** pages and returns the cursor to continue from.
+    /// Use it to keep the gas spent on queries bounded and continue in the next transaction.
+    pub fn collect_pages<Req, R, F>(
+        self,
+        max_pages: usize,
+        fetch: F,
+    ) -> StdResult<PaginatedItems<R::Item>>
+    where
+        Req: PaginationRequest,
+        R: Paged,
+        F: FnMut(Req) -> StdResult<R>,
+    {
+        let mut iter = self.iter(fetch);
+        let mut items = vec![];
+        for _ in 0..max_pages {
+            match iter.next_page()? {
+                Some(page) => items.extend(page),
+                None => break,
+            }
+        }
+
+        Ok(PaginatedItems {
+            items,
+            next: iter.next_cursor(),
+        })
+    }
+}
+
+/// Iterator over items of a paginated query, created by [Paginator::iter] or [paginate]
+pub struct PageIter<Req, R: Paged, F> {
+    fetch: F,
+    page_size: u64,
+    remaining: Option<u64>,
+    cursor: Option<PageCursor>,
+    buffer: VecDeque<R::Item>,
+    done: bool,
+    _request: PhantomData<Req>,
+}
+
+impl<Req, R, F> PageIter<Req, R, F>
+where
+    Req: PaginationRequest,
+    R: Paged,
+    F: FnMut(Req) -> StdResult<R>,
+{
+    /// Returns the cursor to continue the query from after the items received so far,
+    /// **None** if there are no more items
+    pub fn next_cursor(&self) -> Option<PageCursor> {
+        self.cursor.clone()
+    }
+
+    fn next_page(&mut self) -> StdResult<Option<Vec<R::Item>>> {
+        if self.done || self.remaining == Some(0) {
+            return Ok(None);
+        }
+
+        // never request more than the cap, so the cursor points right after the last received item
+        let limit = self
+            .remaining
+            .map_or(self.page_size, |remaining| remaining.min(self.page_size));
+        let response = match (self.fetch)(Req::from_cursor(self.cursor.as_ref(), limit)) {
+            Ok(response) => response,
+            Err(err) => {
+                self.done = true;
+                return Err(err);
+            }
+        };
+        let (items, next_key) = response.into_page();
+        let received = items.len() as u64;
+
+        self.remaining = self
+            .remaining
+            .map(|remaining| remaining.saturating_sub(received));
+        self.cursor = if R::HAS_NEXT_KEY {
+            next_key.filter(|key| !key.is_empty()).map(PageCursor::Key)
+        } else if received >= limit {
+            let offset = match &self.cursor {
+                Some(PageCursor::Offset(offset)) => *offset,
+                _ => 0,
+            };
+            Some(PageCursor::Offset(offset + received))
+        } else {
+            None
+        };
+        self.done = self.cursor.is_none();
+
+        Ok(Some(items))
+    }
+}
+
+impl<Req, R, F> Iterator for PageIter<Req, R, F>
+where
+    Req: PaginationRequest,
+    R: Paged,
+    F: FnMut(Req) -> StdResult<R>,
+{
+    type Item = StdResult<R::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.is_empty() {
+            match self.next_page() {
+                Ok(Some(items)) => self.buffer.extend(items),
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        self.buffer.pop_front().map(Ok)
+    }
+}
+
+/// Returns an iterator over items of all pages of a custom query created by **make_request**,
+/// with [DEFAULT_PAGE_SIZE] items per page. Use [Paginator] to limit the number of items.
+pub fn paginate<'a, C, R>(
+    querier: &'a QuerierWrapper<'a, C>,
+    make_request: impl Fn(PageRequest) -> QueryRequest<C> + 'a,
+) -> PageIter<PageRequest, R, impl FnMut(PageRequest) -> StdResult<R> + 'a>
+where
+    C: CustomQuery + 'a,
+    R: Paged + DeserializeOwned,
+{
+    Paginator::default().query(querier, make_request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::dex::{
+        query::{AllPoolMetadataResponse, DexQuery},
+        types::{PairID, PoolMetadata},
+    };
+    use crate::bindings::query::{NeutronQuery, PageResponse};
+    use crate::bindings::types::{Height, RegisteredQuery};
+    use crate::interchain_queries::types::QueryType;
+    use cosmwasm_std::testing::MockQuerier;
+    use cosmwasm_std::{to_json_binary, ContractResult, StdError, SystemResult};
+
+    fn metadata(id: u64) -> PoolMetadata {
+        PoolMetadata {
+            id,
+            tick: 0,
+            fee: 1,
+            pair_id: PairID::default(),
+        }
+    }
+
+    /// Serves pool metadata with ids 0..total, next_key is the id of the next item
+    fn dex_querier(total: u64) -> MockQuerier<NeutronQuery> {
+        MockQuerier::new(&[]).with_custom_handler(move |query| {
+            let NeutronQuery::Dex(DexQuery::PoolMetadataAll {
+                pagination: Some(pagination),
+            }) = query
+            else {
+                panic!("unexpected query");
+            };
+            let start = if pagination.key.is_empty() {
+                0
+            } else {
+                u64::from_be_bytes(pagination.key.to_vec().try_into().unwrap())
+            };
+            let end = (start + pagination.limit).min(total);
+            let response = AllPoolMetadataResponse {
+                pool_metadata: (start..end).map(metadata).collect(),
+                pagination: Some(PageResponse {
+                    next_key: (end < total).then(|| Binary::new(end.to_be_bytes().to_vec())),
+                    total: None,
+                }),
+            };
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+        })
+    }
+
+    fn pool_metadata_all(pagination: PageRequest) -> QueryRequest<NeutronQuery> {
+        DexQuery::PoolMetadataAll {
+            pagination: Some(pagination),
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_paginate_custom_query() {
+        let querier = dex_querier(25);
+        let querier = QuerierWrapper::<NeutronQuery>::new(&querier);
+
+        let all: Vec<PoolMetadata> =
+            paginate::<_, AllPoolMetadataResponse>(&querier, pool_metadata_all)
+                .collect::<StdResult<_>>()
+                .unwrap();
+        assert_eq!(all, (0..25).map(metadata).collect::<Vec<_>>());
+
+        let capped: Vec<PoolMetadata> = Paginator::new(10)
+            .max_items(15)
+            .query::<_, AllPoolMetadataResponse>(&querier, pool_metadata_all)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(capped, (0..15).map(metadata).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_collect_pages() {
+        let querier = dex_querier(25);
+        let querier = QuerierWrapper::<NeutronQuery>::new(&querier);
+        let fetch =
+            |pagination| querier.query::<AllPoolMetadataResponse>(&pool_metadata_all(pagination));
+
+        let first = Paginator::new(10).collect_pages(2, fetch).unwrap();
+        assert_eq!(first.items.len(), 20);
+        assert_eq!(
+            first.next,
+            Some(PageCursor::Key(Binary::new(20u64.to_be_bytes().to_vec())))
+        );
+
+        let rest = Paginator::new(10)
+            .start_from(first.next)
+            .collect_pages(2, fetch)
+            .unwrap();
+        assert_eq!(rest.items, (20..25).map(metadata).collect::<Vec<_>>());
+        assert_eq!(rest.next, None);
+    }
+
+    #[test]
+    fn test_paginate_by_offset() {
+        // the custom query of registered queries is paginated by offset
+        let mut requests = vec![];
+        let items = Paginator::new(2)
+            .collect(|pagination: PageRequest| {
+                requests.push(pagination.offset);
+                let end = (pagination.offset + pagination.limit).min(5);
+                Ok(QueryRegisteredQueriesResponse {
+                    registered_queries: (pagination.offset..end)
+                        .map(|id| RegisteredQuery {
+                            id,
+                            owner: String::new(),
+                            keys: vec![],
+                            query_type: QueryType::KV,
+                            transactions_filter: String::new(),
+                            connection_id: String::new(),
+                            update_period: 1,
+                            last_submitted_result_local_height: 0,
+                            last_submitted_result_remote_height: Height {
+                                revision_number: 0,
+                                revision_height: 0,
+                            },
+                            deposit: vec![],
+                            submit_timeout: 0,
+                            registered_at_height: 0,
+                        })
+                        .collect(),
+                })
+            })
+            .unwrap();
+        assert_eq!(items.len(), 5);
+        assert_eq!(requests, vec![0, 2, 4]);
+
+        let err = Paginator::default()
+            .collect(|_: Option<proto_query::PageRequest>| {
+                Err::<bank::QueryAllBalancesResponse, _>(StdError::generic_err("query failed"))
+            })
+            .unwrap_err();
+        assert_eq!(err, StdError::generic_err("query failed"));
+    }
+}
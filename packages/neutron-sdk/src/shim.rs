@@ -1,6 +1,6 @@
 use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
 use chrono::{DateTime, Utc};
-use cosmwasm_std::StdResult;
+use cosmwasm_std::{Env, StdError, StdResult};
 use serde::de;
 use serde::de::Visitor;
 
@@ -312,6 +312,183 @@ impl_prost_types_exact_conversion! { Timestamp | seconds, nanos }
 impl_prost_types_exact_conversion! { Duration | seconds, nanos }
 impl_prost_types_exact_conversion! { Any | type_url, value }
 
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+impl Timestamp {
+    /// Creates a timestamp from nanoseconds since Unix epoch, **nanos** of the result is non-negative
+    pub fn from_unix_nanos(nanos: i128) -> StdResult<Self> {
+        Ok(Timestamp {
+            seconds: i64::try_from(nanos.div_euclid(NANOS_PER_SECOND))
+                .map_err(|_| StdError::generic_err("timestamp overflow"))?,
+            nanos: nanos.rem_euclid(NANOS_PER_SECOND) as i32,
+        })
+    }
+
+    /// Returns nanoseconds since Unix epoch
+    pub fn unix_nanos(&self) -> i128 {
+        self.seconds as i128 * NANOS_PER_SECOND + self.nanos as i128
+    }
+
+    pub fn checked_add(&self, duration: &Duration) -> StdResult<Self> {
+        Timestamp::from_unix_nanos(self.unix_nanos() + duration.total_nanos())
+    }
+
+    pub fn checked_sub(&self, duration: &Duration) -> StdResult<Self> {
+        Timestamp::from_unix_nanos(self.unix_nanos() - duration.total_nanos())
+    }
+
+    /// Returns the duration from **earlier** to this timestamp, negative if **earlier** is later
+    pub fn duration_since(&self, earlier: &Timestamp) -> StdResult<Duration> {
+        Duration::from_nanos(self.unix_nanos() - earlier.unix_nanos())
+    }
+}
+
+impl Duration {
+    pub const fn from_seconds(seconds: i64) -> Self {
+        Duration { seconds, nanos: 0 }
+    }
+
+    /// Creates a duration from signed nanoseconds, **nanos** of the result has the sign of **seconds**
+    pub fn from_nanos(nanos: i128) -> StdResult<Self> {
+        Ok(Duration {
+            seconds: i64::try_from(nanos / NANOS_PER_SECOND)
+                .map_err(|_| StdError::generic_err("duration overflow"))?,
+            nanos: (nanos % NANOS_PER_SECOND) as i32,
+        })
+    }
+
+    /// Returns the signed length of the duration in nanoseconds
+    pub fn total_nanos(&self) -> i128 {
+        self.seconds as i128 * NANOS_PER_SECOND + self.nanos as i128
+    }
+
+    pub fn checked_add(&self, other: &Duration) -> StdResult<Self> {
+        Duration::from_nanos(self.total_nanos() + other.total_nanos())
+    }
+
+    pub fn checked_sub(&self, other: &Duration) -> StdResult<Self> {
+        Duration::from_nanos(self.total_nanos() - other.total_nanos())
+    }
+}
+
+impl From<cosmwasm_std::Timestamp> for Timestamp {
+    fn from(ts: cosmwasm_std::Timestamp) -> Self {
+        Timestamp {
+            seconds: ts.seconds() as i64,
+            nanos: ts.subsec_nanos() as i32,
+        }
+    }
+}
+
+impl TryFrom<Timestamp> for cosmwasm_std::Timestamp {
+    type Error = StdError;
+
+    fn try_from(ts: Timestamp) -> StdResult<Self> {
+        u64::try_from(ts.unix_nanos())
+            .map(cosmwasm_std::Timestamp::from_nanos)
+            .map_err(|_| {
+                StdError::generic_err(format!(
+                    "timestamp {}s {}ns is out of the cosmwasm timestamp range",
+                    ts.seconds, ts.nanos
+                ))
+            })
+    }
+}
+
+impl TryFrom<Timestamp> for DateTime<Utc> {
+    type Error = StdError;
+
+    fn try_from(ts: Timestamp) -> StdResult<Self> {
+        let ts = Timestamp::from_unix_nanos(ts.unix_nanos())?;
+        DateTime::from_timestamp(ts.seconds, ts.nanos as u32).ok_or_else(|| {
+            StdError::generic_err(format!(
+                "timestamp {}s {}ns is out of the datetime range",
+                ts.seconds, ts.nanos
+            ))
+        })
+    }
+}
+
+impl TryFrom<std::time::Duration> for Duration {
+    type Error = StdError;
+
+    fn try_from(d: std::time::Duration) -> StdResult<Self> {
+        Duration::from_nanos(d.as_nanos() as i128)
+    }
+}
+
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = StdError;
+
+    fn try_from(d: Duration) -> StdResult<Self> {
+        let nanos = d.total_nanos();
+        if nanos < 0 {
+            return Err(StdError::generic_err(format!(
+                "duration {}s {}ns is negative",
+                d.seconds, d.nanos
+            )));
+        }
+        Ok(std::time::Duration::new(
+            (nanos / NANOS_PER_SECOND) as u64,
+            (nanos % NANOS_PER_SECOND) as u32,
+        ))
+    }
+}
+
+impl From<chrono::Duration> for Duration {
+    fn from(d: chrono::Duration) -> Self {
+        Duration {
+            seconds: d.num_seconds(),
+            nanos: d.subsec_nanos(),
+        }
+    }
+}
+
+impl TryFrom<Duration> for chrono::Duration {
+    type Error = StdError;
+
+    fn try_from(d: Duration) -> StdResult<Self> {
+        let nanos = d.total_nanos();
+        i64::try_from(nanos.div_euclid(NANOS_PER_SECOND))
+            .ok()
+            .and_then(|seconds| {
+                chrono::Duration::new(seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32)
+            })
+            .ok_or_else(|| {
+                StdError::generic_err(format!(
+                    "duration {}s {}ns is out of the chrono duration range",
+                    d.seconds, d.nanos
+                ))
+            })
+    }
+}
+
+/// Returns the block time plus the **timeout** in nanoseconds, to be used as the `timeout_timestamp`
+/// of [crate::bindings::msg::NeutronMsg::IbcTransfer]
+pub fn timeout_timestamp(env: &Env, timeout: &Duration) -> StdResult<u64> {
+    let timeout = Timestamp::from(env.block.time).checked_add(timeout)?;
+    Ok(cosmwasm_std::Timestamp::try_from(timeout)?.nanos())
+}
+
+/// Returns the **timeout** in seconds, to be used as the `timeout` of
+/// [crate::bindings::msg::NeutronMsg::SubmitTx]. Fails if the **timeout** is negative or isn't
+/// a whole number of seconds, since the fraction of a second would be silently dropped.
+pub fn timeout_seconds(timeout: &Duration) -> StdResult<u64> {
+    if timeout.seconds < 0 || timeout.nanos < 0 {
+        return Err(StdError::generic_err(format!(
+            "timeout {}s {}ns is negative",
+            timeout.seconds, timeout.nanos
+        )));
+    }
+    if timeout.nanos != 0 {
+        return Err(StdError::generic_err(format!(
+            "timeout {}s {}ns is not a whole number of seconds",
+            timeout.seconds, timeout.nanos
+        )));
+    }
+    Ok(timeout.seconds as u64)
+}
+
 impl From<cosmwasm_std::Coin> for crate::proto_types::cosmos::base::v1beta1::Coin {
     fn from(cosmwasm_std::Coin { denom, amount }: cosmwasm_std::Coin) -> Self {
         crate::proto_types::cosmos::base::v1beta1::Coin {
@@ -372,4 +549,85 @@ mod tests {
 
         assert_eq!(coins, cosmwasm_coins);
     }
+
+    #[test]
+    fn test_timestamp_conversion() {
+        let ts = cosmwasm_std::Timestamp::from_nanos(1_571_797_419_879_305_533);
+        let proto_ts = Timestamp::from(ts);
+        assert_eq!(proto_ts.seconds, 1_571_797_419);
+        assert_eq!(proto_ts.nanos, 879_305_533);
+        assert_eq!(cosmwasm_std::Timestamp::try_from(proto_ts.clone()), Ok(ts));
+        assert_eq!(
+            DateTime::<Utc>::try_from(proto_ts.clone()).map(Timestamp::from),
+            Ok(proto_ts.clone())
+        );
+
+        let hour = Duration::from_seconds(3600);
+        let later = proto_ts.checked_add(&hour).unwrap();
+        assert_eq!(later.duration_since(&proto_ts), Ok(hour.clone()));
+        assert_eq!(later.checked_sub(&hour), Ok(proto_ts.clone()));
+
+        let before_epoch = Timestamp {
+            seconds: 0,
+            nanos: 0,
+        }
+        .checked_sub(&Duration::from_nanos(1).unwrap())
+        .unwrap();
+        assert_eq!(before_epoch.seconds, -1);
+        assert_eq!(before_epoch.nanos, 999_999_999);
+        assert!(cosmwasm_std::Timestamp::try_from(before_epoch).is_err());
+
+        let env = cosmwasm_std::testing::mock_env();
+        assert_eq!(
+            timeout_timestamp(&env, &hour),
+            Ok(env.block.time.plus_hours(1).nanos())
+        );
+    }
+
+    #[test]
+    fn test_timeout_seconds() {
+        assert_eq!(timeout_seconds(&Duration::from_seconds(3600)), Ok(3600));
+        assert_eq!(timeout_seconds(&Duration::from_seconds(0)), Ok(0));
+        assert_eq!(
+            timeout_seconds(&Duration::from_seconds(-1)),
+            Err(StdError::generic_err("timeout -1s 0ns is negative"))
+        );
+        assert_eq!(
+            timeout_seconds(&Duration::from_nanos(-500_000_000).unwrap()),
+            Err(StdError::generic_err("timeout 0s -500000000ns is negative"))
+        );
+        assert_eq!(
+            timeout_seconds(&Duration::from_nanos(500_000_000).unwrap()),
+            Err(StdError::generic_err(
+                "timeout 0s 500000000ns is not a whole number of seconds"
+            ))
+        );
+        assert_eq!(
+            timeout_seconds(&Duration::from_nanos(1_500_000_000).unwrap()),
+            Err(StdError::generic_err(
+                "timeout 1s 500000000ns is not a whole number of seconds"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_duration_conversion() {
+        let d = Duration::from_nanos(-1_500_000_000).unwrap();
+        assert_eq!((d.seconds, d.nanos), (-1, -500_000_000));
+        assert!(std::time::Duration::try_from(d.clone()).is_err());
+        assert_eq!(
+            chrono::Duration::try_from(d.clone()),
+            Ok(chrono::Duration::milliseconds(-1500))
+        );
+        assert_eq!(Duration::from(chrono::Duration::milliseconds(-1500)), d);
+
+        let std_d = std::time::Duration::from_millis(2500);
+        let proto_d = Duration::try_from(std_d).unwrap();
+        assert_eq!(proto_d.checked_add(&d).unwrap(), Duration::from_seconds(1));
+        assert_eq!(std::time::Duration::try_from(proto_d), Ok(std_d));
+
+        assert!(Duration::from_seconds(i64::MAX)
+            .checked_add(&Duration::from_seconds(1))
+            .is_err());
+    }
 }
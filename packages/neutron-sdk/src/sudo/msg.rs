@@ -1,5 +1,5 @@
 use crate::bindings::types::Height;
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Binary, Coin};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -69,3 +69,22 @@ pub enum TransferSudoMsg {
         request: RequestPacket,
     },
 }
+
+/// BeforeSendSudoMsg is a sudo payload the token factory sends to the before send hook
+/// contract of a denom, see [crate::bindings::msg::NeutronMsg::SetBeforeSendHook].
+/// An error returned from **BlockBeforeSend** blocks the transfer,
+/// an error returned from **TrackBeforeSend** is ignored by the module.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BeforeSendSudoMsg {
+    BlockBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+    TrackBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+}
@@ -0,0 +1,366 @@
+use crate::{
+    errors::error::{NeutronError, NeutronResult},
+    interchain_txs::helpers::decode_message_response,
+    proto_types::neutron::{interchaintxs::v1::MsgSubmitTxResponse, transfer::MsgTransferResponse},
+    sudo::msg::{RequestPacket, SudoMsg},
+};
+use cosmwasm_std::{CosmosMsg, Order, Reply, StdError, StdResult, Storage, SubMsg};
+use cw_storage_plus::Map;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Number of reply ids reserved by a store, i.e. how many messages with sudo callbacks can be sent in a single transaction
+pub const SUDO_PAYLOAD_REPLY_ID_RANGE_SIZE: u64 = 1_000;
+
+/// Attaches contract defined payloads to **SubmitTx** and **IbcTransfer** messages and returns
+/// them back when the packet acknowledgement, error or timeout arrives in the sudo handler.
+///
+/// The flow is [SudoPayloadStore::with_sudo_callback] -> reply() -> [SudoPayloadStore::handle_reply] -> sudo() -> [SudoPayloadStore::take_for_sudo].
+/// Every submessage gets its own reply id from the store range, so one execution may send
+/// several messages with different payloads.
+///
+/// ```rust ignore
+/// const SUDO_PAYLOADS: SudoPayloadStore<SudoPayload> =
+///     SudoPayloadStore::new("reply_payloads", "sudo_payloads", 1_000_000);
+///
+/// // execute
+/// let submsg = SUDO_PAYLOADS.with_sudo_callback(deps.storage, submit_tx_msg, &payload)?;
+///
+/// // reply
+/// if SUDO_PAYLOADS.is_reply(msg.id) {
+///     SUDO_PAYLOADS.handle_reply(deps.storage, msg)?;
+/// }
+///
+/// // sudo
+/// let payload = SUDO_PAYLOADS.take_for_sudo(deps.storage, &msg)?;
+/// ```
+pub struct SudoPayloadStore<T> {
+    reply_payloads: Map<u64, T>,
+    sudo_payloads: Map<(String, u64), T>,
+    reply_id_start: u64,
+}
+
+impl<T: Serialize + DeserializeOwned> SudoPayloadStore<T> {
+    /// Creates a store under the given namespaces. Reply ids from **reply_id_start**
+    /// to **reply_id_start** + [SUDO_PAYLOAD_REPLY_ID_RANGE_SIZE] are reserved by the store.
+    pub const fn new(
+        reply_namespace: &'static str,
+        sudo_namespace: &'static str,
+        reply_id_start: u64,
+    ) -> Self {
+        SudoPayloadStore {
+            reply_payloads: Map::new(reply_namespace),
+            sudo_payloads: Map::new(sudo_namespace),
+            reply_id_start,
+        }
+    }
+
+    /// Saves the **payload** to process later and wraps the **msg** into a submessage with a reply id of the store
+    pub fn with_sudo_callback<C>(
+        &self,
+        storage: &mut dyn Storage,
+        msg: impl Into<CosmosMsg<C>>,
+        payload: &T,
+    ) -> NeutronResult<SubMsg<C>> {
+        let reply_id = self.next_reply_id(storage)?;
+        self.reply_payloads.save(storage, reply_id, payload)?;
+
+        Ok(SubMsg::reply_on_success(msg, reply_id))
+    }
+
+    /// Checks whether the reply **id** belongs to the store
+    pub fn is_reply(&self, id: u64) -> bool {
+        (self.reply_id_start..self.reply_id_start + SUDO_PAYLOAD_REPLY_ID_RANGE_SIZE).contains(&id)
+    }
+
+    /// Extracts channel and sequence id of the sent packet from **MsgSubmitTxResponse** or
+    /// **MsgTransferResponse** and binds the payload of the reply to them.
+    /// Returns the channel and sequence id.
+    pub fn handle_reply(
+        &self,
+        storage: &mut dyn Storage,
+        reply: Reply,
+    ) -> NeutronResult<(String, u64)> {
+        let payload = self.reply_payloads.load(storage, reply.id)?;
+
+        // msg_responses must have exactly one Msg response: https://github.com/neutron-org/neutron/blob/28b1d2ce968aaf1866e92d5286487f079eba3370/wasmbinding/message_plugin.go#L443
+        let response = reply.result.into_result().map_err(StdError::generic_err)?;
+        let msg_response = response
+            .msg_responses
+            .first()
+            .ok_or_else(|| StdError::generic_err("no msg response in reply"))?;
+
+        let (channel_id, seq_id) = match msg_response.type_url.as_str() {
+            MsgSubmitTxResponse::TYPE_URL => {
+                let resp: MsgSubmitTxResponse =
+                    decode_message_response(&msg_response.value.to_vec())?;
+                (resp.channel, resp.sequence_id)
+            }
+            MsgTransferResponse::TYPE_URL => {
+                let resp: MsgTransferResponse =
+                    decode_message_response(&msg_response.value.to_vec())?;
+                (resp.channel, resp.sequence_id)
+            }
+            type_url => {
+                return Err(NeutronError::Std(StdError::generic_err(format!(
+                    "unexpected msg response type in reply: {}",
+                    type_url
+                ))))
+            }
+        };
+
+        self.reply_payloads.remove(storage, reply.id);
+        self.sudo_payloads
+            .save(storage, (channel_id.clone(), seq_id), &payload)?;
+
+        Ok((channel_id, seq_id))
+    }
+
+    /// Saves the **payload** of the packet with **seq_id** sent from **channel_id**,
+    /// e.g. to move payloads saved by an older contract version to the store
+    pub fn save(
+        &self,
+        storage: &mut dyn Storage,
+        channel_id: String,
+        seq_id: u64,
+        payload: &T,
+    ) -> StdResult<()> {
+        self.sudo_payloads
+            .save(storage, (channel_id, seq_id), payload)
+    }
+
+    /// Returns the payload of the packet with **seq_id** sent from **channel_id**
+    pub fn load(&self, storage: &dyn Storage, channel_id: String, seq_id: u64) -> StdResult<T> {
+        self.sudo_payloads.load(storage, (channel_id, seq_id))
+    }
+
+    /// Returns and removes the payload of the packet with **seq_id** sent from **channel_id**
+    pub fn take(
+        &self,
+        storage: &mut dyn Storage,
+        channel_id: String,
+        seq_id: u64,
+    ) -> StdResult<Option<T>> {
+        let key = (channel_id, seq_id);
+        let payload = self.sudo_payloads.may_load(storage, key.clone())?;
+        self.sudo_payloads.remove(storage, key);
+
+        Ok(payload)
+    }
+
+    /// Returns and removes the payload of the **request** packet
+    pub fn take_for_request(
+        &self,
+        storage: &mut dyn Storage,
+        request: &RequestPacket,
+    ) -> NeutronResult<Option<T>> {
+        // not having the sequence id or channel in the request value implies that a fatal error occurred on Neutron side
+        let seq_id = request
+            .sequence
+            .ok_or_else(|| StdError::generic_err("sequence not found"))?;
+        let channel_id = request
+            .source_channel
+            .clone()
+            .ok_or_else(|| StdError::generic_err("channel_id not found"))?;
+
+        Ok(self.take(storage, channel_id, seq_id)?)
+    }
+
+    /// Returns and removes the payload of the packet of **SudoMsg::Response**, **SudoMsg::Error**
+    /// or **SudoMsg::Timeout**. Returns **None** for other sudo messages.
+    pub fn take_for_sudo(
+        &self,
+        storage: &mut dyn Storage,
+        msg: &SudoMsg,
+    ) -> NeutronResult<Option<T>> {
+        match msg {
+            SudoMsg::Response { request, .. }
+            | SudoMsg::Error { request, .. }
+            | SudoMsg::Timeout { request } => self.take_for_request(storage, request),
+            _ => Ok(None),
+        }
+    }
+
+    fn next_reply_id(&self, storage: &dyn Storage) -> NeutronResult<u64> {
+        let last = self
+            .reply_payloads
+            .keys(storage, None, None, Order::Descending)
+            .next()
+            .transpose()?;
+        let id = last.map_or(self.reply_id_start, |id| id + 1);
+
+        if !self.is_reply(id) {
+            return Err(NeutronError::Std(StdError::generic_err(
+                "too many messages with sudo callbacks in a single transaction",
+            )));
+        }
+
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::msg::{IbcFee, NeutronMsg};
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{Binary, MsgResponse, SubMsgResponse, SubMsgResult};
+    use prost::Message;
+
+    const STORE: SudoPayloadStore<String> = SudoPayloadStore::new("reply", "sudo", 100);
+
+    fn submit_tx_msg() -> NeutronMsg {
+        NeutronMsg::submit_tx(
+            "connection-0".to_string(),
+            "test".to_string(),
+            vec![],
+            "".to_string(),
+            100,
+            IbcFee {
+                recv_fee: vec![],
+                ack_fee: vec![],
+                timeout_fee: vec![],
+            },
+        )
+    }
+
+    fn reply(id: u64, type_url: &str, value: Vec<u8>) -> Reply {
+        #[allow(deprecated)]
+        let response = SubMsgResponse {
+            events: vec![],
+            data: None,
+            msg_responses: vec![MsgResponse {
+                type_url: type_url.to_string(),
+                value: Binary::new(value),
+            }],
+        };
+        Reply {
+            id,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: SubMsgResult::Ok(response),
+        }
+    }
+
+    fn request(channel_id: &str, seq_id: u64) -> RequestPacket {
+        RequestPacket {
+            sequence: Some(seq_id),
+            source_port: None,
+            source_channel: Some(channel_id.to_string()),
+            destination_port: None,
+            destination_channel: None,
+            data: None,
+            timeout_height: None,
+            timeout_timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_multiple_in_flight_payloads() {
+        let mut storage = MockStorage::default();
+
+        let first = STORE
+            .with_sudo_callback::<NeutronMsg>(&mut storage, submit_tx_msg(), &"first".to_string())
+            .unwrap();
+        let second = STORE
+            .with_sudo_callback::<NeutronMsg>(&mut storage, submit_tx_msg(), &"second".to_string())
+            .unwrap();
+        assert_eq!((first.id, second.id), (100, 101));
+        assert!(STORE.is_reply(second.id));
+        assert!(!STORE.is_reply(99));
+
+        let submit_tx_response = MsgSubmitTxResponse {
+            sequence_id: 7,
+            channel: "channel-1".to_string(),
+        };
+        assert_eq!(
+            STORE
+                .handle_reply(
+                    &mut storage,
+                    reply(
+                        first.id,
+                        MsgSubmitTxResponse::TYPE_URL,
+                        submit_tx_response.encode_to_vec()
+                    )
+                )
+                .unwrap(),
+            ("channel-1".to_string(), 7)
+        );
+
+        let transfer_response = MsgTransferResponse {
+            sequence_id: 8,
+            channel: "channel-0".to_string(),
+        };
+        assert_eq!(
+            STORE
+                .handle_reply(
+                    &mut storage,
+                    reply(
+                        second.id,
+                        MsgTransferResponse::TYPE_URL,
+                        transfer_response.encode_to_vec()
+                    )
+                )
+                .unwrap(),
+            ("channel-0".to_string(), 8)
+        );
+
+        assert_eq!(
+            STORE.load(&storage, "channel-1".to_string(), 7).unwrap(),
+            "first"
+        );
+
+        let ack = SudoMsg::Response {
+            request: request("channel-1", 7),
+            data: Binary::default(),
+        };
+        assert_eq!(
+            STORE.take_for_sudo(&mut storage, &ack).unwrap(),
+            Some("first".to_string())
+        );
+        // the payload is removed once it's taken
+        assert_eq!(STORE.take_for_sudo(&mut storage, &ack).unwrap(), None);
+
+        let timeout = SudoMsg::Timeout {
+            request: request("channel-0", 8),
+        };
+        assert_eq!(
+            STORE.take_for_sudo(&mut storage, &timeout).unwrap(),
+            Some("second".to_string())
+        );
+
+        assert_eq!(
+            STORE
+                .take_for_sudo(&mut storage, &SudoMsg::KVQueryResult { query_id: 1 })
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unexpected_reply() {
+        let mut storage = MockStorage::default();
+
+        let submsg = STORE
+            .with_sudo_callback::<NeutronMsg>(&mut storage, submit_tx_msg(), &"payload".to_string())
+            .unwrap();
+
+        assert_eq!(
+            STORE
+                .handle_reply(
+                    &mut storage,
+                    reply(submsg.id, "/cosmos.bank.v1beta1.MsgSendResponse", vec![])
+                )
+                .unwrap_err(),
+            NeutronError::Std(StdError::generic_err(
+                "unexpected msg response type in reply: /cosmos.bank.v1beta1.MsgSendResponse"
+            ))
+        );
+
+        let mut request = request("channel-0", 1);
+        request.sequence = None;
+        assert_eq!(
+            STORE.take_for_request(&mut storage, &request).unwrap_err(),
+            NeutronError::Std(StdError::generic_err("sequence not found"))
+        );
+    }
+}
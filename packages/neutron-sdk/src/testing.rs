@@ -0,0 +1,17 @@
+use crate::bindings::query::NeutronQuery;
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::OwnedDeps;
+use std::marker::PhantomData;
+
+pub(crate) type NeutronDeps =
+    OwnedDeps<MockStorage, MockApi, MockQuerier<NeutronQuery>, NeutronQuery>;
+
+/// Builds mock dependencies for the `NeutronQuery` custom query type around the given **querier**.
+pub(crate) fn mock_neutron_deps(querier: MockQuerier<NeutronQuery>) -> NeutronDeps {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier,
+        custom_query_type: PhantomData,
+    }
+}
@@ -0,0 +1,490 @@
+use crate::{
+    errors::error::{NeutronError, NeutronResult},
+    sudo::msg::BeforeSendSudoMsg,
+};
+use cosmwasm_std::{Coin, Empty, Env, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+/// Transfer of a token factory denom the before send hook is called for
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transfer {
+    pub from: String,
+    pub to: String,
+    pub amount: Coin,
+}
+
+/// Rule checked for every transfer of a denom with the contract set as its before send hook.
+///
+/// Policies may keep their own state, e.g. to count transferred amounts. Note that mints and
+/// burns are transfers from and to the token factory module account, so they are checked as well.
+pub trait BeforeSendPolicy {
+    /// Called on **block_before_send**, an error blocks the transfer
+    fn check(&self, storage: &mut dyn Storage, env: &Env, transfer: &Transfer)
+        -> NeutronResult<()>;
+
+    /// Called on **track_before_send**, an error is ignored by the token factory module
+    fn track(
+        &self,
+        _storage: &mut dyn Storage,
+        _env: &Env,
+        _transfer: &Transfer,
+    ) -> NeutronResult<()> {
+        Ok(())
+    }
+}
+
+impl<T: BeforeSendPolicy + ?Sized> BeforeSendPolicy for &T {
+    fn check(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        transfer: &Transfer,
+    ) -> NeutronResult<()> {
+        (**self).check(storage, env, transfer)
+    }
+
+    fn track(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        transfer: &Transfer,
+    ) -> NeutronResult<()> {
+        (**self).track(storage, env, transfer)
+    }
+}
+
+/// Applies policies to the before send hook calls in the order they are added,
+/// the first failing policy blocks the transfer.
+///
+/// ```rust ignore
+/// const ALLOWLIST: AddressList = AddressList::allowlist("allowlist");
+/// const FREEZE: FreezePolicy = FreezePolicy::new("frozen", "frozen_all");
+///
+/// #[entry_point]
+/// pub fn sudo(deps: DepsMut, env: Env, msg: BeforeSendSudoMsg) -> NeutronResult<Response> {
+///     BeforeSendHooks::new()
+///         .with(&FREEZE)
+///         .with(&ALLOWLIST)
+///         .handle(deps.storage, &env, msg)?;
+///     Ok(Response::default())
+/// }
+/// ```
+#[derive(Default)]
+pub struct BeforeSendHooks<'a> {
+    policies: Vec<Box<dyn BeforeSendPolicy + 'a>>,
+}
+
+impl<'a> BeforeSendHooks<'a> {
+    pub fn new() -> Self {
+        BeforeSendHooks::default()
+    }
+
+    /// Adds the **policy** after the already added ones
+    pub fn with(mut self, policy: impl BeforeSendPolicy + 'a) -> Self {
+        self.policies.push(Box::new(policy));
+        self
+    }
+
+    /// Runs the policies for the sudo message
+    pub fn handle(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        msg: BeforeSendSudoMsg,
+    ) -> NeutronResult<()> {
+        match msg {
+            BeforeSendSudoMsg::BlockBeforeSend { from, to, amount } => {
+                let transfer = Transfer { from, to, amount };
+                self.policies
+                    .iter()
+                    .try_for_each(|policy| policy.check(storage, env, &transfer))
+            }
+            BeforeSendSudoMsg::TrackBeforeSend { from, to, amount } => {
+                let transfer = Transfer { from, to, amount };
+                self.policies
+                    .iter()
+                    .try_for_each(|policy| policy.track(storage, env, &transfer))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListMode {
+    /// Only transfers where both parties are in the list are allowed
+    Allow,
+    /// Transfers where any party is in the list are blocked
+    Deny,
+}
+
+/// Allowlist or denylist of addresses
+pub struct AddressList {
+    addresses: Map<String, Empty>,
+    mode: ListMode,
+}
+
+impl AddressList {
+    pub const fn new(namespace: &'static str, mode: ListMode) -> Self {
+        AddressList {
+            addresses: Map::new(namespace),
+            mode,
+        }
+    }
+
+    pub const fn allowlist(namespace: &'static str) -> Self {
+        AddressList::new(namespace, ListMode::Allow)
+    }
+
+    pub const fn denylist(namespace: &'static str) -> Self {
+        AddressList::new(namespace, ListMode::Deny)
+    }
+
+    pub fn add(&self, storage: &mut dyn Storage, address: impl Into<String>) -> StdResult<()> {
+        self.addresses.save(storage, address.into(), &Empty {})
+    }
+
+    pub fn remove(&self, storage: &mut dyn Storage, address: impl Into<String>) {
+        self.addresses.remove(storage, address.into())
+    }
+
+    pub fn contains(&self, storage: &dyn Storage, address: &str) -> bool {
+        self.addresses.has(storage, address.to_string())
+    }
+}
+
+impl BeforeSendPolicy for AddressList {
+    fn check(
+        &self,
+        storage: &mut dyn Storage,
+        _env: &Env,
+        transfer: &Transfer,
+    ) -> NeutronResult<()> {
+        for address in [&transfer.from, &transfer.to] {
+            let listed = self.contains(storage, address);
+            let allowed = match self.mode {
+                ListMode::Allow => listed,
+                ListMode::Deny => !listed,
+            };
+            if !allowed {
+                return Err(NeutronError::TransferNotAllowed {
+                    address: address.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Blocks transfers from frozen addresses or all transfers when the denom is frozen globally
+pub struct FreezePolicy {
+    frozen: Map<String, Empty>,
+    frozen_all: Item<bool>,
+}
+
+impl FreezePolicy {
+    pub const fn new(frozen_namespace: &'static str, frozen_all_namespace: &'static str) -> Self {
+        FreezePolicy {
+            frozen: Map::new(frozen_namespace),
+            frozen_all: Item::new(frozen_all_namespace),
+        }
+    }
+
+    pub fn freeze(&self, storage: &mut dyn Storage, address: impl Into<String>) -> StdResult<()> {
+        self.frozen.save(storage, address.into(), &Empty {})
+    }
+
+    pub fn unfreeze(&self, storage: &mut dyn Storage, address: impl Into<String>) {
+        self.frozen.remove(storage, address.into())
+    }
+
+    /// Freezes or unfreezes all transfers
+    pub fn freeze_all(&self, storage: &mut dyn Storage, frozen: bool) -> StdResult<()> {
+        self.frozen_all.save(storage, &frozen)
+    }
+
+    pub fn is_frozen(&self, storage: &dyn Storage, address: &str) -> StdResult<bool> {
+        Ok(self.frozen_all.may_load(storage)?.unwrap_or_default()
+            || self.frozen.has(storage, address.to_string()))
+    }
+}
+
+impl BeforeSendPolicy for FreezePolicy {
+    fn check(
+        &self,
+        storage: &mut dyn Storage,
+        _env: &Env,
+        transfer: &Transfer,
+    ) -> NeutronResult<()> {
+        if self.is_frozen(storage, &transfer.from)? {
+            return Err(NeutronError::AddressFrozen {
+                address: transfer.from.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Limits the amount an address can send within a period of **period** seconds.
+/// Addresses without a cap are not limited, amounts are counted per denom.
+pub struct TransferCapPolicy {
+    caps: Map<String, Uint128>,
+    sent: Map<(String, String), (u64, Uint128)>,
+    period: u64,
+}
+
+impl TransferCapPolicy {
+    pub const fn new(
+        caps_namespace: &'static str,
+        sent_namespace: &'static str,
+        period: u64,
+    ) -> Self {
+        TransferCapPolicy {
+            caps: Map::new(caps_namespace),
+            sent: Map::new(sent_namespace),
+            period,
+        }
+    }
+
+    /// Sets the cap of the **address**, `None` removes the cap
+    pub fn set_cap(
+        &self,
+        storage: &mut dyn Storage,
+        address: impl Into<String>,
+        cap: Option<Uint128>,
+    ) -> StdResult<()> {
+        match cap {
+            Some(cap) => self.caps.save(storage, address.into(), &cap),
+            None => {
+                self.caps.remove(storage, address.into());
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the amount of **denom** sent by the **address** in the current period
+    pub fn sent(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        address: &str,
+        denom: &str,
+    ) -> StdResult<Uint128> {
+        let sent = self
+            .sent
+            .may_load(storage, (address.to_string(), denom.to_string()))?;
+        Ok(match sent {
+            Some((period, amount)) if period == self.current_period(env) => amount,
+            _ => Uint128::zero(),
+        })
+    }
+
+    fn current_period(&self, env: &Env) -> u64 {
+        env.block.time.seconds() / self.period.max(1)
+    }
+}
+
+impl BeforeSendPolicy for TransferCapPolicy {
+    fn check(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        transfer: &Transfer,
+    ) -> NeutronResult<()> {
+        let Some(cap) = self.caps.may_load(storage, transfer.from.clone())? else {
+            return Ok(());
+        };
+
+        let sent = self
+            .sent(storage, env, &transfer.from, &transfer.amount.denom)?
+            .checked_add(transfer.amount.amount)?;
+        if sent > cap {
+            return Err(NeutronError::TransferCapExceeded {
+                address: transfer.from.clone(),
+                cap,
+                sent,
+            });
+        }
+
+        self.sent.save(
+            storage,
+            (transfer.from.clone(), transfer.amount.denom.clone()),
+            &(self.current_period(env), sent),
+        )?;
+        Ok(())
+    }
+}
+
+/// Blocks transfers from an address until its unlock time
+pub struct TimeLockPolicy {
+    locks: Map<String, u64>,
+}
+
+impl TimeLockPolicy {
+    pub const fn new(namespace: &'static str) -> Self {
+        TimeLockPolicy {
+            locks: Map::new(namespace),
+        }
+    }
+
+    /// Locks the **address** until **unlock_time** in seconds
+    pub fn lock(
+        &self,
+        storage: &mut dyn Storage,
+        address: impl Into<String>,
+        unlock_time: u64,
+    ) -> StdResult<()> {
+        self.locks.save(storage, address.into(), &unlock_time)
+    }
+
+    pub fn unlock(&self, storage: &mut dyn Storage, address: impl Into<String>) {
+        self.locks.remove(storage, address.into())
+    }
+
+    /// Returns the unlock time of the **address** if it's still locked
+    pub fn locked_until(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        address: &str,
+    ) -> StdResult<Option<u64>> {
+        Ok(self
+            .locks
+            .may_load(storage, address.to_string())?
+            .filter(|unlock_time| *unlock_time > env.block.time.seconds()))
+    }
+}
+
+impl BeforeSendPolicy for TimeLockPolicy {
+    fn check(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        transfer: &Transfer,
+    ) -> NeutronResult<()> {
+        if let Some(unlock_time) = self.locked_until(storage, env, &transfer.from)? {
+            return Err(NeutronError::AddressLocked {
+                address: transfer.from.clone(),
+                unlock_time,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+    use cosmwasm_std::{coin, from_json, Timestamp};
+
+    const ALLOWLIST: AddressList = AddressList::allowlist("allowlist");
+    const DENYLIST: AddressList = AddressList::denylist("denylist");
+    const FREEZE: FreezePolicy = FreezePolicy::new("frozen", "frozen_all");
+    const CAPS: TransferCapPolicy = TransferCapPolicy::new("caps", "sent", 100);
+    const LOCKS: TimeLockPolicy = TimeLockPolicy::new("locks");
+
+    fn block(from: &str, to: &str, amount: u128) -> BeforeSendSudoMsg {
+        BeforeSendSudoMsg::BlockBeforeSend {
+            from: from.to_string(),
+            to: to.to_string(),
+            amount: coin(amount, "factory/contract/utoken"),
+        }
+    }
+
+    fn hooks() -> BeforeSendHooks<'static> {
+        BeforeSendHooks::new()
+            .with(FREEZE)
+            .with(LOCKS)
+            .with(DENYLIST)
+            .with(ALLOWLIST)
+            .with(CAPS)
+    }
+
+    #[test]
+    fn test_sudo_msg_format() {
+        let msg: BeforeSendSudoMsg = from_json(
+            r#"{"track_before_send":{"from":"alice","to":"bob","amount":{"denom":"utoken","amount":"10"}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            msg,
+            BeforeSendSudoMsg::TrackBeforeSend {
+                from: "alice".to_string(),
+                to: "bob".to_string(),
+                amount: coin(10, "utoken"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_policies() {
+        let mut storage = MockStorage::default();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(1000);
+        for address in ["alice", "bob", "carol"] {
+            ALLOWLIST.add(&mut storage, address).unwrap();
+        }
+
+        assert_eq!(
+            hooks().handle(&mut storage, &env, block("alice", "mallory", 1)),
+            Err(NeutronError::TransferNotAllowed {
+                address: "mallory".to_string()
+            })
+        );
+        DENYLIST.add(&mut storage, "carol").unwrap();
+        assert_eq!(
+            hooks().handle(&mut storage, &env, block("carol", "bob", 1)),
+            Err(NeutronError::TransferNotAllowed {
+                address: "carol".to_string()
+            })
+        );
+
+        CAPS.set_cap(&mut storage, "alice", Some(Uint128::new(100)))
+            .unwrap();
+        hooks()
+            .handle(&mut storage, &env, block("alice", "bob", 60))
+            .unwrap();
+        assert_eq!(
+            hooks().handle(&mut storage, &env, block("alice", "bob", 41)),
+            Err(NeutronError::TransferCapExceeded {
+                address: "alice".to_string(),
+                cap: Uint128::new(100),
+                sent: Uint128::new(101),
+            })
+        );
+        env.block.time = Timestamp::from_seconds(1100);
+        hooks()
+            .handle(&mut storage, &env, block("alice", "bob", 100))
+            .unwrap();
+
+        LOCKS.lock(&mut storage, "bob", 1200).unwrap();
+        assert_eq!(
+            hooks().handle(&mut storage, &env, block("bob", "alice", 1)),
+            Err(NeutronError::AddressLocked {
+                address: "bob".to_string(),
+                unlock_time: 1200,
+            })
+        );
+        env.block.time = Timestamp::from_seconds(1200);
+        hooks()
+            .handle(&mut storage, &env, block("bob", "alice", 1))
+            .unwrap();
+
+        // freeze is checked before the other policies
+        FREEZE.freeze_all(&mut storage, true).unwrap();
+        assert_eq!(
+            hooks().handle(&mut storage, &env, block("carol", "mallory", 1)),
+            Err(NeutronError::AddressFrozen {
+                address: "carol".to_string()
+            })
+        );
+        FREEZE.freeze_all(&mut storage, false).unwrap();
+        FREEZE.freeze(&mut storage, "bob").unwrap();
+        assert!(hooks()
+            .handle(&mut storage, &env, block("bob", "alice", 1))
+            .is_err());
+        hooks()
+            .handle(&mut storage, &env, block("alice", "bob", 1))
+            .unwrap();
+    }
+}
@@ -0,0 +1,468 @@
+use crate::{
+    bindings::msg::NeutronMsg,
+    errors::error::{NeutronError, NeutronResult},
+    interchain_txs::helpers::decode_message_response,
+    proto_types::osmosis::tokenfactory::v1beta1::MsgCreateDenomResponse,
+    tokenfactory::metadata::DenomMetadata,
+};
+use cosmwasm_std::{Env, Order, Reply, StdError, StdResult, Storage, SubMsg, Uint128};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Token factory denom tracked by [DenomManager]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ManagedDenom {
+    pub subdenom: String,
+    /// **denom** is the full denom, `None` until the create denom reply is handled
+    pub denom: Option<String>,
+    /// **admin** is the admin of the denom as last set through the manager
+    pub admin: String,
+    /// **supply_cap** is the max amount of tokens the manager is allowed to have in circulation
+    pub supply_cap: Option<Uint128>,
+    /// **supply** is the amount of tokens minted by the manager minus the amount burned by it
+    pub supply: Uint128,
+}
+
+/// Creates token factory denoms and keeps track of their full denoms, admins and supplies.
+///
+/// The flow is [DenomManager::create] -> reply() -> [DenomManager::handle_reply], after that
+/// the denom can be minted, burned and configured while the contract stays its admin.
+///
+/// ```rust ignore
+/// const DENOMS: DenomManager = DenomManager::new("denoms", 1);
+///
+/// // execute
+/// let submsg = DENOMS.create(deps.storage, &env, "utoken", Some(Uint128::new(1_000_000)))?;
+///
+/// // reply
+/// if DENOMS.is_reply(msg.id) {
+///     let denom = DENOMS.handle_reply(deps.storage, msg)?;
+/// }
+///
+/// // execute
+/// let msg = DENOMS.mint(deps.storage, &env, "utoken", amount, recipient)?;
+/// ```
+pub struct DenomManager {
+    denoms: Map<String, ManagedDenom>,
+    reply_id: u64,
+}
+
+impl DenomManager {
+    /// Creates a manager under the given namespace, **reply_id** is used for create denom submessages
+    pub const fn new(namespace: &'static str, reply_id: u64) -> Self {
+        DenomManager {
+            denoms: Map::new(namespace),
+            reply_id,
+        }
+    }
+
+    /// Creates a submessage to create the **subdenom** under the contract address
+    /// and starts tracking it with an optional supply cap
+    pub fn create(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        subdenom: impl Into<String>,
+        supply_cap: Option<Uint128>,
+    ) -> NeutronResult<SubMsg<NeutronMsg>> {
+        let subdenom = subdenom.into();
+        if self.denoms.has(storage, subdenom.clone()) {
+            return Err(NeutronError::DenomAlreadyExists { subdenom });
+        }
+
+        self.denoms.save(
+            storage,
+            subdenom.clone(),
+            &ManagedDenom {
+                subdenom: subdenom.clone(),
+                denom: None,
+                admin: env.contract.address.to_string(),
+                supply_cap,
+                supply: Uint128::zero(),
+            },
+        )?;
+
+        Ok(SubMsg::reply_on_success(
+            NeutronMsg::submit_create_denom(subdenom),
+            self.reply_id,
+        ))
+    }
+
+    /// Checks whether the reply **id** belongs to the manager
+    pub fn is_reply(&self, id: u64) -> bool {
+        id == self.reply_id
+    }
+
+    /// Saves the full denom from **MsgCreateDenomResponse** of the reply and returns it
+    pub fn handle_reply(&self, storage: &mut dyn Storage, reply: Reply) -> NeutronResult<String> {
+        let response = reply.result.into_result().map_err(StdError::generic_err)?;
+        let msg_response = response
+            .msg_responses
+            .iter()
+            .find(|r| r.type_url == MsgCreateDenomResponse::TYPE_URL)
+            .ok_or_else(|| StdError::generic_err("no create denom response in reply"))?;
+        let denom =
+            decode_message_response::<MsgCreateDenomResponse>(&msg_response.value.to_vec())?
+                .new_token_denom;
+
+        // full denom is factory/{creator}/{subdenom}, subdenom may contain slashes
+        let subdenom = denom
+            .splitn(3, '/')
+            .nth(2)
+            .ok_or_else(|| StdError::generic_err(format!("invalid factory denom: {}", denom)))?
+            .to_string();
+        let mut managed = self.load(storage, &subdenom)?;
+        managed.denom = Some(denom.clone());
+        self.denoms.save(storage, subdenom, &managed)?;
+
+        Ok(denom)
+    }
+
+    /// Returns the tracked denom
+    pub fn load(&self, storage: &dyn Storage, subdenom: &str) -> NeutronResult<ManagedDenom> {
+        self.denoms
+            .may_load(storage, subdenom.to_string())?
+            .ok_or_else(|| NeutronError::DenomNotFound {
+                subdenom: subdenom.to_string(),
+            })
+    }
+
+    /// Returns all tracked denoms
+    pub fn all(&self, storage: &dyn Storage) -> StdResult<Vec<ManagedDenom>> {
+        self.denoms
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, denom)| denom))
+            .collect()
+    }
+
+    /// Returns the full denom of the **subdenom**
+    pub fn full_denom(&self, storage: &dyn Storage, subdenom: &str) -> NeutronResult<String> {
+        self.load(storage, subdenom)?
+            .denom
+            .ok_or_else(|| NeutronError::DenomNotCreated {
+                subdenom: subdenom.to_string(),
+            })
+    }
+
+    /// Creates a message to mint **amount** of tokens to **mint_to_address**,
+    /// fails if the mint exceeds the supply cap
+    pub fn mint(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        subdenom: &str,
+        amount: Uint128,
+        mint_to_address: impl Into<String>,
+    ) -> NeutronResult<NeutronMsg> {
+        let (mut managed, denom) = self.load_administered(storage, env, subdenom)?;
+        let supply = managed.supply.checked_add(amount)?;
+        if let Some(cap) = managed.supply_cap {
+            if supply > cap {
+                return Err(NeutronError::SupplyCapExceeded { denom, cap, supply });
+            }
+        }
+
+        managed.supply = supply;
+        self.denoms.save(storage, subdenom.to_string(), &managed)?;
+
+        Ok(NeutronMsg::submit_mint_tokens(
+            denom,
+            amount,
+            mint_to_address,
+        ))
+    }
+
+    /// Creates a message to burn **amount** of tokens held by the contract
+    pub fn burn(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        subdenom: &str,
+        amount: Uint128,
+    ) -> NeutronResult<NeutronMsg> {
+        let (mut managed, denom) = self.load_administered(storage, env, subdenom)?;
+        managed.supply = managed.supply.checked_sub(amount)?;
+        self.denoms.save(storage, subdenom.to_string(), &managed)?;
+
+        Ok(NeutronMsg::submit_burn_tokens(denom, amount, None))
+    }
+
+    /// Changes the supply cap of the denom, the cap can't be lower than the current supply
+    pub fn set_supply_cap(
+        &self,
+        storage: &mut dyn Storage,
+        subdenom: &str,
+        supply_cap: Option<Uint128>,
+    ) -> NeutronResult<()> {
+        let mut managed = self.load(storage, subdenom)?;
+        if let Some(cap) = supply_cap {
+            if managed.supply > cap {
+                return Err(NeutronError::SupplyCapExceeded {
+                    denom: managed.denom.unwrap_or(managed.subdenom),
+                    cap,
+                    supply: managed.supply,
+                });
+            }
+        }
+
+        managed.supply_cap = supply_cap;
+        self.denoms.save(storage, subdenom.to_string(), &managed)?;
+        Ok(())
+    }
+
+    /// Validates the metadata and creates a message to set it for the denom.
+    /// The base of the metadata must be the full denom.
+    pub fn set_metadata(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        subdenom: &str,
+        metadata: DenomMetadata,
+    ) -> NeutronResult<NeutronMsg> {
+        let (_, denom) = self.load_administered(storage, env, subdenom)?;
+        if metadata.base != denom {
+            return Err(NeutronError::InvalidDenomMetadata(format!(
+                "base denom must be {}, got {}",
+                denom, metadata.base
+            )));
+        }
+
+        metadata.into_msg()
+    }
+
+    /// Creates a message to set the before send hook of the denom to **contract_addr**
+    pub fn set_before_send_hook(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        subdenom: &str,
+        contract_addr: impl Into<String>,
+    ) -> NeutronResult<NeutronMsg> {
+        let (_, denom) = self.load_administered(storage, env, subdenom)?;
+        Ok(NeutronMsg::submit_set_before_send_hook(
+            denom,
+            contract_addr,
+        ))
+    }
+
+    /// Creates a message to force transfer **amount** of tokens between the addresses
+    pub fn force_transfer(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        subdenom: &str,
+        amount: Uint128,
+        from_address: impl Into<String>,
+        to_address: impl Into<String>,
+    ) -> NeutronResult<NeutronMsg> {
+        let (_, denom) = self.load_administered(storage, env, subdenom)?;
+        Ok(NeutronMsg::submit_force_transfer(
+            denom,
+            amount,
+            from_address,
+            to_address,
+        ))
+    }
+
+    /// Creates a message to change the admin of the denom and records the new admin.
+    /// After that the manager can't mint, burn or configure the denom anymore.
+    pub fn change_admin(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        subdenom: &str,
+        new_admin: impl Into<String>,
+    ) -> NeutronResult<NeutronMsg> {
+        let (mut managed, denom) = self.load_administered(storage, env, subdenom)?;
+        managed.admin = new_admin.into();
+        self.denoms.save(storage, subdenom.to_string(), &managed)?;
+
+        Ok(NeutronMsg::submit_change_admin(denom, managed.admin))
+    }
+
+    /// Creates messages to hand over all created denoms administered by the contract to **new_admin**,
+    /// e.g. to a contract replacing this one
+    pub fn migrate_admin(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        new_admin: impl Into<String>,
+    ) -> NeutronResult<Vec<NeutronMsg>> {
+        let new_admin = new_admin.into();
+        self.all(storage)?
+            .into_iter()
+            .filter(|managed| {
+                managed.denom.is_some() && managed.admin == env.contract.address.as_str()
+            })
+            .map(|managed| self.change_admin(storage, env, &managed.subdenom, new_admin.clone()))
+            .collect()
+    }
+
+    fn load_administered(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        subdenom: &str,
+    ) -> NeutronResult<(ManagedDenom, String)> {
+        let managed = self.load(storage, subdenom)?;
+        let denom = managed
+            .denom
+            .clone()
+            .ok_or_else(|| NeutronError::DenomNotCreated {
+                subdenom: subdenom.to_string(),
+            })?;
+        if managed.admin != env.contract.address.as_str() {
+            return Err(NeutronError::NotDenomAdmin {
+                denom,
+                admin: managed.admin,
+            });
+        }
+
+        Ok((managed, denom))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+    use cosmwasm_std::{Binary, MsgResponse, SubMsgResponse, SubMsgResult};
+    use prost::Message;
+
+    const DENOMS: DenomManager = DenomManager::new("denoms", 7);
+
+    fn create(storage: &mut dyn Storage, env: &Env, subdenom: &str, cap: Option<u128>) -> String {
+        let submsg = DENOMS
+            .create(storage, env, subdenom, cap.map(Uint128::new))
+            .unwrap();
+        assert_eq!(submsg.id, 7);
+
+        #[allow(deprecated)]
+        let response = SubMsgResponse {
+            events: vec![],
+            data: None,
+            msg_responses: vec![MsgResponse {
+                type_url: MsgCreateDenomResponse::TYPE_URL.to_string(),
+                value: Binary::new(
+                    MsgCreateDenomResponse {
+                        new_token_denom: format!("factory/{}/{}", env.contract.address, subdenom),
+                    }
+                    .encode_to_vec(),
+                ),
+            }],
+        };
+        DENOMS
+            .handle_reply(
+                storage,
+                Reply {
+                    id: submsg.id,
+                    payload: Binary::default(),
+                    gas_used: 0,
+                    result: SubMsgResult::Ok(response),
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_mint_and_burn() {
+        let mut storage = MockStorage::default();
+        let env = mock_env();
+
+        DENOMS.create(&mut storage, &env, "pending", None).unwrap();
+        assert_eq!(
+            DENOMS.mint(&mut storage, &env, "pending", Uint128::one(), "addr"),
+            Err(NeutronError::DenomNotCreated {
+                subdenom: "pending".to_string()
+            })
+        );
+
+        let denom = create(&mut storage, &env, "u/token", Some(100));
+        assert_eq!(denom, format!("factory/{}/u/token", env.contract.address));
+        assert_eq!(DENOMS.full_denom(&storage, "u/token"), Ok(denom.clone()));
+        assert_eq!(
+            DENOMS.create(&mut storage, &env, "u/token", None),
+            Err(NeutronError::DenomAlreadyExists {
+                subdenom: "u/token".to_string()
+            })
+        );
+
+        assert_eq!(
+            DENOMS.mint(&mut storage, &env, "u/token", Uint128::new(80), "addr"),
+            Ok(NeutronMsg::submit_mint_tokens(
+                denom.clone(),
+                Uint128::new(80),
+                "addr"
+            ))
+        );
+        assert_eq!(
+            DENOMS.mint(&mut storage, &env, "u/token", Uint128::new(21), "addr"),
+            Err(NeutronError::SupplyCapExceeded {
+                denom: denom.clone(),
+                cap: Uint128::new(100),
+                supply: Uint128::new(101),
+            })
+        );
+        DENOMS
+            .burn(&mut storage, &env, "u/token", Uint128::new(30))
+            .unwrap();
+        assert!(DENOMS
+            .set_supply_cap(&mut storage, "u/token", Some(Uint128::new(49)))
+            .is_err());
+        DENOMS
+            .set_supply_cap(&mut storage, "u/token", None)
+            .unwrap();
+        DENOMS
+            .mint(&mut storage, &env, "u/token", Uint128::new(1000), "addr")
+            .unwrap();
+        assert_eq!(
+            DENOMS.load(&storage, "u/token").unwrap().supply,
+            Uint128::new(1050)
+        );
+    }
+
+    #[test]
+    fn test_metadata_and_admin() {
+        let mut storage = MockStorage::default();
+        let env = mock_env();
+        let denom = create(&mut storage, &env, "utoken", None);
+        create(&mut storage, &env, "uother", None);
+
+        assert!(DENOMS
+            .set_metadata(
+                &storage,
+                &env,
+                "utoken",
+                DenomMetadata::new("factory/other/utoken", "token", 6, "Token", "TKN")
+            )
+            .is_err());
+        DENOMS
+            .set_metadata(
+                &storage,
+                &env,
+                "utoken",
+                DenomMetadata::new(denom.clone(), "token", 6, "Token", "TKN"),
+            )
+            .unwrap();
+
+        DENOMS
+            .change_admin(&mut storage, &env, "uother", "neutron1dao")
+            .unwrap();
+        assert_eq!(
+            DENOMS.migrate_admin(&mut storage, &env, "neutron1next"),
+            Ok(vec![NeutronMsg::submit_change_admin(
+                denom.clone(),
+                "neutron1next"
+            )])
+        );
+        assert_eq!(
+            DENOMS.mint(&mut storage, &env, "utoken", Uint128::one(), "addr"),
+            Err(NeutronError::NotDenomAdmin {
+                denom,
+                admin: "neutron1next".to_string(),
+            })
+        );
+    }
+}
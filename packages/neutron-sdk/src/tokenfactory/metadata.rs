@@ -0,0 +1,208 @@
+use crate::bindings::msg::NeutronMsg;
+use crate::{NeutronError, NeutronResult};
+use cosmwasm_std::DenomUnit;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Bank metadata of a token factory denom, see [NeutronMsg::SetDenomMetadata].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct DenomMetadata {
+    /// **description** description of a token
+    pub description: String,
+    /// **denom_units** represents the list of DenomUnit's for a given coin
+    pub denom_units: Vec<DenomUnit>,
+    /// **base** represents the base denom (should be the DenomUnit with exponent = 0).
+    pub base: String,
+    /// **display** indicates the suggested denom that should be displayed in clients.
+    pub display: String,
+    /// **name** defines the name of the token (eg: Cosmos Atom)
+    pub name: String,
+    /// **symbol** is the token symbol usually shown on exchanges (eg: ATOM).
+    pub symbol: String,
+    /// **uri** to a document (on or off-chain) that contains additional information. Optional.
+    pub uri: String,
+    /// **uri_hash** is a sha256 hash of a document pointed by URI. Optional.
+    pub uri_hash: String,
+}
+
+impl DenomMetadata {
+    /// Creates metadata of the **base** denom with a display unit **display** of **exponent**
+    pub fn new(
+        base: impl Into<String>,
+        display: impl Into<String>,
+        exponent: u32,
+        name: impl Into<String>,
+        symbol: impl Into<String>,
+    ) -> Self {
+        let base = base.into();
+        let display = display.into();
+        DenomMetadata {
+            denom_units: vec![
+                DenomUnit {
+                    denom: base.clone(),
+                    exponent: 0,
+                    aliases: vec![],
+                },
+                DenomUnit {
+                    denom: display.clone(),
+                    exponent,
+                    aliases: vec![],
+                },
+            ],
+            base,
+            display,
+            name: name.into(),
+            symbol: symbol.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn uri(mut self, uri: impl Into<String>, uri_hash: impl Into<String>) -> Self {
+        self.uri = uri.into();
+        self.uri_hash = uri_hash.into();
+        self
+    }
+
+    /// Performs the same checks as the bank module does for the metadata:
+    /// * name and symbol are not blank;
+    /// * base and display are valid denoms;
+    /// * the first denom unit is the base denom with exponent 0;
+    /// * denom unit exponents are strictly increasing;
+    /// * denom unit denoms and aliases are valid and unique;
+    /// * the display denom is one of the denom units.
+    pub fn validate(&self) -> NeutronResult<()> {
+        if self.name.trim().is_empty() {
+            return Err(invalid("name field cannot be blank"));
+        }
+        if self.symbol.trim().is_empty() {
+            return Err(invalid("symbol field cannot be blank"));
+        }
+        validate_denom(&self.base)?;
+        validate_denom(&self.display)?;
+
+        let first = self
+            .denom_units
+            .first()
+            .ok_or_else(|| invalid("denom units cannot be empty"))?;
+        if first.denom != self.base || first.exponent != 0 {
+            return Err(invalid(format!(
+                "the first denomination unit must be the base {} with exponent 0",
+                self.base
+            )));
+        }
+
+        let mut seen = HashSet::new();
+        let mut has_display = false;
+        for (i, unit) in self.denom_units.iter().enumerate() {
+            if i > 0 && unit.exponent <= self.denom_units[i - 1].exponent {
+                return Err(invalid(
+                    "the denomination units must be sorted in ascending order of exponents",
+                ));
+            }
+            validate_denom(&unit.denom)?;
+            has_display |= unit.denom == self.display;
+            for denom in std::iter::once(&unit.denom).chain(&unit.aliases) {
+                if denom.trim().is_empty() {
+                    return Err(invalid(format!("alias of {} cannot be blank", unit.denom)));
+                }
+                if !seen.insert(denom.as_str()) {
+                    return Err(invalid(format!("duplicate denomination unit {}", denom)));
+                }
+            }
+        }
+
+        if !has_display {
+            return Err(invalid(format!(
+                "metadata must contain a denomination unit with display denom {}",
+                self.display
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validates the metadata and creates a message to set it
+    pub fn into_msg(self) -> NeutronResult<NeutronMsg> {
+        self.validate()?;
+        Ok(NeutronMsg::submit_set_denom_metadata(
+            self.description,
+            self.denom_units,
+            self.base,
+            self.display,
+            self.name,
+            self.symbol,
+            self.uri,
+            self.uri_hash,
+        ))
+    }
+}
+
+/// Checks the denom against the Cosmos SDK denom format `[a-zA-Z][a-zA-Z0-9/:._-]{2,127}`
+pub fn validate_denom(denom: &str) -> NeutronResult<()> {
+    let valid = (3..=128).contains(&denom.len())
+        && denom.starts_with(|c: char| c.is_ascii_alphabetic())
+        && denom
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c));
+    if !valid {
+        return Err(invalid(format!("invalid denom: {}", denom)));
+    }
+    Ok(())
+}
+
+fn invalid(reason: impl Into<String>) -> NeutronError {
+    NeutronError::InvalidDenomMetadata(reason.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "factory/neutron1contract/utoken";
+
+    #[test]
+    fn test_validate_metadata() {
+        let metadata = DenomMetadata::new(BASE, "token", 6, "Token", "TKN");
+        assert_eq!(metadata.validate(), Ok(()));
+
+        let mut unsorted = metadata.clone();
+        unsorted.denom_units.push(DenomUnit {
+            denom: "mtoken".to_string(),
+            exponent: 3,
+            aliases: vec![],
+        });
+        assert!(unsorted.validate().is_err());
+
+        let mut duplicate_alias = metadata.clone();
+        duplicate_alias.denom_units[1].aliases = vec![BASE.to_string()];
+        assert_eq!(
+            duplicate_alias.validate(),
+            Err(NeutronError::InvalidDenomMetadata(format!(
+                "duplicate denomination unit {}",
+                BASE
+            )))
+        );
+
+        let mut no_display = metadata.clone();
+        no_display.display = "TOKEN".to_string();
+        assert!(no_display.validate().is_err());
+
+        let mut wrong_base = metadata.clone();
+        wrong_base.denom_units[0].exponent = 1;
+        assert!(wrong_base.validate().is_err());
+
+        assert!(DenomMetadata::new(BASE, "token", 6, "", "TKN")
+            .validate()
+            .is_err());
+        assert!(DenomMetadata::new("1token", "token", 6, "Token", "TKN")
+            .validate()
+            .is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod before_send;
+pub mod manager;
+pub mod metadata;
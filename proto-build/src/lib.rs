@@ -1,5 +1,6 @@
 pub mod code_generator;
 pub mod git;
 pub mod mod_gen;
+pub mod msg_responses;
 pub mod transform;
 pub mod transformers;
@@ -8,6 +8,7 @@ use std::{fs, path::PathBuf};
 use proto_build::{
     code_generator::{CodeGenerator, CosmosProject},
     git,
+    msg_responses::generate_msg_responses,
 };
 
 const COSMOS_SDK_REPO: &str = "https://github.com/neutron-org/cosmos-sdk.git";
@@ -67,6 +68,8 @@ const TMP_REPOS_DIR: &str = "./dependencies/";
 const TMP_BUILD_DIR: &str = "/tmp/tmp-protobuf/";
 /// The directory generated cosmos-sdk proto files go into in this repo
 const OUT_DIR: &str = "../packages/neutron-sdk/src/proto_types/";
+/// The file the ICA response registry generated from the proto files goes into in this repo
+const MSG_RESPONSES_FILE: &str = "../packages/neutron-sdk/src/interchain_txs/msg_responses.rs";
 
 pub fn generate() {
     let tmp_repos_dir: PathBuf = TMP_REPOS_DIR.parse().unwrap();
@@ -85,6 +88,7 @@ pub fn generate() {
 
     let tmp_build_dir: PathBuf = TMP_BUILD_DIR.parse().unwrap();
     let out_dir: PathBuf = OUT_DIR.parse().unwrap();
+    let msg_responses_file: PathBuf = MSG_RESPONSES_FILE.parse().unwrap();
 
     let cosmos_project = CosmosProject {
         name: "cosmos".to_string(),
@@ -143,7 +147,7 @@ pub fn generate() {
     };
 
     let neutron_code_generator = CodeGenerator::new(
-        out_dir,
+        out_dir.clone(),
         tmp_build_dir,
         neutron_project,
         vec![
@@ -158,6 +162,7 @@ pub fn generate() {
     );
 
     neutron_code_generator.generate();
+    generate_msg_responses(&out_dir, &msg_responses_file);
 
     fs::remove_dir_all(tmp_repos_dir.clone()).unwrap();
 }
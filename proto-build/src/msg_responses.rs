@@ -0,0 +1,98 @@
+use heck::ToUpperCamelCase;
+use log::info;
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use syn::{Item, ItemStruct};
+use walkdir::WalkDir;
+
+/// Generates the `typed_msg_responses!` invocation of the ICA response registry into **out_file**
+/// from the compiled protos in **types_dir**: every `MsgX` (or `MsgXRequest`) message that has
+/// a `MsgXResponse` in the same module is registered under a variant named after the module path and `X`,
+/// e.g. `cosmos::staking::v1beta1::MsgDelegate` becomes `CosmosStakingV1beta1Delegate`.
+pub fn generate_msg_responses(types_dir: &Path, out_file: &Path) {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let types_dir = root.join(types_dir);
+    let out_file = root.join(out_file);
+
+    info!(
+        "🧪 Generating ICA response registry into '{}'...",
+        out_file.display()
+    );
+
+    let mut entries = vec![];
+    for entry in WalkDir::new(&types_dir).sort_by_file_name() {
+        let entry = entry.unwrap();
+        if entry.path().extension() != Some(OsStr::new("rs")) {
+            continue;
+        }
+
+        let module = module_path(&types_dir, entry.path());
+        let file = syn::parse_file(&fs::read_to_string(entry.path()).unwrap()).unwrap();
+        let messages = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Struct(s) if is_registrable(s) => Some(s.ident.to_string()),
+                _ => None,
+            })
+            .collect::<BTreeSet<String>>();
+
+        for message in &messages {
+            let Some(name) = message.strip_prefix("Msg") else {
+                continue;
+            };
+            // a few Neutron messages are named `MsgXRequest`, their responses are `MsgXResponse`
+            let name = name.strip_suffix("Request").unwrap_or(name);
+            let response = format!("Msg{}Response", name);
+            if name.is_empty() || *message == response || !messages.contains(&response) {
+                continue;
+            }
+            let variant = module
+                .iter()
+                .chain([&name.to_string()])
+                .map(|s| s.to_upper_camel_case())
+                .collect::<String>();
+            entries.push((variant, module.join("::"), message.clone(), response));
+        }
+    }
+
+    let mut content = String::from(
+        "// @generated by proto-build from the `Msg*` and `Msg*Response` pairs of `proto_types`.\n\
+         // Do not edit it manually, run `make build-proto` instead.\n\
+         \n\
+         typed_msg_responses! {\n",
+    );
+    for (variant, module, message, response) in entries {
+        writeln!(
+            content,
+            "    {variant}(crate::proto_types::{module}::{message} => crate::proto_types::{module}::{response}),"
+        )
+        .unwrap();
+    }
+    content.push_str("}\n");
+
+    fs::write(out_file, content).unwrap();
+}
+
+/// Returns path of the module of the **file**, e.g. `["cosmos", "bank", "v1beta1"]`
+fn module_path(types_dir: &Path, file: &Path) -> Vec<String> {
+    let relative = file.strip_prefix(types_dir).unwrap().with_extension("");
+    let mut path = relative
+        .iter()
+        .map(|s| s.to_string_lossy().to_string())
+        .collect::<Vec<String>>();
+    if path.last().map(String::as_str) == Some("mod") {
+        path.pop();
+    }
+    path
+}
+
+/// Checks whether the struct is a public non-deprecated proto message with a type URL
+fn is_registrable(s: &ItemStruct) -> bool {
+    matches!(s.vis, syn::Visibility::Public(_))
+        && s.attrs.iter().any(|a| a.path.is_ident("proto_message"))
+        && !s.attrs.iter().any(|a| a.path.is_ident("deprecated"))
+}